@@ -0,0 +1,160 @@
+//! Compares the lookup-table-backed conversions against the equivalent `match` statements they
+//! replaced.
+//!
+//! The `char_to_*` benchmarks measure `TryFrom<char>` as it is built; run them with and without
+//! `--features fast-lookup` to compare the two.
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::convert::TryFrom;
+use unikorn::{Choseong, Jaeum, Jongseong, Jungseong};
+
+fn all_jaeum() -> Vec<Jaeum> {
+    (0..30)
+        .map(|index| Jaeum::try_from(index as u8).unwrap())
+        .collect()
+}
+
+fn choseong_by_match(jaeum: Jaeum) -> Option<Choseong> {
+    match jaeum {
+        Jaeum::Kiyeok => Some(Choseong::Kiyeok),
+        Jaeum::SsangKiyeok => Some(Choseong::SsangKiyeok),
+        Jaeum::Nieun => Some(Choseong::Nieun),
+        Jaeum::Tikeut => Some(Choseong::Tikeut),
+        Jaeum::SsangTikeut => Some(Choseong::SsangTikeut),
+        Jaeum::Rieul => Some(Choseong::Rieul),
+        Jaeum::Mieum => Some(Choseong::Mieum),
+        Jaeum::Pieup => Some(Choseong::Pieup),
+        Jaeum::SsangPieup => Some(Choseong::SsangPieup),
+        Jaeum::Sios => Some(Choseong::Sios),
+        Jaeum::SsangSios => Some(Choseong::SsangSios),
+        Jaeum::Ieung => Some(Choseong::Ieung),
+        Jaeum::Cieuc => Some(Choseong::Cieuc),
+        Jaeum::SsangCieuc => Some(Choseong::SsangCieuc),
+        Jaeum::Chieuch => Some(Choseong::Chieuch),
+        Jaeum::Khieukh => Some(Choseong::Khieukh),
+        Jaeum::Thieuth => Some(Choseong::Thieuth),
+        Jaeum::Phieuph => Some(Choseong::Phieuph),
+        Jaeum::Hieuh => Some(Choseong::Hieuh),
+        _ => None,
+    }
+}
+
+fn jongseong_by_match(jaeum: Jaeum) -> Option<Jongseong> {
+    match jaeum {
+        Jaeum::Kiyeok => Some(Jongseong::Kiyeok),
+        Jaeum::SsangKiyeok => Some(Jongseong::SsangKiyeok),
+        Jaeum::KiyeokSios => Some(Jongseong::KiyeokSios),
+        Jaeum::Nieun => Some(Jongseong::Nieun),
+        Jaeum::NieunCieuc => Some(Jongseong::NieunCieuc),
+        Jaeum::NieunHieuh => Some(Jongseong::NieunHieuh),
+        Jaeum::Tikeut => Some(Jongseong::Tikeut),
+        Jaeum::Rieul => Some(Jongseong::Rieul),
+        Jaeum::RieulKiyeok => Some(Jongseong::RieulKiyeok),
+        Jaeum::RieulMieum => Some(Jongseong::RieulMieum),
+        Jaeum::RieulPieup => Some(Jongseong::RieulPieup),
+        Jaeum::RieulSios => Some(Jongseong::RieulSios),
+        Jaeum::RieulThieuth => Some(Jongseong::RieulThieuth),
+        Jaeum::RieulPhieuph => Some(Jongseong::RieulPhieuph),
+        Jaeum::RieulHieuh => Some(Jongseong::RieulHieuh),
+        Jaeum::Mieum => Some(Jongseong::Mieum),
+        Jaeum::Pieup => Some(Jongseong::Pieup),
+        Jaeum::PieupSios => Some(Jongseong::PieupSios),
+        Jaeum::Sios => Some(Jongseong::Sios),
+        Jaeum::SsangSios => Some(Jongseong::SsangSios),
+        Jaeum::Ieung => Some(Jongseong::Ieung),
+        Jaeum::Cieuc => Some(Jongseong::Cieuc),
+        Jaeum::Chieuch => Some(Jongseong::Chieuch),
+        Jaeum::Khieukh => Some(Jongseong::Khieukh),
+        Jaeum::Thieuth => Some(Jongseong::Thieuth),
+        Jaeum::Phieuph => Some(Jongseong::Phieuph),
+        Jaeum::Hieuh => Some(Jongseong::Hieuh),
+        _ => None,
+    }
+}
+
+fn bench_jaeum_to_choseong(c: &mut Criterion) {
+    let jaeums = all_jaeum();
+
+    let mut group = c.benchmark_group("jaeum_to_choseong");
+    group.bench_function("lookup_table", |b| {
+        b.iter(|| {
+            for &jaeum in &jaeums {
+                black_box(Choseong::try_from(black_box(jaeum)).ok());
+            }
+        })
+    });
+    group.bench_function("match", |b| {
+        b.iter(|| {
+            for &jaeum in &jaeums {
+                black_box(choseong_by_match(black_box(jaeum)));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn bench_jaeum_to_jongseong(c: &mut Criterion) {
+    let jaeums = all_jaeum();
+
+    let mut group = c.benchmark_group("jaeum_to_jongseong");
+    group.bench_function("lookup_table", |b| {
+        b.iter(|| {
+            for &jaeum in &jaeums {
+                black_box(Jongseong::try_from(black_box(jaeum)).ok());
+            }
+        })
+    });
+    group.bench_function("match", |b| {
+        b.iter(|| {
+            for &jaeum in &jaeums {
+                black_box(jongseong_by_match(black_box(jaeum)));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn bench_char_to_choseong(c: &mut Criterion) {
+    let characters: Vec<char> = ('\u{3131}'..='\u{3163}').collect();
+
+    c.bench_function("char_to_choseong", |b| {
+        b.iter(|| {
+            for &character in &characters {
+                black_box(Choseong::try_from(black_box(character)).ok());
+            }
+        })
+    });
+}
+
+fn bench_char_to_jongseong(c: &mut Criterion) {
+    let characters: Vec<char> = ('\u{3131}'..='\u{3163}').collect();
+
+    c.bench_function("char_to_jongseong", |b| {
+        b.iter(|| {
+            for &character in &characters {
+                black_box(Jongseong::try_from(black_box(character)).ok());
+            }
+        })
+    });
+}
+
+fn bench_char_to_jungseong(c: &mut Criterion) {
+    let characters: Vec<char> = ('\u{3131}'..='\u{3163}').collect();
+
+    c.bench_function("char_to_jungseong", |b| {
+        b.iter(|| {
+            for &character in &characters {
+                black_box(Jungseong::try_from(black_box(character)).ok());
+            }
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_jaeum_to_choseong,
+    bench_jaeum_to_jongseong,
+    bench_char_to_choseong,
+    bench_char_to_jongseong,
+    bench_char_to_jungseong
+);
+criterion_main!(benches);
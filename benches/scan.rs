@@ -0,0 +1,105 @@
+//! Compares the byte-level scanners against the equivalent loops decoding every `char`.
+//!
+//! Run with and without `--features simd` to compare the SSE2 search as well.
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use unikorn::{
+    count_korean_syllables, decompose_syllables, find_korean_syllable, JamoForm, Syllable,
+};
+
+// a few MB of Korean mixed with ASCII, CJK ideographs and jamo, as in a typical web page.
+fn mixed_text() -> String {
+    "<p class=\"article\">한국어 텍스트와 English text, 漢字 그리고 ㄱㄴㄷ가 섞인 문단입니다.</p>\n"
+        .repeat(40_000)
+}
+
+// mostly ASCII, with a single syllable at the very end.
+fn ascii_text() -> String {
+    let mut text = "fn main() { println!(\"hello, world\"); }\n".repeat(80_000);
+    text.push('끝');
+    text
+}
+
+fn count_by_chars(s: &str) -> usize {
+    s.chars()
+        .filter(|&character| Syllable::is_one_of_us(character))
+        .count()
+}
+
+fn find_by_chars(s: &str) -> Option<usize> {
+    s.char_indices()
+        .find(|&(_, character)| Syllable::is_one_of_us(character))
+        .map(|(offset, _)| offset)
+}
+
+fn decompose_by_chars(s: &str) -> String {
+    let mut decomposed = String::with_capacity(s.len() * 3);
+    for character in s.chars() {
+        match Syllable::try_from(character) {
+            Ok(syllable) => {
+                decomposed.push(syllable.choseong.into());
+                decomposed.push(syllable.jungseong.into());
+                if let Some(jongseong) = syllable.jongseong {
+                    decomposed.push(jongseong.into());
+                }
+            }
+            Err(_) => decomposed.push(character),
+        }
+    }
+    decomposed
+}
+
+fn bench_count(c: &mut Criterion) {
+    let mixed = mixed_text();
+    let ascii = ascii_text();
+
+    let mut group = c.benchmark_group("count_syllables");
+    group.bench_function("mixed/chars", |b| {
+        b.iter(|| count_by_chars(black_box(&mixed)))
+    });
+    group.bench_function("mixed/count_korean_syllables", |b| {
+        b.iter(|| count_korean_syllables(black_box(&mixed)))
+    });
+    group.bench_function("ascii/chars", |b| {
+        b.iter(|| count_by_chars(black_box(&ascii)))
+    });
+    group.bench_function("ascii/count_korean_syllables", |b| {
+        b.iter(|| count_korean_syllables(black_box(&ascii)))
+    });
+    group.finish();
+}
+
+fn bench_find(c: &mut Criterion) {
+    let ascii = ascii_text();
+
+    let mut group = c.benchmark_group("find_syllable");
+    group.bench_function("ascii/chars", |b| {
+        b.iter(|| find_by_chars(black_box(&ascii)))
+    });
+    group.bench_function("ascii/find_korean_syllable", |b| {
+        b.iter(|| find_korean_syllable(black_box(&ascii)))
+    });
+    group.finish();
+}
+
+fn bench_decompose(c: &mut Criterion) {
+    let mixed = mixed_text();
+    let ascii = ascii_text();
+
+    let mut group = c.benchmark_group("decompose_syllables");
+    group.bench_function("mixed/chars", |b| {
+        b.iter(|| decompose_by_chars(black_box(&mixed)))
+    });
+    group.bench_function("mixed/decompose_syllables", |b| {
+        b.iter(|| decompose_syllables(black_box(&mixed), JamoForm::Compatibility))
+    });
+    group.bench_function("ascii/chars", |b| {
+        b.iter(|| decompose_by_chars(black_box(&ascii)))
+    });
+    group.bench_function("ascii/decompose_syllables", |b| {
+        b.iter(|| decompose_syllables(black_box(&ascii), JamoForm::Compatibility))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_count, bench_find, bench_decompose);
+criterion_main!(benches);
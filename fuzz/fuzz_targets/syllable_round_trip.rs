@@ -0,0 +1,24 @@
+//! Checks that a syllable survives every round trip through its other representations.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use std::convert::TryFrom;
+use unikorn::{compose_from_indices, decode_johab, decompose_to_indices, encode_johab, Syllable};
+
+fuzz_target!(|syllable: Syllable| {
+    let character = char::from(syllable);
+    assert_eq!(Syllable::try_from(character), Ok(syllable));
+    assert_eq!(Syllable::try_from(<[u8; 3]>::from(syllable)), Ok(syllable));
+    assert_eq!(
+        Syllable::from_index(syllable.dictionary_index()),
+        Some(syllable)
+    );
+    assert_eq!(
+        Syllable::try_from_jamo_sequence(&format!("{:#}", syllable)),
+        Some(syllable)
+    );
+
+    let source = character.to_string();
+    assert_eq!(compose_from_indices(&decompose_to_indices(&source)), source);
+    assert_eq!(decode_johab(&encode_johab(&source).unwrap()), Ok(source));
+});
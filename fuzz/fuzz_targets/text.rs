@@ -0,0 +1,31 @@
+//! Checks the whole-string transformations against arbitrary, mostly non-Korean, input.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use unikorn::{
+    display_width, normalize_halfwidth, reverse_syllables, strip_jongseong, truncate_to_width,
+    CharExt,
+};
+
+fuzz_target!(|input: (&str, u8)| {
+    let (s, max) = input;
+
+    let normalized = normalize_halfwidth(s);
+    assert_eq!(normalize_halfwidth(&normalized), normalized);
+    assert!(!normalized
+        .chars()
+        .any(|character| character.is_korean_halfwidth() && character != '\u{FFA0}'));
+
+    let stripped = strip_jongseong(s);
+    assert_eq!(strip_jongseong(&stripped), stripped);
+    assert_eq!(stripped.chars().count(), s.chars().count());
+
+    // reversing may compose, but never split, a syllable.
+    assert!(reverse_syllables(s).chars().count() <= s.chars().count());
+
+    let truncated = truncate_to_width(s, max as usize, "…");
+    assert!(display_width(&truncated) <= max as usize);
+    if display_width(s) <= max as usize {
+        assert_eq!(truncated, s);
+    }
+});
@@ -0,0 +1,154 @@
+//! Implementations of `Arbitrary` from `arbitrary`, for fuzzing, and from `proptest`, for
+//! property-based testing.
+//!
+//! Either way, every value is picked uniformly among the modern values of its type.
+use crate::{Choseong, Jaeum, Jongseong, Jungseong, Syllable};
+use core::convert::TryFrom;
+
+#[cfg(feature = "arbitrary")]
+mod fuzzing {
+    use super::{Choseong, Jaeum, Jongseong, Jungseong, Syllable, TryFrom};
+    use arbitrary::{Arbitrary, Result, Unstructured};
+
+    impl<'a> Arbitrary<'a> for Choseong {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            let index = u.int_in_range(0..=Self::COUNT as u8 - 1)?;
+            Ok(Self::try_from(index).unwrap())
+        }
+    }
+    impl<'a> Arbitrary<'a> for Jaeum {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            let index = u.int_in_range(0..=Self::COUNT as u8 - 1)?;
+            Ok(Self::try_from(index).unwrap())
+        }
+    }
+    impl<'a> Arbitrary<'a> for Jongseong {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            // starts from 1, see `Jongseong`.
+            let index = u.int_in_range(1..=Self::COUNT as u8)?;
+            Ok(Self::try_from(index).unwrap())
+        }
+    }
+    impl<'a> Arbitrary<'a> for Jungseong {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            let index = u.int_in_range(0..=Self::COUNT as u8 - 1)?;
+            Ok(Self::try_from(index).unwrap())
+        }
+    }
+    impl<'a> Arbitrary<'a> for Syllable {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            // picking the index rather than each component keeps every syllable equally likely.
+            let index = u.int_in_range(0..=11171)?;
+            Ok(Self::from_index(index).unwrap())
+        }
+    }
+}
+
+#[cfg(feature = "proptest")]
+mod property_testing {
+    use super::{Choseong, Jaeum, Jongseong, Jungseong, Syllable, TryFrom};
+    use core::ops::Range;
+    use proptest::{
+        arbitrary::Arbitrary,
+        strategy::{Map, Strategy},
+    };
+
+    impl Arbitrary for Choseong {
+        type Parameters = ();
+        type Strategy = Map<Range<u8>, fn(u8) -> Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (0..Self::COUNT as u8).prop_map(|index| Self::try_from(index).unwrap())
+        }
+    }
+    impl Arbitrary for Jaeum {
+        type Parameters = ();
+        type Strategy = Map<Range<u8>, fn(u8) -> Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (0..Self::COUNT as u8).prop_map(|index| Self::try_from(index).unwrap())
+        }
+    }
+    impl Arbitrary for Jongseong {
+        type Parameters = ();
+        type Strategy = Map<Range<u8>, fn(u8) -> Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            // starts from 1, see `Jongseong`.
+            (1..Self::COUNT as u8 + 1).prop_map(|index| Self::try_from(index).unwrap())
+        }
+    }
+    impl Arbitrary for Jungseong {
+        type Parameters = ();
+        type Strategy = Map<Range<u8>, fn(u8) -> Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (0..Self::COUNT as u8).prop_map(|index| Self::try_from(index).unwrap())
+        }
+    }
+    impl Arbitrary for Syllable {
+        type Parameters = ();
+        type Strategy = Map<Range<u32>, fn(u32) -> Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            // picking the index rather than each component keeps every syllable equally likely.
+            (0..11172).prop_map(|index| Self::from_index(index).unwrap())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Choseong, Jaeum, Jongseong, Jungseong, Syllable};
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn test_arbitrary_from_unstructured() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let bytes = (0..=255).cycle().take(4096).collect::<Vec<u8>>();
+        let mut u = Unstructured::new(&bytes);
+        while !u.is_empty() {
+            let choseong = Choseong::arbitrary(&mut u).unwrap();
+            assert!(Choseong::ALL.contains(&choseong));
+            let jaeum = Jaeum::arbitrary(&mut u).unwrap();
+            assert!(('ㄱ'..='ㅎ').contains(&char::from(jaeum)));
+            let jongseong = Jongseong::arbitrary(&mut u).unwrap();
+            assert!(Jongseong::ALL.contains(&jongseong));
+            let jungseong = Jungseong::arbitrary(&mut u).unwrap();
+            assert!(Jungseong::ALL.contains(&jungseong));
+            let syllable = Syllable::arbitrary(&mut u).unwrap();
+            assert!(Syllable::is_one_of_us(char::from(syllable)));
+        }
+
+        // running out of bytes still yields a value.
+        let mut u = Unstructured::new(&[]);
+        assert_eq!(Choseong::arbitrary(&mut u), Ok(Choseong::Kiyeok));
+        assert_eq!(Syllable::arbitrary(&mut u).unwrap(), '가');
+    }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn test_arbitrary_jamo(
+            choseong in proptest::arbitrary::any::<Choseong>(),
+            jaeum in proptest::arbitrary::any::<Jaeum>(),
+            jongseong in proptest::arbitrary::any::<Jongseong>(),
+            jungseong in proptest::arbitrary::any::<Jungseong>(),
+        ) {
+            proptest::prop_assert!(Choseong::ALL.contains(&choseong));
+            proptest::prop_assert!(('ㄱ'..='ㅎ').contains(&char::from(jaeum)));
+            proptest::prop_assert!(Jongseong::ALL.contains(&jongseong));
+            proptest::prop_assert!(Jungseong::ALL.contains(&jungseong));
+        }
+
+        #[test]
+        fn test_arbitrary_syllable(syllable in proptest::arbitrary::any::<Syllable>()) {
+            proptest::prop_assert!(Syllable::is_one_of_us(char::from(syllable)));
+            proptest::prop_assert_eq!(
+                Syllable::from_index(syllable.dictionary_index()),
+                Some(syllable)
+            );
+        }
+    }
+}
@@ -0,0 +1,321 @@
+//! A command line front end to the string transformations of the library, reading the files given
+//! (or the standard input) line by line and writing the result to the standard output.
+use std::{
+    env,
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    process,
+};
+use unikorn::{
+    compose_syllables, decompose_syllables, flip_chojongseong_horizontally, io::romanize_reader,
+    normalize_halfwidth, pullup_choseong_with, pushdown_jongseong_with, JamoForm, PullupOptions,
+    PushdownOptions, RomanizationScheme,
+};
+
+const USAGE: &str = "\
+usage: unikorn <command> [options] [file ...]
+
+Reads each file, or the standard input if there is none or it is '-', line by line, and writes the
+transformed lines to the standard output.
+
+commands:
+    decompose    decomposes the syllables into the conjoining jamo (NFD), or the compatibility jamo
+                 with '--form compat'
+    compose      composes the runs of conjoining jamo into syllables (NFC)
+    convert      converts into the form given by '--form', one of:
+                     nfc             the same as 'compose'
+                     nfd             the same as 'decompose'
+                     compat          decomposes the syllables into the compatibility jamo
+                     from-halfwidth  turns Halfwidth Hangul into the compatibility jamo
+    pushdown     links each final consonant into the next syllable beginning with a silent 'ㅇ'
+    pullup       moves each initial consonant into the previous syllable without a final one, the
+                 reverse of 'pushdown'
+    flip         swaps the initial and the final consonant of each syllable
+    romanize     romanizes each syllable by itself, following the Revised Romanization
+
+options:
+    --form <form>     the form to convert into; see above
+    --skip <chars>    with 'pushdown' or 'pullup', the characters to look past, e.g. ' '
+    --palatalize      with 'pushdown', turns 'ㄷ' and 'ㅌ' into 'ㅈ' and 'ㅊ' before 'ㅣ', e.g. '같이'
+                      into '가치'
+    --strict          fails on the first byte not making up a valid UTF-8 sequence, rather than
+                      replacing the invalid ones with U+FFFD
+    -h, --help        prints this message";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Form {
+    Nfc,
+    Nfd,
+    Compat,
+    FromHalfwidth,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Command {
+    Convert(Form),
+    Pushdown,
+    Pullup,
+    Flip,
+    Romanize,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct Options {
+    command: Command,
+    skip_chars: Vec<char>,
+    palatalization: bool,
+    strict: bool,
+    files: Vec<String>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let command = args.next().ok_or("no command given")?;
+    let mut form = None;
+    let mut skip_chars = Vec::new();
+    let mut palatalization = false;
+    let mut strict = false;
+    let mut files = Vec::new();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--form" => {
+                let value = args.next().ok_or("'--form' takes a value")?;
+                form = Some(match value.as_str() {
+                    "nfc" => Form::Nfc,
+                    "nfd" => Form::Nfd,
+                    "compat" => Form::Compat,
+                    "from-halfwidth" => Form::FromHalfwidth,
+                    _ => return Err(format!("unknown form '{}'", value)),
+                });
+            }
+            "--skip" => skip_chars.extend(args.next().ok_or("'--skip' takes a value")?.chars()),
+            "--palatalize" => palatalization = true,
+            "--strict" => strict = true,
+            "-" => files.push(arg),
+            _ if arg.starts_with('-') => return Err(format!("unknown option '{}'", arg)),
+            _ => files.push(arg),
+        }
+    }
+
+    let command = match (command.as_str(), form) {
+        ("decompose", None | Some(Form::Nfd)) => Command::Convert(Form::Nfd),
+        ("decompose", Some(Form::Compat)) => Command::Convert(Form::Compat),
+        ("compose", None | Some(Form::Nfc)) => Command::Convert(Form::Nfc),
+        ("convert", Some(form)) => Command::Convert(form),
+        ("convert", None) => return Err(String::from("'convert' needs '--form'")),
+        ("pushdown", None) => Command::Pushdown,
+        ("pullup", None) => Command::Pullup,
+        ("flip", None) => Command::Flip,
+        ("romanize", None) => Command::Romanize,
+        ("decompose" | "compose" | "pushdown" | "pullup" | "flip" | "romanize", Some(_)) => {
+            return Err(format!("'{}' does not take this '--form'", command))
+        }
+        _ => return Err(format!("unknown command '{}'", command)),
+    };
+    if !skip_chars.is_empty() && command != Command::Pushdown && command != Command::Pullup {
+        return Err(String::from("only 'pushdown' and 'pullup' take '--skip'"));
+    }
+    if palatalization && command != Command::Pushdown {
+        return Err(String::from("only 'pushdown' takes '--palatalize'"));
+    }
+
+    Ok(Options {
+        command,
+        skip_chars,
+        palatalization,
+        strict,
+        files,
+    })
+}
+
+// transforms `line` as `options` says into `output`; the romanization streams through
+// `romanize_reader`, while the rest are built up as a whole line first.
+fn transform(line: &str, options: &Options, output: &mut impl Write) -> io::Result<()> {
+    let transformed = match options.command {
+        Command::Convert(Form::Nfc) => compose_syllables(line),
+        Command::Convert(Form::Nfd) => decompose_syllables(line, JamoForm::Conjoining),
+        Command::Convert(Form::Compat) => decompose_syllables(line, JamoForm::Compatibility),
+        Command::Convert(Form::FromHalfwidth) => normalize_halfwidth(line),
+        Command::Pushdown => pushdown_jongseong_with(
+            line,
+            PushdownOptions {
+                skip_chars: &options.skip_chars,
+                palatalization: options.palatalization,
+            },
+        ),
+        Command::Pullup => pullup_choseong_with(
+            line,
+            PullupOptions {
+                skip_chars: &options.skip_chars,
+            },
+        ),
+        Command::Flip => flip_chojongseong_horizontally(line),
+        Command::Romanize => {
+            return romanize_reader(line.as_bytes(), output, RomanizationScheme::default())
+        }
+    };
+    output.write_all(transformed.as_bytes())
+}
+
+// transforms every line from `input`, a line ending included, into `output`; `name` and `offset`
+// locate the invalid UTF-8 in the error message under `--strict`.
+fn run(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    name: &str,
+    options: &Options,
+) -> Result<(), String> {
+    let mut buffer = Vec::new();
+    let mut offset = 0;
+    loop {
+        buffer.clear();
+        let read = input
+            .read_until(b'\n', &mut buffer)
+            .map_err(|error| format!("{}: {}", name, error))?;
+        if read == 0 {
+            return Ok(());
+        }
+
+        let line = match std::str::from_utf8(&buffer) {
+            Ok(line) => line.into(),
+            Err(error) if options.strict => {
+                return Err(format!(
+                    "{}: invalid UTF-8 at byte {}",
+                    name,
+                    offset + error.valid_up_to()
+                ))
+            }
+            Err(_) => String::from_utf8_lossy(&buffer),
+        };
+        transform(&line, options, output).map_err(|error| format!("<stdout>: {}", error))?;
+
+        offset += read;
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return;
+    }
+    let mut options = match parse_args(args.into_iter()) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("unikorn: {}\n\n{}", message, USAGE);
+            process::exit(2);
+        }
+    };
+    if options.files.is_empty() {
+        options.files.push(String::from("-"));
+    }
+
+    let stdout = io::stdout();
+    let mut output = BufWriter::new(stdout.lock());
+    for name in &options.files {
+        let result = if name == "-" {
+            run(&mut io::stdin().lock(), &mut output, "<stdin>", &options)
+        } else {
+            File::open(name)
+                .map_err(|error| format!("{}: {}", name, error))
+                .and_then(|file| run(&mut BufReader::new(file), &mut output, name, &options))
+        };
+        if let Err(message) = result {
+            let _ = output.flush();
+            eprintln!("unikorn: {}", message);
+            process::exit(1);
+        }
+    }
+    if let Err(error) = output.flush() {
+        eprintln!("unikorn: <stdout>: {}", error);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_args, run, Command, Form, Options};
+
+    fn options(args: &[&str]) -> Result<Options, String> {
+        parse_args(args.iter().map(|arg| String::from(*arg)))
+    }
+
+    fn run_on(input: &[u8], args: &[&str]) -> Result<String, String> {
+        let mut output = Vec::new();
+        run(&mut &input[..], &mut output, "<test>", &options(args)?)?;
+        Ok(String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let parsed = options(&["decompose", "--form", "compat", "a.txt", "-"]).unwrap();
+        assert_eq!(parsed.command, Command::Convert(Form::Compat));
+        assert_eq!(parsed.files, ["a.txt", "-"]);
+        assert!(!parsed.strict);
+
+        assert_eq!(
+            options(&["convert", "--form", "from-halfwidth", "--strict"])
+                .unwrap()
+                .command,
+            Command::Convert(Form::FromHalfwidth)
+        );
+        assert_eq!(
+            options(&["pullup", "--skip", " "]).unwrap().command,
+            Command::Pullup
+        );
+        assert_eq!(options(&["flip"]).unwrap().command, Command::Flip);
+        assert_eq!(
+            options(&["pushdown", "--skip", " ,"]).unwrap().skip_chars,
+            [' ', ',']
+        );
+
+        assert!(options(&[]).is_err());
+        assert!(options(&["convert", "--form", "halfwidth"]).is_err());
+        assert!(options(&["flip", "--skip", " "]).is_err());
+        assert!(options(&["pullup", "--palatalize"]).is_err());
+        assert!(options(&["convert"]).is_err());
+        assert!(options(&["compose", "--form", "nfd"]).is_err());
+        assert!(options(&["romanize", "--skip", " "]).is_err());
+        assert!(options(&["compose", "--palatalize"]).is_err());
+        assert!(options(&["compose", "--extended"]).is_err());
+    }
+
+    #[test]
+    fn test_run() {
+        assert_eq!(
+            run_on("한글\nok\n".as_bytes(), &["decompose"]).unwrap(),
+            "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}\nok\n"
+        );
+        assert_eq!(
+            run_on("\u{1112}\u{1161}\u{11AB}".as_bytes(), &["compose"]).unwrap(),
+            "한"
+        );
+        assert_eq!(
+            run_on("밥 을\r\n".as_bytes(), &["pushdown", "--skip", " "]).unwrap(),
+            "바 블\r\n"
+        );
+        assert_eq!(
+            run_on("같이\n".as_bytes(), &["pushdown", "--palatalize"]).unwrap(),
+            "가치\n"
+        );
+        assert_eq!(
+            run_on("머 거\n".as_bytes(), &["pullup", "--skip", " "]).unwrap(),
+            "먹 어\n"
+        );
+        assert_eq!(run_on("간다\n".as_bytes(), &["flip"]).unwrap(), "낙앋\n");
+        assert_eq!(
+            run_on("한글, 밝다\n".as_bytes(), &["romanize"]).unwrap(),
+            "hangeul, balkda\n"
+        );
+    }
+
+    #[test]
+    fn test_run_strict() {
+        let input = b"ok\n\xED\x95\x9C\xFF\n";
+        assert_eq!(run_on(input, &["compose"]).unwrap(), "ok\n한\u{FFFD}\n");
+        assert_eq!(
+            run_on(input, &["compose", "--strict"]).unwrap_err(),
+            "<test>: invalid UTF-8 at byte 6"
+        );
+    }
+}
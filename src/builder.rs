@@ -0,0 +1,412 @@
+//! Composing syllables out of jamo as they come, the way an input method does.
+use crate::{Choseong, Jaeum, Jamo, Jongseong, Jungseong, Syllable};
+use alloc::string::String;
+use core::convert::TryFrom;
+
+// the compound vowels an input method composes out of two simple ones, i.e. (first, second,
+// compound).
+const COMPOUND_VOWELS: [(Jungseong, Jungseong, Jungseong); 7] = [
+    (Jungseong::O, Jungseong::A, Jungseong::Wa),
+    (Jungseong::O, Jungseong::Ae, Jungseong::Wae),
+    (Jungseong::O, Jungseong::I, Jungseong::Oe),
+    (Jungseong::U, Jungseong::Eo, Jungseong::Weo),
+    (Jungseong::U, Jungseong::E, Jungseong::We),
+    (Jungseong::U, Jungseong::I, Jungseong::Wi),
+    (Jungseong::Eu, Jungseong::I, Jungseong::Yi),
+];
+
+/// Builds a string out of jamo pushed one by one, composing them into Precomposed Korean
+/// [`Syllable`]s as it goes, just like an input method does with keystrokes.
+///
+/// At most one syllable is pending, i.e. still open to change, at a time; everything before it is
+/// final. A consonant following a vowel is taken as the final consonant (종성, [`Jongseong`]) at
+/// first, and moves on to begin the next syllable once a vowel follows it, e.g. 'ㄱ', 'ㅏ', 'ㄱ'
+/// make '각', which becomes '가가' as another 'ㅏ' is pushed. Likewise, two consonants making up a
+/// clustered final consonant are split in two, and two vowels making up a compound vowel, e.g. 'ㅗ'
+/// and 'ㅏ', are combined.
+///
+/// Whether a jamo is pushed in the conjoining or the compatibility form makes no difference; it is
+/// the order that decides the position. A jamo left standalone comes out in the compatibility form.
+///
+/// ```
+/// use unikorn::HangulBuilder;
+///
+/// let mut builder = HangulBuilder::new();
+/// builder.push_str("\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}");
+/// assert_eq!(builder.as_pending(), Some('글'));
+///
+/// builder.push_char('ㅣ');
+/// assert_eq!(builder.build(), "한그리");
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct HangulBuilder {
+    built: String,
+    pending: Pending,
+}
+impl HangulBuilder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns what the pending syllable currently looks like, or [`None`] if nothing is pending.
+    ///
+    /// ```
+    /// use unikorn::HangulBuilder;
+    ///
+    /// let mut builder = HangulBuilder::new();
+    /// builder.push_str("ㄷㅏㄹ");
+    /// assert_eq!(builder.as_pending(), Some('달'));
+    /// builder.push_char('ㄱ');
+    /// assert_eq!(builder.as_pending(), Some('닭'));
+    /// ```
+    pub fn as_pending(&self) -> Option<char> {
+        self.pending.to_char()
+    }
+
+    /// Removes the jamo pushed last, reopening the syllable before it if nothing is pending.
+    ///
+    /// A clustered final consonant or a compound vowel loses its second component only, whether it
+    /// was pushed as two jamo or a single one. Anything other than a syllable is removed as a
+    /// whole.
+    ///
+    /// ```
+    /// use unikorn::HangulBuilder;
+    ///
+    /// let mut builder = HangulBuilder::new();
+    /// builder.push_str("닭 ");
+    /// builder.backspace();
+    /// builder.backspace();
+    /// assert_eq!(builder.build(), "달");
+    /// ```
+    pub fn backspace(&mut self) {
+        if self.pending.is_empty() {
+            match self.built.pop() {
+                Some(character) => match Syllable::try_from(character) {
+                    Ok(syllable) => self.pending = Pending::reopen(syllable),
+                    Err(_) => return,
+                },
+                None => return,
+            }
+        }
+
+        self.pending.pop();
+    }
+
+    /// Finishes the pending syllable, if any, and returns everything built so far.
+    pub fn build(mut self) -> String {
+        self.commit();
+        self.built
+    }
+
+    /// Pushes a [`char`], composing it into the pending syllable if it is a modern jamo.
+    ///
+    /// A Precomposed Korean [`Syllable`] becomes the pending syllable, so that a jamo pushed
+    /// afterwards can still be added to it, e.g. '가' followed by 'ㄱ' makes '각'. Anything else
+    /// finishes the pending syllable, and is then appended as it is.
+    pub fn push_char(&mut self, character: char) {
+        if let Ok(jamo) = Jamo::try_from(character) {
+            self.push_jamo(jamo);
+        } else if let Ok(syllable) = Syllable::try_from(character) {
+            self.commit();
+            self.pending = Pending::open(syllable);
+        } else {
+            self.commit();
+            self.built.push(character);
+        }
+    }
+
+    /// Pushes a jamo, composing it into the pending syllable.
+    pub fn push_jamo(&mut self, jamo: Jamo) {
+        match jamo {
+            Jamo::Choseong(choseong) => self.push_jaeum(choseong.into()),
+            Jamo::Jongseong(jongseong) => self.push_jaeum(jongseong.into()),
+            Jamo::CompatJaeum(jaeum) | Jamo::HalfwidthJaeum(jaeum) => self.push_jaeum(jaeum),
+            Jamo::Jungseong(jungseong)
+            | Jamo::CompatMoeum(jungseong)
+            | Jamo::HalfwidthMoeum(jungseong) => self.push_jungseong(jungseong),
+        }
+    }
+
+    /// Pushes every [`char`] in a given string; see [`HangulBuilder::push_char`].
+    pub fn push_str(&mut self, s: &str) {
+        for character in s.chars() {
+            self.push_char(character);
+        }
+    }
+
+    fn commit(&mut self) {
+        if let Some(character) = self.pending.to_char() {
+            self.built.push(character);
+        }
+        self.pending = Pending::default();
+    }
+
+    fn push_jaeum(&mut self, jaeum: Jaeum) {
+        let pending = &mut self.pending;
+        if pending.choseong.is_some() && pending.jungseong.is_some() {
+            match pending.jongseong {
+                None => {
+                    if let Some(jongseong) = jaeum.to_jongseong() {
+                        pending.jongseong = Some((jongseong, None));
+                        return;
+                    }
+                }
+                Some((first, None)) => {
+                    let cluster = jaeum.to_jongseong().and_then(|second| {
+                        Jongseong::iter()
+                            .find(|cluster| cluster.components() == Some((first, second)))
+                    });
+                    if let Some(cluster) = cluster {
+                        pending.jongseong = Some((first, Some(cluster)));
+                        return;
+                    }
+                }
+                Some((_, Some(_))) => {}
+            }
+        }
+
+        self.commit();
+        match jaeum.to_choseong() {
+            Some(choseong) => self.pending.choseong = Some(choseong),
+            // e.g. 'ㄳ', which can never begin a syllable.
+            None => self.built.push(jaeum.into()),
+        }
+    }
+
+    fn push_jungseong(&mut self, jungseong: Jungseong) {
+        let pending = &mut self.pending;
+        match (pending.jungseong, pending.jongseong) {
+            (None, _) if pending.choseong.is_some() => {
+                pending.jungseong = Some((jungseong, None));
+            }
+            (Some((first, None)), None) => match combine_vowels(first, jungseong) {
+                Some(compound) => pending.jungseong = Some((first, Some(compound))),
+                None => {
+                    self.commit();
+                    self.pending.jungseong = Some((jungseong, None));
+                }
+            },
+            (Some(_), Some((first, second))) => {
+                // the final consonant, or its second component if clustered, moves on to begin the
+                // next syllable.
+                let jongseong = second.unwrap_or(first);
+                let (remaining, moved) = match jongseong.components() {
+                    Some((first, second)) => (Some(first), second),
+                    None => (None, jongseong),
+                };
+                pending.jongseong = remaining.map(|remaining| (remaining, None));
+                self.commit();
+                // every simple final consonant has its initial counterpart.
+                self.pending.choseong = Jaeum::from(moved).to_choseong();
+                self.pending.jungseong = Some((jungseong, None));
+            }
+            _ => {
+                self.commit();
+                self.pending.jungseong = Some((jungseong, None));
+            }
+        }
+    }
+}
+
+// a syllable in the making; each component remembers the first half as well, if it was made out of
+// two jamo, so that it can be taken apart again.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+struct Pending {
+    choseong: Option<Choseong>,
+    // (as first pushed, what it became after the second one was pushed)
+    jungseong: Option<(Jungseong, Option<Jungseong>)>,
+    jongseong: Option<(Jongseong, Option<Jongseong>)>,
+}
+impl Pending {
+    fn is_empty(&self) -> bool {
+        self.choseong.is_none() && self.jungseong.is_none()
+    }
+
+    fn open(syllable: Syllable) -> Self {
+        Self {
+            choseong: Some(syllable.choseong),
+            jungseong: Some((syllable.jungseong, None)),
+            jongseong: syllable.jongseong.map(|jongseong| (jongseong, None)),
+        }
+    }
+
+    // like `open()`, but with the compound components split up for `pop()`.
+    fn reopen(syllable: Syllable) -> Self {
+        let jungseong = COMPOUND_VOWELS
+            .iter()
+            .find(|&&(_, _, compound)| compound == syllable.jungseong)
+            .map_or((syllable.jungseong, None), |&(first, _, compound)| {
+                (first, Some(compound))
+            });
+        let jongseong = syllable
+            .jongseong
+            .map(|jongseong| match jongseong.components() {
+                Some((first, _)) => (first, Some(jongseong)),
+                None => (jongseong, None),
+            });
+
+        Self {
+            choseong: Some(syllable.choseong),
+            jungseong: Some(jungseong),
+            jongseong,
+        }
+    }
+
+    fn pop(&mut self) {
+        if let Some(jongseong) = self.jongseong {
+            self.jongseong = match jongseong {
+                (first, Some(_)) => Some((first, None)),
+                (single, None) => single.components().map(|(first, _)| (first, None)),
+            };
+        } else if let Some(jungseong) = self.jungseong {
+            self.jungseong = match jungseong {
+                (first, Some(_)) => Some((first, None)),
+                (single, None) => COMPOUND_VOWELS
+                    .iter()
+                    .find(|&&(_, _, compound)| compound == single)
+                    .map(|&(first, _, _)| (first, None)),
+            };
+        } else {
+            self.choseong = None;
+        }
+    }
+
+    fn to_char(self) -> Option<char> {
+        let jungseong = self
+            .jungseong
+            .map(|(first, second)| second.unwrap_or(first));
+        let jongseong = self
+            .jongseong
+            .map(|(first, second)| second.unwrap_or(first));
+
+        match (self.choseong, jungseong) {
+            (Some(choseong), Some(jungseong)) => {
+                Some(Syllable::from((choseong, jungseong, jongseong)).into())
+            }
+            (Some(choseong), None) => Some(choseong.into()),
+            (None, Some(jungseong)) => Some(jungseong.into()),
+            (None, None) => None,
+        }
+    }
+}
+
+fn combine_vowels(first: Jungseong, second: Jungseong) -> Option<Jungseong> {
+    COMPOUND_VOWELS
+        .iter()
+        .find(|&&(a, b, _)| (a, b) == (first, second))
+        .map(|&(_, _, compound)| compound)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HangulBuilder;
+    use crate::{Jaeum, Jamo, Jungseong};
+
+    fn build(s: &str) -> String {
+        let mut builder = HangulBuilder::new();
+        builder.push_str(s);
+        builder.build()
+    }
+
+    #[test]
+    fn test_hangul_builder_composition() {
+        assert_eq!(build(""), "");
+        assert_eq!(build("ㄱ"), "ㄱ");
+        assert_eq!(build("ㄱㅏ"), "가");
+        assert_eq!(build("ㄱㅏㄱ"), "각");
+        // the conjoining jamo, in whichever position, work all the same.
+        assert_eq!(build("\u{1100}\u{1161}\u{11A8}\u{1161}"), "가가");
+        assert_eq!(build("\u{11A8}\u{1161}\u{1100}"), "각");
+
+        // consonants that cannot go together stay apart.
+        assert_eq!(build("ㄱㄱㅏ"), "ㄱ가");
+        assert_eq!(build("ㄱㅏㄸ"), "가ㄸ");
+        assert_eq!(build("ㄱㅏㄸㅏ"), "가따");
+        assert_eq!(build("ㄳㅏ"), "ㄳㅏ");
+        // vowels likewise.
+        assert_eq!(build("ㅏㅏ"), "ㅏㅏ");
+        assert_eq!(build("ㄱㅏㅏ"), "가ㅏ");
+    }
+
+    #[test]
+    fn test_hangul_builder_resplit() {
+        // a simple final consonant moves on as a whole, 'ㅇ' included.
+        assert_eq!(build("ㄱㅏㄴㅏ"), "가나");
+        assert_eq!(build("ㅇㅏㅇㅏ"), "아아");
+        // a clustered one leaves its first component behind, whether pushed in two...
+        assert_eq!(build("ㄷㅏㄹㄱ"), "닭");
+        assert_eq!(build("ㄷㅏㄹㄱㅣ"), "달기");
+        assert_eq!(build("ㄱㅏㅂㅅㅣ"), "갑시");
+        assert_eq!(build("ㅇㅏㄴㅎㅏ"), "안하");
+        // ...or in one.
+        assert_eq!(build("ㄷㅏㄺㅣ"), "달기");
+        assert_eq!(build("\u{1103}\u{1161}\u{11B0}\u{1175}"), "달기");
+        // only two consonants make a cluster.
+        assert_eq!(build("ㄷㅏㄹㄱㅅ"), "닭ㅅ");
+        assert_eq!(build("ㄷㅏㄹㄱㅅㅏ"), "닭사");
+    }
+
+    #[test]
+    fn test_hangul_builder_compound_vowels() {
+        assert_eq!(build("ㄱㅗㅏ"), "과");
+        assert_eq!(build("ㄱㅗㅐㄴ"), "괜");
+        assert_eq!(build("ㅇㅜㅓㄴ"), "원");
+        assert_eq!(build("ㅇㅡㅣ"), "의");
+        assert_eq!(build("ㅗㅏ"), "ㅘ");
+        assert_eq!(build("ㄱㅗㅏㅏ"), "과ㅏ");
+        // not after the final consonant, though.
+        assert_eq!(build("ㄱㅗㄱㅏ"), "고가");
+    }
+
+    #[test]
+    fn test_hangul_builder_other_characters() {
+        assert_eq!(build("ㅎㅏㄴ ㄱㅡㄹ!"), "한 글!");
+        // a syllable stays open to the jamo following it.
+        assert_eq!(build("가\u{11A8}"), "각");
+        assert_eq!(build("가ㄱㅏ"), "가가");
+        assert_eq!(build("ㄱ가"), "ㄱ가");
+        // an archaic jamo is just another character.
+        assert_eq!(build("ㄱㅏ\u{1140}ㅏ"), "가\u{1140}ㅏ");
+
+        let mut builder = HangulBuilder::new();
+        builder.push_jamo(Jamo::CompatJaeum(Jaeum::Nieun));
+        builder.push_jamo(Jamo::Jungseong(Jungseong::A));
+        assert_eq!(builder.as_pending(), Some('나'));
+        assert_eq!(builder.build(), "나");
+    }
+
+    #[test]
+    fn test_hangul_builder_backspace() {
+        let mut builder = HangulBuilder::new();
+        builder.backspace();
+        assert_eq!(builder.as_pending(), None);
+
+        builder.push_str("ㄷㅏㄹㄱ");
+        for expected in [Some('달'), Some('다'), Some('ㄷ'), None] {
+            builder.backspace();
+            assert_eq!(builder.as_pending(), expected);
+        }
+
+        builder.push_str("ㄱㅗㅏ");
+        builder.backspace();
+        assert_eq!(builder.as_pending(), Some('고'));
+
+        // a committed syllable is reopened, and loses one jamo at a time.
+        let mut builder = HangulBuilder::new();
+        builder.push_str("괅 ");
+        builder.backspace();
+        assert_eq!(builder.as_pending(), None);
+        for expected in [Some('괄'), Some('과'), Some('고'), Some('ㄱ'), None] {
+            builder.backspace();
+            assert_eq!(builder.as_pending(), expected);
+        }
+        assert_eq!(builder.build(), "");
+
+        let mut builder = HangulBuilder::new();
+        builder.push_str("가나");
+        builder.backspace();
+        builder.push_char('ㅓ');
+        assert_eq!(builder.build(), "가너");
+    }
+}
@@ -0,0 +1,140 @@
+//! Bulk conversions processing a whole string at once, for callers across an FFI boundary (e.g.
+//! JavaScript via WebAssembly) to whom calling back per [`char`] is costly.
+use crate::{Choseong, Jongseong, Jungseong, Syllable};
+use alloc::{string::String, vec::Vec};
+use core::convert::TryFrom;
+
+/// The sentinel value [`decompose_to_indices`] emits in place of a [`char`] which is not a
+/// Precomposed Korean [`Syllable`].
+pub const NON_KOREAN: u32 = u32::MAX;
+
+/// Packs every [`char`] in a given string into a [`u32`].
+///
+/// # Packing Format
+/// Each [`Syllable`] is packed into a single [`u32`] as follows, which is guaranteed to stay the
+/// same across releases:
+/// * bits 16 -- 23: the initial consonant, i.e. `Choseong as u32` (0 -- 18),
+/// * bits 8 -- 15: the medial vowel, i.e. `Jungseong as u32` (0 -- 20),
+/// * bits 0 -- 7: the final consonant, i.e. `Jongseong as u32` (1 -- 27), or 0 if there is none,
+/// * bits 24 -- 31: always 0.
+///
+/// Every other [`char`] is represented by [`NON_KOREAN`] (`u32::MAX`).
+///
+/// ```
+/// use unikorn::{decompose_to_indices, NON_KOREAN};
+///
+/// assert_eq!(
+///     decompose_to_indices("한a"),
+///     [18 << 16 | 0 << 8 | 4, NON_KOREAN]
+/// );
+/// ```
+pub fn decompose_to_indices(source: &str) -> Vec<u32> {
+    source
+        .chars()
+        .map(|character| match Syllable::try_from(character) {
+            Ok(syllable) => {
+                (syllable.choseong as u32) << 16
+                    | (syllable.jungseong as u32) << 8
+                    | syllable.jongseong.map_or(0, |jongseong| jongseong as u32)
+            }
+            Err(_) => NON_KOREAN,
+        })
+        .collect()
+}
+
+/// Does the reverse of [`decompose_to_indices`].
+///
+/// Since the original [`char`] is not recoverable from [`NON_KOREAN`], it is turned into U+FFFD
+/// REPLACEMENT CHARACTER '�', as is any other value not following the
+/// [packing format](decompose_to_indices#packing-format).
+///
+/// ```
+/// use unikorn::{compose_from_indices, NON_KOREAN};
+///
+/// assert_eq!(compose_from_indices(&[18 << 16 | 0 << 8 | 4, NON_KOREAN]), "한\u{FFFD}");
+/// ```
+pub fn compose_from_indices(indices: &[u32]) -> String {
+    indices
+        .iter()
+        .map(|&index| unpack(index).map_or(char::REPLACEMENT_CHARACTER, char::from))
+        .collect()
+}
+
+fn unpack(index: u32) -> Option<Syllable> {
+    if index >> 24 != 0 {
+        return None;
+    }
+
+    let choseong = Choseong::try_from((index >> 16) as u8).ok()?;
+    let jungseong = Jungseong::try_from((index >> 8) as u8).ok()?;
+    let jongseong = match index as u8 {
+        0 => None,
+        jongseong => Some(Jongseong::try_from(jongseong).ok()?),
+    };
+
+    Some(Syllable {
+        choseong,
+        jungseong,
+        jongseong,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compose_from_indices, decompose_to_indices, NON_KOREAN};
+
+    #[test]
+    fn test_decompose_to_indices() {
+        assert_eq!(decompose_to_indices("가"), [0]);
+        assert_eq!(decompose_to_indices("각"), [1]);
+        assert_eq!(decompose_to_indices("힣"), [18 << 16 | 20 << 8 | 27]);
+        assert_eq!(
+            decompose_to_indices("a ㄱ😀"),
+            [NON_KOREAN, NON_KOREAN, NON_KOREAN, NON_KOREAN]
+        );
+        assert!(decompose_to_indices("").is_empty());
+    }
+
+    #[test]
+    fn test_compose_from_indices() {
+        assert_eq!(compose_from_indices(&[0, 1, NON_KOREAN]), "가각\u{FFFD}");
+
+        // out of range in each field
+        assert_eq!(
+            compose_from_indices(&[19 << 16, 21 << 8, 28, 1 << 24]),
+            "\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}"
+        );
+        assert_eq!(compose_from_indices(&[]), "");
+    }
+
+    #[test]
+    fn test_indices_round_trip() {
+        let syllables = (0xAC00..=0xD7A3)
+            .map(|code_point| char::from_u32(code_point).unwrap())
+            .collect::<String>();
+        let indices = decompose_to_indices(&syllables);
+        assert_eq!(indices.len(), 11172);
+        assert!(!indices.contains(&NON_KOREAN));
+        assert_eq!(compose_from_indices(&indices), syllables);
+
+        // every non-Korean char is replaced, but the rest stays in place.
+        let mixed = (0..0x20000)
+            .step_by(97)
+            .filter_map(char::from_u32)
+            .collect::<String>();
+        let expected = mixed
+            .chars()
+            .map(|character| {
+                if ('가'..='힣').contains(&character) {
+                    character
+                } else {
+                    '\u{FFFD}'
+                }
+            })
+            .collect::<String>();
+        assert_eq!(
+            compose_from_indices(&decompose_to_indices(&mixed)),
+            expected
+        );
+    }
+}
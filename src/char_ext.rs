@@ -0,0 +1,181 @@
+//! Korean-specific extensions to [`char`].
+use crate::{Choseong, Jongseong, Jungseong, Syllable};
+use core::convert::TryFrom;
+
+/// Extends [`char`] with Korean-specific predicates and conversions, so that they can be called
+/// with the method-call syntax.
+///
+/// ```
+/// use unikorn::{CharExt, Choseong};
+///
+/// assert!('한'.is_korean_syllable());
+/// assert_eq!('ㄱ'.to_choseong(), Some(Choseong::Kiyeok));
+/// ```
+pub trait CharExt: Copy {
+    /// Checks if this is a Precomposed Korean [`Syllable`] (U+AC00 '가' -- U+D7A3 '힣').
+    fn is_korean_syllable(self) -> bool;
+
+    /// Checks if this is in Hangul Jamo (U+1100 -- U+11FF), Hangul Jamo Extended-A (U+A960 --
+    /// U+A97C), or Hangul Jamo Extended-B (U+D7B0 -- U+D7FB) range, i.e. a conjoining jamo,
+    /// including the archaic ones and the fillers.
+    fn is_korean_jamo(self) -> bool;
+
+    /// Checks if this is in Hangul Compatibility Jamo range (U+3131 'ㄱ' -- U+318E 'ㆎ'),
+    /// including the archaic ones.
+    fn is_korean_compatibility_jamo(self) -> bool;
+
+    /// Checks if this is a Halfwidth Hangul jamo (U+FFA0 -- U+FFDC), including the halfwidth
+    /// filler.
+    fn is_korean_halfwidth(self) -> bool;
+
+    /// Converts this into a [`Syllable`], if it is one.
+    fn to_syllable(self) -> Option<Syllable>;
+
+    /// Converts this into an initial consonant, if it is one in either the compatibility (e.g.
+    /// 'ㄱ') or the conjoining (e.g. U+1100 'ᄀ') form.
+    fn to_choseong(self) -> Option<Choseong>;
+
+    /// Converts this into a medial vowel, if it is one in either the compatibility (e.g. 'ㅏ') or
+    /// the conjoining (e.g. U+1161 'ᅡ') form.
+    fn to_jungseong(self) -> Option<Jungseong>;
+
+    /// Converts this into a final consonant, if it is one in either the compatibility (e.g. 'ㄳ')
+    /// or the conjoining (e.g. U+11AA 'ᆪ') form.
+    fn to_jongseong(self) -> Option<Jongseong>;
+}
+impl CharExt for char {
+    fn is_korean_syllable(self) -> bool {
+        Syllable::is_one_of_us(self)
+    }
+
+    fn is_korean_jamo(self) -> bool {
+        matches!(self, '\u{1100}'..='\u{11FF}' | '\u{A960}'..='\u{A97C}' | '\u{D7B0}'..='\u{D7FB}')
+    }
+
+    fn is_korean_compatibility_jamo(self) -> bool {
+        ('\u{3131}'..='\u{318E}').contains(&self)
+    }
+
+    fn is_korean_halfwidth(self) -> bool {
+        // Halfwidth Hangul leaves a few code points unassigned in between the vowels.
+        matches!(
+            self,
+            '\u{FFA0}'..='\u{FFBE}'
+                | '\u{FFC2}'..='\u{FFC7}'
+                | '\u{FFCA}'..='\u{FFCF}'
+                | '\u{FFD2}'..='\u{FFD7}'
+                | '\u{FFDA}'..='\u{FFDC}'
+        )
+    }
+
+    fn to_syllable(self) -> Option<Syllable> {
+        Syllable::try_from(self).ok()
+    }
+
+    fn to_choseong(self) -> Option<Choseong> {
+        Choseong::try_from(self)
+            .or_else(|_| Choseong::from_conjoining(self))
+            .ok()
+    }
+
+    fn to_jungseong(self) -> Option<Jungseong> {
+        Jungseong::try_from(self)
+            .or_else(|_| Jungseong::from_conjoining(self))
+            .ok()
+    }
+
+    fn to_jongseong(self) -> Option<Jongseong> {
+        Jongseong::try_from(self)
+            .or_else(|_| Jongseong::from_conjoining(self))
+            .ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CharExt;
+    use crate::{Choseong, Jongseong, Jungseong, Syllable};
+    use core::convert::TryFrom;
+
+    #[test]
+    fn test_is_korean_syllable() {
+        assert!('가'.is_korean_syllable());
+        assert!('힣'.is_korean_syllable());
+
+        assert!(!'ㄱ'.is_korean_syllable());
+        assert!(!'\u{D7A4}'.is_korean_syllable());
+        assert!(!'a'.is_korean_syllable());
+    }
+
+    #[test]
+    fn test_is_korean_jamo() {
+        assert!('\u{1100}'.is_korean_jamo());
+        assert!('\u{11FF}'.is_korean_jamo());
+        assert!('\u{A960}'.is_korean_jamo());
+        assert!('\u{A97C}'.is_korean_jamo());
+        assert!('\u{D7B0}'.is_korean_jamo());
+        assert!('\u{D7FB}'.is_korean_jamo());
+
+        assert!(!'\u{10FF}'.is_korean_jamo());
+        assert!(!'\u{A97D}'.is_korean_jamo());
+        assert!(!'\u{D7FC}'.is_korean_jamo());
+        assert!(!'ㄱ'.is_korean_jamo());
+        assert!(!'가'.is_korean_jamo());
+    }
+
+    #[test]
+    fn test_is_korean_compatibility_jamo() {
+        assert!('ㄱ'.is_korean_compatibility_jamo());
+        assert!('ㅣ'.is_korean_compatibility_jamo());
+        assert!('\u{3165}'.is_korean_compatibility_jamo());
+        assert!('\u{318E}'.is_korean_compatibility_jamo());
+
+        assert!(!'\u{3130}'.is_korean_compatibility_jamo());
+        assert!(!'\u{1100}'.is_korean_compatibility_jamo());
+    }
+
+    #[test]
+    fn test_is_korean_halfwidth() {
+        assert!('\u{FFA0}'.is_korean_halfwidth());
+        assert!('\u{FFA1}'.is_korean_halfwidth());
+        assert!('\u{FFDC}'.is_korean_halfwidth());
+
+        assert!(!'\u{FFC0}'.is_korean_halfwidth());
+        assert!(!'\u{FFC8}'.is_korean_halfwidth());
+        assert!(!'\u{FFDD}'.is_korean_halfwidth());
+        assert!(!'ㄱ'.is_korean_halfwidth());
+    }
+
+    #[test]
+    fn test_to_syllable() {
+        assert_eq!('각'.to_syllable(), Syllable::try_from('각').ok());
+        assert_eq!('ㄱ'.to_syllable(), None);
+    }
+
+    #[test]
+    fn test_to_choseong() {
+        assert_eq!('ㄲ'.to_choseong(), Some(Choseong::SsangKiyeok));
+        assert_eq!('\u{1101}'.to_choseong(), Some(Choseong::SsangKiyeok));
+
+        assert_eq!('ㄳ'.to_choseong(), None);
+        assert_eq!('\u{11A8}'.to_choseong(), None);
+        assert_eq!('가'.to_choseong(), None);
+    }
+
+    #[test]
+    fn test_to_jungseong() {
+        assert_eq!('ㅘ'.to_jungseong(), Some(Jungseong::Wa));
+        assert_eq!('\u{116A}'.to_jungseong(), Some(Jungseong::Wa));
+
+        assert_eq!('ㄱ'.to_jungseong(), None);
+    }
+
+    #[test]
+    fn test_to_jongseong() {
+        assert_eq!('ㄳ'.to_jongseong(), Some(Jongseong::KiyeokSios));
+        assert_eq!('\u{11AA}'.to_jongseong(), Some(Jongseong::KiyeokSios));
+
+        assert_eq!('ㄸ'.to_jongseong(), None);
+        assert_eq!('\u{1100}'.to_jongseong(), None);
+    }
+}
@@ -0,0 +1,286 @@
+//! Sorting strings in Korean dictionary order (가나다순, Ganadasun).
+use crate::{write::compose_conjoining, Choseong, Jaeum, Jungseong, Moeum, Syllable};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{cmp::Ordering, convert::TryFrom};
+
+/// Wraps a string-like value so that it is ordered by [`korean_cmp`] rather than by raw Unicode
+/// code points.
+///
+/// Useful whenever an [`Ord`] implementation is required, e.g. sorting with
+/// [`slice::sort`] or storing in a [`BTreeSet`](std::collections::BTreeSet):
+/// ```
+/// use unikorn::KoreanOrd;
+///
+/// let mut words = vec![KoreanOrd("나"), KoreanOrd("ㄴ"), KoreanOrd("가"), KoreanOrd("ㄱ")];
+/// words.sort();
+/// assert_eq!(
+///     words.iter().map(|word| word.0).collect::<Vec<_>>(),
+///     ["ㄱ", "가", "ㄴ", "나"]
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct KoreanOrd<S: AsRef<str>>(pub S);
+impl<S: AsRef<str>> Eq for KoreanOrd<S> {}
+impl<S: AsRef<str>> Ord for KoreanOrd<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        korean_cmp(self.0.as_ref(), other.0.as_ref())
+    }
+}
+impl<S: AsRef<str>> PartialEq for KoreanOrd<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl<S: AsRef<str>> PartialOrd for KoreanOrd<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares two strings in Korean dictionary order.
+///
+/// Precomposed Korean [`Syllable`]s are compared by their initial consonant, medial vowel, and
+/// final consonant in that order. Unlike raw Unicode code point order, standalone consonants and
+/// vowels (Hangul Compatibility Jamo) are sorted alongside the syllables they would begin, e.g.
+/// 'ㄴ' comes right after '깋' and right before '나'. Everything else keeps its Unicode order,
+/// interleaved with the Korean portions as if those were still occupying the Precomposed Korean
+/// Syllables range (U+AC00 '가' -- U+D7A3 '힣'), e.g. Latin comes before Korean and emoji after.
+///
+/// A run of modern conjoining jamo (Hangul Jamo range, U+1100 -- U+11FF) is composed into a
+/// syllable beforehand, so that the decomposed text (NFD) sorts the same as the precomposed. The
+/// archaic jamo have no place in the modern order, and thus keep their Unicode order as well, e.g.
+/// 'ㆍ' (U+318D) comes before any syllable.
+///
+/// ```
+/// use unikorn::korean_cmp;
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(korean_cmp("가방", "가위"), Ordering::Less);
+/// assert_eq!(korean_cmp("ㄴ", "가"), Ordering::Greater); // raw code point order says otherwise
+/// assert_eq!(korean_cmp("abc", "가"), Ordering::Less);
+/// ```
+pub fn korean_cmp(a: &str, b: &str) -> Ordering {
+    sort_keys_of(a).cmp(sort_keys_of(b))
+}
+
+/// Produces a sort key for a given string, comparing which yields the same result as
+/// [`korean_cmp`].
+///
+/// Each [`char`], or each run of conjoining jamo composing into a syllable, contributes exactly one
+/// element to the key, so that the key can be computed once and cached when sorting large
+/// collections:
+/// ```
+/// use unikorn::korean_sort_key;
+///
+/// let mut words = vec!["나무", "ㄱ", "가지", "A"];
+/// words.sort_by_cached_key(|word| korean_sort_key(word));
+/// assert_eq!(words, ["A", "ㄱ", "가지", "나무"]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn korean_sort_key(s: &str) -> Vec<u32> {
+    sort_keys_of(s).collect()
+}
+
+/// Produces a collation key for a given string, with every [`Syllable`] decomposed into its
+/// components.
+///
+/// Each [`Syllable`] contributes three elements: its initial consonant, its medial vowel, and its
+/// final consonant (or lack thereof). Standalone vowels contribute three elements as well, lacking
+/// the final consonant, and everything else contributes a single element derived from its Unicode
+/// code point. The elements are offset in such a way that comparing two keys lexicographically
+/// yields the same result as [`korean_cmp`]:
+/// ```
+/// use unikorn::korean_collation_key;
+///
+/// assert_eq!(korean_collation_key("가").len(), 3);
+/// assert_eq!(korean_collation_key("ABC").len(), 3);
+/// assert!(korean_collation_key("가") < korean_collation_key("각"));
+/// assert!(korean_collation_key("각") < korean_collation_key("개"));
+/// assert!(korean_collation_key("Z") < korean_collation_key("가"));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn korean_collation_key(s: &str) -> Vec<u32> {
+    let mut key = Vec::with_capacity(s.len());
+
+    for (_, character) in compose_conjoining(s) {
+        if let Ok(syllable) = Syllable::try_from(character) {
+            key.push(first_syllable_of(syllable.choseong) * SPREAD);
+            key.push(1 + syllable.jungseong as u32);
+            key.push(1 + syllable.jongseong.map_or(0, |jongseong| jongseong as u32));
+        } else if let Ok(moeum) = Moeum::try_from(character) {
+            key.push(first_syllable_of(Choseong::Ieung) * SPREAD);
+            key.push(1 + moeum as u32);
+            key.push(0);
+        } else {
+            key.push(sort_key_of(character));
+        }
+    }
+
+    key
+}
+
+// every code point is spread out by this factor, so that the gaps in between can accommodate
+// standalone jamo right before the syllables they would begin.
+const SPREAD: u32 = 64;
+
+fn sort_keys_of(s: &str) -> impl Iterator<Item = u32> + '_ {
+    compose_conjoining(s).map(|(_, character)| sort_key_of(character))
+}
+
+fn sort_key_of(character: char) -> u32 {
+    if Syllable::is_one_of_us(character) {
+        return character as u32 * SPREAD;
+    }
+
+    if let Ok(jaeum) = Jaeum::try_from(character) {
+        if let Ok(choseong) = Choseong::try_from(jaeum) {
+            return first_syllable_of(choseong) * SPREAD - SPREAD / 2;
+        }
+
+        // clustered consonants (e.g. 'ㄳ') never begin a syllable, so they go right after every
+        // syllable of the preceding initial consonant, keeping their relative order.
+        let mut distance = 1;
+        while let Ok(next) = Jaeum::try_from(jaeum as u8 + distance) {
+            if let Ok(choseong) = Choseong::try_from(next) {
+                return first_syllable_of(choseong) * SPREAD - SPREAD / 2 - distance as u32;
+            }
+            distance += 1;
+        }
+    }
+
+    if let Ok(moeum) = Moeum::try_from(character) {
+        // a standalone vowel goes right before the 'ㅇ'-initial syllable of the same vowel.
+        let syllable = Syllable::from(moeum);
+        return char::from(syllable) as u32 * SPREAD - SPREAD / 4;
+    }
+
+    character as u32 * SPREAD
+}
+
+fn first_syllable_of(choseong: Choseong) -> u32 {
+    char::from(Syllable::from((choseong, Jungseong::A))) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{korean_cmp, KoreanOrd};
+    #[cfg(feature = "alloc")]
+    use super::{korean_collation_key, korean_sort_key};
+    use core::cmp::Ordering;
+
+    #[test]
+    fn test_korean_cmp() {
+        assert_eq!(korean_cmp("가", "가"), Ordering::Equal);
+        assert_eq!(korean_cmp("가", "각"), Ordering::Less);
+        assert_eq!(korean_cmp("각", "개"), Ordering::Less);
+        assert_eq!(korean_cmp("가나", "가"), Ordering::Greater);
+
+        // standalone jamo
+        assert_eq!(korean_cmp("ㄱ", "가"), Ordering::Less);
+        assert_eq!(korean_cmp("ㄲ", "깋"), Ordering::Greater);
+        assert_eq!(korean_cmp("ㄲ", "까"), Ordering::Less);
+        assert_eq!(korean_cmp("ㄳ", "깋"), Ordering::Greater);
+        assert_eq!(korean_cmp("ㄳ", "ㄴ"), Ordering::Less);
+        assert_eq!(korean_cmp("ㄵ", "ㄶ"), Ordering::Less);
+        assert_eq!(korean_cmp("ㄶ", "닣"), Ordering::Greater);
+        assert_eq!(korean_cmp("ㅇ", "ㅏ"), Ordering::Less);
+        assert_eq!(korean_cmp("ㅏ", "아"), Ordering::Less);
+        assert_eq!(korean_cmp("ㅐ", "앟"), Ordering::Greater);
+
+        // non-korean characters
+        assert_eq!(korean_cmp("z", "ㄱ"), Ordering::Less);
+        assert_eq!(korean_cmp("가a", "가b"), Ordering::Less);
+        assert_eq!(korean_cmp("😀", "힣"), Ordering::Greater);
+
+        // the decomposed syllables
+        assert_eq!(
+            korean_cmp("\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}", "한글"),
+            Ordering::Equal
+        );
+        assert_eq!(korean_cmp("\u{1100}\u{1161}", "각"), Ordering::Less);
+        assert_eq!(korean_cmp("가\u{11A8}", "간"), Ordering::Less);
+        assert_eq!(korean_cmp("\u{1102}\u{1161}", "ㄴ"), Ordering::Greater);
+
+        // the archaic jamo
+        assert_eq!(korean_cmp("ㆍ", "가"), Ordering::Less);
+        assert_eq!(korean_cmp("ㆍ", "z"), Ordering::Greater);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_korean_sort_key() {
+        let mut words = vec!["하늘", "ㅎ", "가을", "ㄱㄴ", "가", "바다", "ㅂ"];
+        words.sort_by_key(|word| korean_sort_key(word));
+        assert_eq!(words, ["ㄱㄴ", "가", "가을", "ㅂ", "바다", "ㅎ", "하늘"]);
+
+        let mut words = vec!["낙", "나", "난", "가"];
+        words.sort_by_cached_key(|word| korean_sort_key(word));
+        assert_eq!(words, ["가", "나", "낙", "난"]);
+
+        let mut words = vec!["\u{1102}\u{1161}\u{11AB}", "낙", "\u{1102}\u{1161}"];
+        words.sort_by_cached_key(|word| korean_sort_key(word));
+        assert_eq!(
+            words,
+            ["\u{1102}\u{1161}", "낙", "\u{1102}\u{1161}\u{11AB}"]
+        );
+
+        assert_eq!(korean_sort_key("").len(), 0);
+        assert_eq!(korean_sort_key("a가ㄱ").len(), 3);
+        assert_eq!(
+            korean_sort_key("\u{1100}\u{1161}\u{11A8}"),
+            korean_sort_key("각")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_korean_collation_key() {
+        assert_eq!(
+            korean_collation_key("각a"),
+            [0xAC00 * 64, 1, 2, 'a' as u32 * 64]
+        );
+
+        let words = [
+            "",
+            "a",
+            "ㄱ",
+            "가",
+            "가a",
+            "각",
+            "개",
+            "ㄳ",
+            "ㄴ",
+            "나",
+            "ㅇ",
+            "ㅏ",
+            "아",
+            "앟",
+            "ㅐ",
+            "애",
+            "힣",
+            "😀",
+            "\u{1100}\u{1161}",
+            "\u{1100}\u{1161}\u{11A8}",
+            "ㆍ",
+        ];
+        for a in words.iter() {
+            for b in words.iter() {
+                assert_eq!(
+                    korean_collation_key(a).cmp(&korean_collation_key(b)),
+                    korean_cmp(a, b),
+                    "{} vs. {}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_korean_ord() {
+        assert!(KoreanOrd("ㄷ") < KoreanOrd("다"));
+        assert!(KoreanOrd(String::from("닭")) > KoreanOrd(String::from("달")));
+        assert_eq!(KoreanOrd("말"), KoreanOrd("말"));
+    }
+}
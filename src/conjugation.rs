@@ -0,0 +1,79 @@
+//! Contracting the last syllable of a verb or an adjective stem (어간, Eogan) with the first
+//! syllable of an ending (어미, Eomi), as done all over Korean conjugation (활용, Hwaryong).
+use crate::{Choseong, Syllable};
+
+/// Contracts the last syllable of a stem with the first syllable of an ending beginning with
+/// '아' or '어' (or '여' after '하'), e.g. '오' and '아' into '와' as in '와서', following the
+/// regular vowel fusion rules; see [`Jungseong::fuse`](crate::Jungseong::fuse).
+///
+/// The contracted syllable takes the initial consonant from the stem, and the final consonant, if
+/// any, from the ending, e.g. '가' and '았' into '갔'.
+///
+/// Returns [`None`] if the two do not contract, i.e. if the stem syllable ends in a consonant, the
+/// ending syllable does not begin with a silent 'ㅇ', or the vowels do not fuse, e.g. '쉬' and
+/// '어'.
+///
+/// ```
+/// use unikorn::{contract_vowels, Syllable};
+/// use std::convert::TryFrom;
+///
+/// let syllable = |character| Syllable::try_from(character).unwrap();
+/// assert_eq!(contract_vowels(syllable('우'), syllable('어')), Some(syllable('워'))); // 배워
+/// assert_eq!(contract_vowels(syllable('쓰'), syllable('었')), Some(syllable('썼')));
+/// assert_eq!(contract_vowels(syllable('먹'), syllable('어')), None);
+/// ```
+pub fn contract_vowels(stem_last: Syllable, ending_first: Syllable) -> Option<Syllable> {
+    if stem_last.jongseong.is_some() || ending_first.choseong != Choseong::Ieung {
+        return None;
+    }
+
+    Some(Syllable {
+        jungseong: stem_last.jungseong.fuse(ending_first.jungseong)?,
+        jongseong: ending_first.jongseong,
+        ..stem_last
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::contract_vowels;
+    use crate::Syllable;
+    use core::convert::TryFrom;
+
+    fn syllable(character: char) -> Syllable {
+        Syllable::try_from(character).unwrap()
+    }
+
+    #[test]
+    fn test_contract_vowels() {
+        for (stem_last, ending_first, contracted) in [
+            ('가', '아', '가'),
+            ('서', '어', '서'),
+            ('오', '아', '와'),
+            ('우', '어', '워'),
+            ('쓰', '어', '써'),
+            ('쁘', '아', '빠'),
+            ('되', '어', '돼'),
+            ('하', '여', '해'),
+            ('시', '어', '셔'),
+            ('내', '어', '내'),
+            ('가', '았', '갔'),
+            ('하', '였', '했'),
+            ('보', '았', '봤'),
+        ] {
+            assert_eq!(
+                contract_vowels(syllable(stem_last), syllable(ending_first)),
+                Some(syllable(contracted)),
+                "{} + {}",
+                stem_last,
+                ending_first
+            );
+        }
+
+        // a closed stem syllable, an ending not beginning with a silent 'ㅇ', and unfusable vowels.
+        assert_eq!(contract_vowels(syllable('먹'), syllable('어')), None);
+        assert_eq!(contract_vowels(syllable('가'), syllable('고')), None);
+        assert_eq!(contract_vowels(syllable('쉬'), syllable('어')), None);
+        assert_eq!(contract_vowels(syllable('가'), syllable('어')), None);
+    }
+}
@@ -0,0 +1,334 @@
+//! Conversions from and into EUC-KR, the legacy encoding based on KS X 1001, which only contains
+//! 2,350 out of the 11,172 modern Korean syllables.
+//!
+//! Only the Korean portion of KS X 1001, i.e. syllables and Hangul Compatibility Jamo, and ASCII
+//! are supported; symbols and Hanja are not.
+#[cfg(feature = "alloc")]
+use crate::Error;
+use crate::Syllable;
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::convert::TryFrom;
+
+/// Determines if a given [`Syllable`] is one of the 2,350 syllables in KS X 1001, i.e. whether it
+/// survives a round trip through EUC-KR.
+///
+/// ```
+/// use unikorn::{is_ksx1001_syllable, Syllable};
+///
+/// assert!(is_ksx1001_syllable(Syllable::try_from('뷰').unwrap()));
+/// assert!(!is_ksx1001_syllable(Syllable::try_from('뷁').unwrap()));
+/// ```
+pub fn is_ksx1001_syllable(syllable: Syllable) -> bool {
+    ksx1001_index(syllable).is_some()
+}
+
+/// Decodes an EUC-KR-encoded byte sequence into a [`String`].
+///
+/// ```
+/// use unikorn::{decode_euckr, Error};
+///
+/// assert_eq!(decode_euckr(&[0xC7, 0xD1, 0xB1, 0xDB, b'!']), Ok(String::from("한글!")));
+/// assert_eq!(decode_euckr(&[0xA1, 0xA1]), Err(Error::NonEucKr(0xA1A1)));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_euckr(bytes: &[u8]) -> Result<String, Error> {
+    let mut decoded = String::with_capacity(bytes.len() * 3 / 2);
+
+    let mut bytes = bytes.iter().copied();
+    while let Some(leading) = bytes.next() {
+        if leading < 0x80 {
+            decoded.push(leading as char);
+            continue;
+        }
+
+        let trailing = bytes.next().ok_or(Error::NonEucKr(leading as u16))?;
+        let code = (leading as u16) << 8 | trailing as u16;
+        if !(0xA1..=0xFE).contains(&trailing) {
+            return Err(Error::NonEucKr(code));
+        }
+
+        let character = match leading {
+            // Hangul Compatibility Jamo, in the very same order as in Unicode.
+            0xA4 if trailing <= 0xD3 => char::from_u32(0x3131 + (trailing - 0xA1) as u32),
+            0xB0..=0xC8 => {
+                let index = (leading - 0xB0) as usize * 94 + (trailing - 0xA1) as usize;
+                char::from_u32(0xAC00 + KSX1001_SYLLABLES[index] as u32)
+            }
+            _ => None,
+        };
+        decoded.push(character.ok_or(Error::NonEucKr(code))?);
+    }
+
+    Ok(decoded)
+}
+
+/// Encodes a string into an EUC-KR-encoded byte sequence.
+///
+/// Only ASCII, the 2,350 Precomposed Korean [`Syllable`]s in KS X 1001 (see
+/// [`is_ksx1001_syllable`]), and Hangul Compatibility Jamo are representable.
+///
+/// ```
+/// use unikorn::{encode_euckr, Error};
+///
+/// assert_eq!(encode_euckr("한글!"), Ok(vec![0xC7, 0xD1, 0xB1, 0xDB, b'!']));
+/// assert_eq!(encode_euckr("뷁"), Err(Error::NotRepresentableInEucKr('뷁')));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_euckr(source: &str) -> Result<Vec<u8>, Error> {
+    let mut encoded = Vec::with_capacity(source.len());
+
+    for character in source.chars() {
+        if character.is_ascii() {
+            encoded.push(character as u8);
+        } else if ('\u{3131}'..='\u{3163}').contains(&character) {
+            encoded.push(0xA4);
+            encoded.push(0xA1 + (character as u32 - 0x3131) as u8);
+        } else {
+            let index = Syllable::try_from(character)
+                .ok()
+                .and_then(ksx1001_index)
+                .ok_or(Error::NotRepresentableInEucKr(character))?;
+            encoded.push(0xB0 + (index / 94) as u8);
+            encoded.push(0xA1 + (index % 94) as u8);
+        }
+    }
+
+    Ok(encoded)
+}
+
+fn ksx1001_index(syllable: Syllable) -> Option<usize> {
+    let offset = (char::from(syllable) as u32 - 0xAC00) as u16;
+
+    KSX1001_SYLLABLES.binary_search(&offset).ok()
+}
+
+// offsets from U+AC00 '가' of the syllables in KS X 1001, in the order of their EUC-KR codes (from
+// 0xB0A1 to 0xC8FE), which happens to be the ascending order of their Unicode code points as well.
+#[rustfmt::skip]
+const KSX1001_SYLLABLES: [u16; 2350] = [
+    0, 1, 4, 7, 8, 9, 10, 16, 17, 18, 19, 20, 21, 22, 23, 25,
+    26, 27, 28, 29, 32, 36, 44, 45, 47, 48, 49, 56, 57, 60, 64, 75,
+    77, 84, 88, 92, 112, 113, 116, 119, 120, 122, 128, 129, 131, 132, 133, 134,
+    137, 138, 139, 140, 144, 148, 156, 157, 159, 160, 161, 168, 169, 170, 172, 175,
+    176, 184, 185, 187, 188, 189, 193, 196, 200, 204, 213, 215, 224, 225, 228, 231,
+    232, 234, 236, 239, 240, 241, 243, 245, 246, 252, 253, 256, 260, 262, 268, 269,
+    271, 273, 280, 284, 288, 297, 300, 301, 308, 309, 312, 316, 324, 325, 327, 329,
+    336, 340, 344, 353, 355, 364, 365, 368, 371, 372, 373, 374, 379, 380, 381, 383,
+    385, 386, 392, 393, 396, 400, 412, 413, 420, 439, 448, 449, 452, 456, 464, 465,
+    467, 476, 480, 484, 504, 505, 508, 511, 512, 513, 520, 521, 523, 525, 532, 560,
+    561, 564, 567, 568, 570, 576, 577, 579, 581, 582, 586, 588, 589, 590, 592, 596,
+    598, 604, 605, 607, 608, 609, 613, 616, 617, 620, 624, 632, 633, 635, 636, 637,
+    644, 645, 652, 700, 701, 702, 704, 708, 716, 717, 719, 720, 721, 728, 729, 732,
+    744, 747, 749, 756, 760, 764, 775, 776, 781, 784, 812, 813, 816, 818, 820, 828,
+    829, 831, 833, 834, 835, 840, 841, 848, 860, 861, 868, 869, 889, 896, 900, 904,
+    912, 913, 917, 924, 952, 953, 956, 960, 967, 968, 969, 971, 973, 974, 980, 988,
+    1000, 1001, 1008, 1009, 1012, 1016, 1024, 1025, 1028, 1036, 1040, 1044, 1052, 1053, 1064, 1092,
+    1093, 1096, 1098, 1100, 1102, 1107, 1108, 1109, 1111, 1113, 1117, 1148, 1149, 1152, 1156, 1164,
+    1165, 1167, 1169, 1176, 1177, 1178, 1180, 1183, 1184, 1185, 1186, 1192, 1193, 1195, 1196, 1197,
+    1198, 1199, 1201, 1203, 1204, 1205, 1208, 1212, 1220, 1221, 1223, 1224, 1225, 1232, 1233, 1236,
+    1240, 1248, 1253, 1288, 1289, 1291, 1292, 1296, 1298, 1299, 1304, 1305, 1307, 1308, 1309, 1315,
+    1316, 1317, 1320, 1324, 1332, 1333, 1335, 1336, 1337, 1344, 1345, 1348, 1352, 1360, 1361, 1364,
+    1365, 1368, 1372, 1376, 1400, 1401, 1404, 1408, 1410, 1416, 1417, 1419, 1421, 1426, 1427, 1428,
+    1432, 1436, 1448, 1484, 1488, 1492, 1500, 1501, 1503, 1512, 1513, 1516, 1520, 1529, 1531, 1533,
+    1540, 1541, 1544, 1547, 1548, 1556, 1557, 1559, 1561, 1568, 1588, 1596, 1624, 1628, 1632, 1640,
+    1641, 1652, 1653, 1660, 1668, 1669, 1673, 1680, 1681, 1684, 1688, 1689, 1690, 1696, 1697, 1699,
+    1701, 1702, 1706, 1708, 1712, 1716, 1736, 1737, 1740, 1744, 1746, 1752, 1753, 1755, 1757, 1762,
+    1764, 1765, 1766, 1768, 1771, 1772, 1773, 1774, 1775, 1779, 1780, 1781, 1783, 1784, 1785, 1786,
+    1787, 1791, 1792, 1793, 1796, 1800, 1808, 1809, 1811, 1812, 1813, 1820, 1876, 1877, 1878, 1880,
+    1883, 1884, 1886, 1887, 1892, 1893, 1895, 1897, 1899, 1902, 1904, 1905, 1908, 1912, 1920, 1921,
+    1923, 1924, 1925, 1932, 1936, 1940, 1952, 1953, 1960, 1964, 1988, 1989, 1992, 1995, 1996, 1998,
+    2000, 2004, 2005, 2007, 2009, 2011, 2013, 2016, 2020, 2024, 2044, 2064, 2072, 2076, 2080, 2088,
+    2089, 2091, 2100, 2128, 2129, 2132, 2136, 2144, 2145, 2147, 2149, 2156, 2176, 2184, 2205, 2212,
+    2216, 2220, 2229, 2231, 2233, 2240, 2244, 2248, 2256, 2261, 2268, 2269, 2272, 2275, 2276, 2278,
+    2284, 2285, 2287, 2289, 2296, 2324, 2325, 2328, 2331, 2332, 2340, 2341, 2343, 2344, 2345, 2346,
+    2352, 2353, 2356, 2360, 2368, 2369, 2371, 2372, 2373, 2379, 2380, 2381, 2384, 2388, 2396, 2397,
+    2399, 2400, 2401, 2464, 2465, 2468, 2472, 2474, 2475, 2480, 2481, 2483, 2484, 2485, 2491, 2492,
+    2493, 2496, 2500, 2508, 2509, 2511, 2512, 2513, 2520, 2540, 2576, 2577, 2580, 2584, 2597, 2604,
+    2612, 2632, 2660, 2664, 2716, 2717, 2720, 2724, 2731, 2732, 2737, 2772, 2800, 2804, 2808, 2816,
+    2817, 2821, 2856, 2857, 2860, 2863, 2864, 2872, 2873, 2875, 2884, 2888, 2892, 2900, 2901, 2912,
+    2916, 2920, 2928, 2929, 2931, 2933, 2940, 2941, 2944, 2948, 2956, 2957, 2959, 2960, 2961, 2962,
+    2966, 2967, 2968, 2969, 2972, 2976, 2984, 2985, 2987, 2988, 2989, 2996, 2997, 3000, 3015, 3017,
+    3052, 3053, 3056, 3060, 3068, 3069, 3071, 3072, 3073, 3079, 3080, 3081, 3084, 3088, 3096, 3097,
+    3099, 3101, 3108, 3109, 3112, 3116, 3124, 3125, 3127, 3128, 3129, 3136, 3140, 3153, 3155, 3164,
+    3165, 3168, 3172, 3180, 3181, 3183, 3185, 3192, 3196, 3213, 3240, 3248, 3252, 3256, 3264, 3265,
+    3267, 3269, 3276, 3280, 3284, 3293, 3295, 3297, 3304, 3305, 3308, 3312, 3320, 3321, 3323, 3325,
+    3332, 3352, 3360, 3388, 3389, 3392, 3396, 3404, 3407, 3409, 3416, 3417, 3420, 3424, 3432, 3433,
+    3435, 3437, 3444, 3445, 3448, 3452, 3460, 3461, 3463, 3465, 3466, 3469, 3470, 3500, 3501, 3504,
+    3508, 3516, 3517, 3519, 3521, 3528, 3529, 3532, 3534, 3535, 3536, 3537, 3538, 3544, 3545, 3547,
+    3549, 3550, 3553, 3555, 3556, 3557, 3560, 3564, 3572, 3573, 3575, 3576, 3577, 3578, 3584, 3585,
+    3592, 3605, 3640, 3641, 3644, 3648, 3650, 3656, 3657, 3659, 3661, 3662, 3667, 3668, 3669, 3672,
+    3676, 3684, 3685, 3687, 3688, 3689, 3696, 3697, 3700, 3704, 3715, 3716, 3717, 3719, 3724, 3752,
+    3753, 3755, 3756, 3760, 3762, 3768, 3769, 3771, 3773, 3780, 3784, 3800, 3801, 3836, 3840, 3844,
+    3853, 3855, 3857, 3864, 3868, 3872, 3881, 3883, 3892, 3893, 3894, 3896, 3899, 3900, 3901, 3902,
+    3908, 3909, 3911, 3913, 3917, 3919, 3920, 3924, 3928, 3937, 3939, 3948, 3976, 3980, 3984, 4004,
+    4008, 4012, 4020, 4023, 4032, 4036, 4040, 4048, 4051, 4088, 4089, 4092, 4095, 4096, 4098, 4104,
+    4105, 4107, 4108, 4109, 4111, 4113, 4116, 4117, 4118, 4119, 4120, 4123, 4124, 4125, 4126, 4127,
+    4132, 4133, 4135, 4137, 4141, 4144, 4145, 4148, 4152, 4160, 4161, 4163, 4164, 4165, 4169, 4172,
+    4173, 4176, 4189, 4228, 4229, 4232, 4235, 4236, 4238, 4244, 4245, 4247, 4249, 4250, 4256, 4257,
+    4260, 4263, 4264, 4272, 4273, 4275, 4276, 4277, 4284, 4285, 4288, 4292, 4301, 4303, 4304, 4305,
+    4309, 4312, 4316, 4340, 4341, 4342, 4344, 4348, 4356, 4357, 4359, 4361, 4368, 4372, 4388, 4396,
+    4416, 4424, 4425, 4428, 4432, 4440, 4441, 4452, 4456, 4480, 4481, 4484, 4487, 4488, 4489, 4490,
+    4496, 4497, 4499, 4501, 4505, 4506, 4508, 4516, 4528, 4536, 4564, 4565, 4568, 4572, 4585, 4592,
+    4596, 4600, 4608, 4611, 4613, 4620, 4621, 4624, 4628, 4636, 4637, 4639, 4676, 4677, 4680, 4684,
+    4686, 4692, 4693, 4695, 4697, 4698, 4699, 4704, 4705, 4708, 4712, 4714, 4720, 4721, 4723, 4724,
+    4725, 4731, 4732, 4733, 4736, 4740, 4748, 4749, 4751, 4752, 4753, 4760, 4761, 4776, 4816, 4817,
+    4820, 4823, 4824, 4832, 4835, 4836, 4837, 4844, 4865, 4872, 4873, 4888, 4889, 4891, 4892, 4893,
+    4928, 4929, 4932, 4936, 4944, 4945, 4949, 5012, 5040, 5061, 5068, 5069, 5072, 5076, 5084, 5087,
+    5089, 5180, 5201, 5208, 5212, 5216, 5224, 5225, 5264, 5265, 5268, 5272, 5280, 5281, 5283, 5285,
+    5292, 5293, 5295, 5296, 5299, 5300, 5301, 5302, 5308, 5309, 5311, 5312, 5313, 5317, 5320, 5321,
+    5324, 5328, 5336, 5337, 5339, 5340, 5341, 5348, 5349, 5352, 5356, 5364, 5365, 5367, 5369, 5376,
+    5380, 5384, 5392, 5397, 5404, 5405, 5406, 5407, 5408, 5411, 5412, 5414, 5415, 5420, 5421, 5423,
+    5424, 5425, 5430, 5432, 5433, 5436, 5440, 5448, 5449, 5451, 5452, 5453, 5460, 5461, 5464, 5468,
+    5476, 5477, 5479, 5480, 5481, 5488, 5492, 5496, 5509, 5516, 5517, 5518, 5520, 5524, 5526, 5532,
+    5533, 5535, 5537, 5541, 5544, 5545, 5548, 5552, 5565, 5572, 5576, 5580, 5588, 5591, 5592, 5600,
+    5604, 5608, 5616, 5617, 5619, 5628, 5629, 5632, 5636, 5644, 5645, 5647, 5649, 5656, 5657, 5660,
+    5663, 5664, 5672, 5673, 5675, 5677, 5679, 5681, 5682, 5684, 5704, 5712, 5713, 5716, 5720, 5728,
+    5733, 5740, 5741, 5744, 5748, 5756, 5757, 5759, 5761, 5768, 5769, 5776, 5784, 5787, 5789, 5796,
+    5797, 5800, 5804, 5805, 5812, 5813, 5815, 5817, 5852, 5853, 5856, 5859, 5860, 5867, 5868, 5869,
+    5871, 5873, 5878, 5880, 5881, 5883, 5884, 5888, 5896, 5897, 5900, 5901, 5907, 5908, 5909, 5912,
+    5916, 5924, 5925, 5928, 5929, 5957, 5992, 5993, 5996, 6000, 6002, 6008, 6009, 6012, 6013, 6020,
+    6024, 6028, 6080, 6104, 6105, 6108, 6111, 6112, 6114, 6120, 6121, 6125, 6132, 6133, 6136, 6152,
+    6160, 6180, 6188, 6192, 6196, 6204, 6205, 6216, 6244, 6245, 6248, 6252, 6260, 6261, 6265, 6272,
+    6292, 6300, 6328, 6332, 6377, 6384, 6385, 6388, 6392, 6394, 6399, 6400, 6401, 6412, 6416, 6420,
+    6428, 6440, 6441, 6444, 6448, 6456, 6457, 6459, 6461, 6468, 6469, 6472, 6473, 6474, 6476, 6477,
+    6478, 6483, 6484, 6485, 6487, 6488, 6489, 6493, 6494, 6496, 6497, 6500, 6504, 6512, 6513, 6515,
+    6516, 6517, 6524, 6525, 6528, 6532, 6535, 6540, 6541, 6543, 6545, 6549, 6551, 6552, 6556, 6560,
+    6569, 6580, 6581, 6584, 6585, 6587, 6588, 6589, 6590, 6596, 6597, 6598, 6599, 6600, 6601, 6602,
+    6604, 6606, 6608, 6609, 6612, 6616, 6624, 6625, 6627, 6629, 6636, 6637, 6638, 6640, 6644, 6646,
+    6647, 6652, 6653, 6654, 6655, 6656, 6657, 6661, 6662, 6663, 6664, 6668, 6672, 6680, 6681, 6683,
+    6684, 6692, 6693, 6696, 6700, 6701, 6702, 6704, 6707, 6708, 6709, 6711, 6713, 6715, 6720, 6721,
+    6724, 6728, 6736, 6737, 6739, 6740, 6741, 6748, 6749, 6752, 6764, 6767, 6769, 6776, 6777, 6780,
+    6784, 6792, 6793, 6795, 6797, 6804, 6805, 6808, 6812, 6820, 6821, 6823, 6825, 6832, 6833, 6836,
+    6840, 6841, 6842, 6848, 6849, 6851, 6853, 6860, 6861, 6864, 6868, 6876, 6877, 6880, 6881, 6888,
+    6889, 6892, 6896, 6904, 6905, 6909, 6916, 6917, 6920, 6924, 6932, 6933, 6935, 6937, 6944, 6945,
+    6948, 6952, 6960, 6961, 6963, 6965, 6967, 6972, 6973, 6976, 6980, 6986, 6988, 6989, 6991, 6993,
+    6994, 6995, 6996, 6997, 6998, 6999, 7000, 7004, 7008, 7016, 7019, 7028, 7029, 7032, 7036, 7037,
+    7038, 7043, 7044, 7045, 7047, 7048, 7049, 7050, 7054, 7056, 7057, 7060, 7062, 7063, 7064, 7066,
+    7072, 7073, 7075, 7076, 7077, 7078, 7084, 7085, 7088, 7092, 7100, 7101, 7103, 7104, 7105, 7112,
+    7113, 7116, 7118, 7120, 7128, 7133, 7140, 7144, 7148, 7168, 7169, 7172, 7176, 7178, 7184, 7185,
+    7187, 7189, 7190, 7196, 7197, 7200, 7204, 7212, 7213, 7215, 7217, 7224, 7228, 7232, 7240, 7241,
+    7244, 7245, 7252, 7280, 7281, 7284, 7288, 7290, 7296, 7297, 7299, 7301, 7302, 7303, 7307, 7308,
+    7309, 7316, 7325, 7327, 7329, 7336, 7356, 7357, 7364, 7368, 7372, 7380, 7381, 7383, 7385, 7392,
+    7393, 7396, 7413, 7420, 7421, 7424, 7428, 7429, 7430, 7436, 7437, 7439, 7441, 7448, 7468, 7476,
+    7504, 7505, 7508, 7512, 7520, 7521, 7523, 7532, 7536, 7540, 7548, 7560, 7561, 7564, 7568, 7576,
+    7577, 7579, 7581, 7616, 7617, 7620, 7623, 7624, 7626, 7632, 7633, 7635, 7637, 7638, 7641, 7642,
+    7644, 7645, 7648, 7650, 7652, 7655, 7660, 7661, 7663, 7664, 7665, 7672, 7673, 7676, 7680, 7688,
+    7689, 7691, 7692, 7693, 7700, 7704, 7721, 7756, 7757, 7760, 7764, 7772, 7773, 7775, 7776, 7777,
+    7784, 7805, 7812, 7832, 7868, 7869, 7872, 7876, 7884, 7885, 7887, 7889, 7891, 7896, 7897, 7904,
+    7916, 7924, 7944, 7952, 7956, 7960, 7968, 7969, 8001, 8008, 8009, 8012, 8016, 8024, 8025, 8029,
+    8036, 8056, 8057, 8092, 8120, 8148, 8164, 8167, 8169, 8204, 8205, 8208, 8212, 8220, 8221, 8225,
+    8226, 8231, 8232, 8233, 8236, 8238, 8240, 8248, 8249, 8251, 8252, 8253, 8254, 8260, 8261, 8264,
+    8268, 8276, 8277, 8279, 8280, 8281, 8288, 8292, 8294, 8296, 8304, 8309, 8344, 8345, 8348, 8352,
+    8360, 8361, 8363, 8364, 8365, 8372, 8373, 8376, 8380, 8388, 8389, 8391, 8393, 8400, 8404, 8420,
+    8428, 8432, 8449, 8456, 8457, 8460, 8464, 8472, 8473, 8475, 8477, 8484, 8488, 8492, 8505, 8540,
+    8544, 8548, 8556, 8557, 8559, 8561, 8568, 8584, 8596, 8597, 8600, 8604, 8612, 8613, 8615, 8617,
+    8624, 8644, 8652, 8656, 8680, 8684, 8688, 8696, 8697, 8699, 8701, 8708, 8712, 8716, 8724, 8729,
+    8736, 8737, 8740, 8744, 8752, 8753, 8755, 8757, 8792, 8793, 8796, 8799, 8800, 8801, 8808, 8809,
+    8811, 8813, 8820, 8821, 8824, 8828, 8836, 8837, 8839, 8841, 8848, 8849, 8852, 8856, 8864, 8865,
+    8867, 8868, 8869, 8876, 8877, 8897, 8932, 8933, 8936, 8939, 8940, 8948, 8949, 8951, 8952, 8953,
+    8960, 8961, 8964, 8968, 8976, 8977, 8979, 8981, 8988, 8992, 8996, 9004, 9005, 9007, 9008, 9009,
+    9016, 9044, 9045, 9048, 9052, 9060, 9061, 9063, 9065, 9072, 9073, 9076, 9080, 9088, 9093, 9100,
+    9121, 9128, 9136, 9156, 9184, 9185, 9188, 9192, 9200, 9201, 9203, 9205, 9212, 9216, 9220, 9233,
+    9240, 9261, 9268, 9269, 9272, 9276, 9284, 9285, 9287, 9289, 9296, 9300, 9304, 9312, 9324, 9325,
+    9328, 9332, 9340, 9341, 9345, 9380, 9381, 9384, 9388, 9396, 9397, 9399, 9401, 9408, 9409, 9412,
+    9416, 9417, 9424, 9425, 9427, 9428, 9429, 9436, 9437, 9440, 9444, 9452, 9453, 9455, 9456, 9457,
+    9464, 9485, 9520, 9521, 9524, 9528, 9530, 9536, 9537, 9539, 9540, 9541, 9548, 9549, 9552, 9556,
+    9564, 9565, 9567, 9569, 9576, 9580, 9596, 9604, 9608, 9632, 9633, 9636, 9640, 9648, 9649, 9651,
+    9653, 9658, 9660, 9664, 9688, 9716, 9720, 9735, 9737, 9744, 9772, 9773, 9776, 9780, 9788, 9789,
+    9791, 9793, 9800, 9820, 9828, 9856, 9857, 9860, 9864, 9872, 9873, 9877, 9884, 9888, 9892, 9900,
+    9905, 9912, 9913, 9916, 9919, 9920, 9922, 9928, 9929, 9931, 9940, 9944, 9948, 9956, 9957, 9968,
+    9969, 9972, 9976, 9984, 9985, 9987, 9989, 9996, 9997, 9998, 10000, 10004, 10006, 10012, 10013, 10015,
+    10016, 10017, 10021, 10024, 10025, 10028, 10032, 10040, 10041, 10043, 10044, 10045, 10052, 10053, 10108, 10109,
+    10112, 10116, 10124, 10125, 10127, 10128, 10129, 10136, 10137, 10140, 10144, 10152, 10153, 10155, 10157, 10164,
+    10168, 10172, 10180, 10181, 10184, 10185, 10192, 10200, 10209, 10211, 10220, 10221, 10224, 10228, 10236, 10237,
+    10239, 10241, 10248, 10269, 10304, 10308, 10332, 10336, 10340, 10349, 10351, 10360, 10361, 10364, 10367, 10368,
+    10370, 10376, 10377, 10379, 10381, 10388, 10409, 10444, 10448, 10452, 10460, 10463, 10472, 10476, 10480, 10488,
+    10491, 10493, 10500, 10504, 10508, 10516, 10517, 10519, 10556, 10557, 10560, 10564, 10572, 10573, 10575, 10577,
+    10584, 10585, 10588, 10592, 10597, 10600, 10601, 10603, 10605, 10612, 10613, 10616, 10620, 10628, 10629, 10631,
+    10632, 10633, 10640, 10661, 10696, 10697, 10700, 10704, 10706, 10712, 10713, 10715, 10717, 10724, 10725, 10728,
+    10732, 10740, 10741, 10743, 10745, 10752, 10753, 10756, 10760, 10768, 10769, 10771, 10772, 10773, 10780, 10784,
+    10788, 10797, 10808, 10809, 10812, 10816, 10821, 10824, 10825, 10827, 10829, 10833, 10836, 10837, 10840, 10844,
+    10855, 10857, 10864, 10865, 10868, 10883, 10885, 10892, 10893, 10896, 10900, 10909, 10911, 10913, 10920, 10924,
+    10928, 10937, 10939, 10948, 10949, 10952, 10956, 10961, 10964, 10967, 10969, 10976, 10980, 10984, 10992, 10997,
+    11004, 11005, 11008, 11012, 11025, 11032, 11033, 11036, 11040, 11048, 11049, 11051, 11053, 11060, 11061, 11064,
+    11068, 11076, 11079, 11081, 11088, 11089, 11092, 11094, 11095, 11096, 11097, 11104, 11105, 11107, 11109, 11113,
+    11116, 11120, 11124, 11132, 11133, 11137, 11144, 11145, 11148, 11152, 11160, 11161, 11163, 11165,
+];
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alloc")]
+    use super::{decode_euckr, encode_euckr};
+    use super::{is_ksx1001_syllable, KSX1001_SYLLABLES};
+    #[cfg(feature = "alloc")]
+    use crate::Error;
+    use crate::Syllable;
+    use core::convert::TryFrom;
+
+    #[test]
+    fn test_ksx1001_syllables_sorted() {
+        assert!(KSX1001_SYLLABLES.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_is_ksx1001_syllable() {
+        for character in "가각힝똥뷰".chars() {
+            assert!(is_ksx1001_syllable(Syllable::try_from(character).unwrap()));
+        }
+        for character in "힣똠갂똡뷁햏".chars() {
+            assert!(!is_ksx1001_syllable(Syllable::try_from(character).unwrap()));
+        }
+
+        let count = (0xAC00..=0xD7A3)
+            .filter_map(char::from_u32)
+            .filter(|&character| is_ksx1001_syllable(Syllable::try_from(character).unwrap()))
+            .count();
+        assert_eq!(count, 2350);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_euckr() {
+        assert_eq!(
+            decode_euckr(&[0xB0, 0xA1, 0xC8, 0xFE, 0xA4, 0xA1, 0xA4, 0xD3, b' ', b'a']),
+            Ok(String::from("가힝ㄱㅣ a"))
+        );
+
+        assert_eq!(decode_euckr(&[0xB0]), Err(Error::NonEucKr(0xB0)));
+        assert_eq!(decode_euckr(&[0xB0, 0x41]), Err(Error::NonEucKr(0xB041)));
+        assert_eq!(decode_euckr(&[0xA4, 0xD4]), Err(Error::NonEucKr(0xA4D4)));
+        assert_eq!(decode_euckr(&[0xCA, 0xA1]), Err(Error::NonEucKr(0xCAA1)));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_encode_euckr() {
+        assert_eq!(
+            encode_euckr("가힝ㄱㅣ a"),
+            Ok(vec![
+                0xB0, 0xA1, 0xC8, 0xFE, 0xA4, 0xA1, 0xA4, 0xD3, b' ', b'a'
+            ])
+        );
+
+        assert_eq!(
+            encode_euckr("햏"),
+            Err(Error::NotRepresentableInEucKr('햏'))
+        );
+        assert_eq!(encode_euckr("é"), Err(Error::NotRepresentableInEucKr('é')));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_euckr_round_trip() {
+        for code_point in 0xAC00..=0xD7A3 {
+            let character = char::from_u32(code_point).unwrap();
+            let source = character.to_string();
+            match encode_euckr(&source) {
+                Ok(encoded) => assert_eq!(decode_euckr(&encoded), Ok(source)),
+                Err(error) => assert_eq!(error, Error::NotRepresentableInEucKr(character)),
+            }
+        }
+    }
+}
@@ -0,0 +1,243 @@
+//! Folding the look-alike consonants together for lenient search, much like case folding does for
+//! the scripts with letter case.
+use crate::Syllable;
+#[cfg(feature = "alloc")]
+use crate::{Choseong, Jungseong};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use core::convert::TryFrom;
+
+/// Decides which distinctions [`fold_syllable`] and [`fold_korean`] erase.
+///
+/// Folding is lossy by design: '빨' and '발' (or, with every option on, '닭' and '다') are no longer
+/// told apart, even though they are pronounced differently and mean different things. The result
+/// is meant to be compared or hashed, never shown to a reader.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FoldOptions {
+    /// Folds a tense consonant (경음, Gyeongeum) into its plain counterpart, e.g. '까' into '가'.
+    /// See [`Choseong::without_tension`](crate::Choseong::without_tension) and
+    /// [`Jongseong::without_tension`](crate::Jongseong::without_tension).
+    pub fold_tense: bool,
+    /// Folds an aspirated consonant (격음, Gyeogeum) into its plain counterpart, e.g. '타' into
+    /// '다'. See [`Choseong::without_aspiration`](crate::Choseong::without_aspiration) and
+    /// [`Jongseong::without_aspiration`](crate::Jongseong::without_aspiration).
+    pub fold_aspirated: bool,
+    /// Removes the final consonant altogether, clustered or not, e.g. '닭' into '다'. This takes
+    /// precedence over the other two, which then only apply to the initial consonant.
+    pub strip_jongseong: bool,
+}
+
+/// Folds a given syllable according to `options`.
+///
+/// A clustered final consonant is folded only as a whole, i.e. 'ㄿ' becomes 'ㄼ' with
+/// [`FoldOptions::fold_aspirated`], while 'ㄾ' stays as it is, since there is no 'ㄹㄷ' to fold it
+/// into; the components are never folded separately. Use [`FoldOptions::strip_jongseong`] to get
+/// rid of the clusters entirely.
+///
+/// ```
+/// use unikorn::{fold_syllable, FoldOptions, Syllable};
+/// use std::convert::TryFrom;
+///
+/// let options = FoldOptions {
+///     fold_tense: true,
+///     fold_aspirated: true,
+///     ..FoldOptions::default()
+/// };
+/// let fold = |character| {
+///     char::from(fold_syllable(Syllable::try_from(character).unwrap(), options))
+/// };
+///
+/// assert_eq!(fold('까'), '가');
+/// assert_eq!(fold('탔'), '닷');
+/// assert_eq!(fold('읊'), '읇');
+/// ```
+pub fn fold_syllable(syllable: Syllable, options: FoldOptions) -> Syllable {
+    let mut choseong = syllable.choseong;
+    let mut jongseong = syllable.jongseong;
+    if options.fold_tense {
+        choseong = choseong.without_tension();
+        jongseong = jongseong.map(|jongseong| jongseong.without_tension());
+    }
+    if options.fold_aspirated {
+        choseong = choseong.without_aspiration();
+        jongseong = jongseong.map(|jongseong| jongseong.without_aspiration());
+    }
+    if options.strip_jongseong {
+        jongseong = None;
+    }
+
+    Syllable::from((choseong, syllable.jungseong, jongseong))
+}
+
+/// Folds every Precomposed Korean [`Syllable`] in a given string according to `options`, leaving
+/// everything else untouched, so that the result can be used as a key for lenient search.
+///
+/// See [`fold_syllable`] for the details, and [`FoldOptions`] for what is lost.
+///
+/// ```
+/// use unikorn::{fold_korean, FoldOptions};
+///
+/// let options = FoldOptions {
+///     fold_tense: true,
+///     fold_aspirated: true,
+///     strip_jongseong: true,
+/// };
+/// assert_eq!(fold_korean("빨간 토끼", options), fold_korean("바가 도기", options));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn fold_korean(source: &str, options: FoldOptions) -> String {
+    crate::map_syllables(source, |syllable| fold_syllable(syllable, options))
+}
+
+/// Builds a phonetic key of a given string, much like Soundex does for English names, so that
+/// the Korean names sounding alike, e.g. '김재석' and '김제석', get the same key.
+///
+/// Every Precomposed Korean [`Syllable`] becomes its Hangul Compatibility Jamo after:
+/// * folding a tense or an aspirated initial consonant into its plain counterpart, as
+///   [`fold_syllable`] does, and dropping a silent 'ㅇ',
+/// * merging 'ㅔ' into 'ㅐ', and 'ㅙ' and 'ㅞ' into 'ㅚ', as in the speech of most speakers today,
+///   and
+/// * turning the final consonant into the one it is pronounced as, see
+///   [`Jongseong::representative_sound`](crate::Jongseong::representative_sound).
+///
+/// Everything else is left out. As with folding, the key is meant to be compared, never shown.
+///
+/// ```
+/// use unikorn::korean_soundex;
+///
+/// assert_eq!(korean_soundex("김재석"), korean_soundex("김제석"));
+/// assert_eq!(korean_soundex("최 왜진"), korean_soundex("최외진"));
+/// assert_eq!(korean_soundex("김재석"), "ㄱㅣㅁㅈㅐㅅㅓㄱ");
+/// assert_ne!(korean_soundex("김재석"), korean_soundex("김재성"));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn korean_soundex(s: &str) -> String {
+    let mut key = String::with_capacity(s.len());
+    for syllable in s
+        .chars()
+        .filter_map(|character| Syllable::try_from(character).ok())
+    {
+        let choseong = syllable.choseong.without_tension().without_aspiration();
+        if choseong != Choseong::Ieung {
+            key.push(choseong.into());
+        }
+        key.push(
+            match syllable.jungseong {
+                Jungseong::E => Jungseong::Ae,
+                Jungseong::Wae | Jungseong::We => Jungseong::Oe,
+                jungseong => jungseong,
+            }
+            .into(),
+        );
+        if let Some(jongseong) = syllable.jongseong {
+            key.push(jongseong.representative_sound().into());
+        }
+    }
+
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alloc")]
+    use super::{fold_korean, korean_soundex};
+    use super::{fold_syllable, FoldOptions};
+    use crate::Syllable;
+    use core::convert::TryFrom;
+
+    fn fold(character: char, options: FoldOptions) -> char {
+        fold_syllable(Syllable::try_from(character).unwrap(), options).into()
+    }
+
+    #[test]
+    fn test_fold_syllable() {
+        let tense = FoldOptions {
+            fold_tense: true,
+            ..FoldOptions::default()
+        };
+        let aspirated = FoldOptions {
+            fold_aspirated: true,
+            ..FoldOptions::default()
+        };
+        let strip = FoldOptions {
+            strip_jongseong: true,
+            ..FoldOptions::default()
+        };
+
+        assert_eq!(fold('까', FoldOptions::default()), '까');
+        assert_eq!(fold('까', tense), '가');
+        assert_eq!(fold('까', aspirated), '까');
+        assert_eq!(fold('빠', tense), '바');
+        assert_eq!(fold('있', tense), '잇');
+        assert_eq!(fold('타', aspirated), '다');
+        assert_eq!(fold('타', tense), '타');
+        assert_eq!(fold('엌', aspirated), '억');
+
+        // clusters fold as a whole, or not at all.
+        assert_eq!(fold('읊', aspirated), '읇');
+        assert_eq!(fold('핥', aspirated), '핥');
+        assert_eq!(fold('값', tense), '값');
+        assert_eq!(fold('닭', strip), '다');
+        assert_eq!(fold('닭', aspirated), '닭');
+
+        // folding is idempotent.
+        let all = FoldOptions {
+            fold_tense: true,
+            fold_aspirated: true,
+            strip_jongseong: true,
+        };
+        for index in 0..11172 {
+            let syllable = Syllable::from_index(index).unwrap();
+            let folded = fold_syllable(syllable, all);
+            assert_eq!(fold_syllable(folded, all), folded);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_fold_korean() {
+        let options = FoldOptions {
+            fold_tense: true,
+            fold_aspirated: true,
+            strip_jongseong: false,
+        };
+        assert_eq!(fold_korean("까치 abc", options), "가지 abc");
+        assert_eq!(fold_korean("ㄲ", options), "ㄲ");
+        assert_eq!(fold_korean("", options), "");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_korean_soundex() {
+        for (a, b) in [
+            ("김재석", "김제석"),
+            ("빵", "방"),
+            ("팥", "밭"),
+            ("닭", "닥"),
+            ("낮", "낫"),
+            ("낳", "낟"),
+            ("밖", "박"),
+            ("왜", "외"),
+            ("웨", "외"),
+            ("이", "ㅣ이"),
+            ("김 재석", "김재석"),
+        ] {
+            assert_eq!(korean_soundex(a), korean_soundex(b), "{} {}", a, b);
+        }
+        for (a, b) in [
+            ("이", "리"),
+            ("강", "간"),
+            ("애", "얘"),
+            ("왜", "와"),
+            ("김재석", "김재"),
+        ] {
+            assert_ne!(korean_soundex(a), korean_soundex(b), "{} {}", a, b);
+        }
+
+        assert_eq!(korean_soundex("최지훈"), "ㅈㅚㅈㅣㅎㅜㄴ");
+        assert_eq!(korean_soundex("이영"), "ㅣㅕㅇ");
+        assert_eq!(korean_soundex("abc"), "");
+        assert_eq!(korean_soundex(""), "");
+    }
+}
@@ -0,0 +1,271 @@
+//! Telling which forms Korean text comes in, i.e. which normalization it needs.
+use crate::{CharExt, Syllable};
+
+/// Denotes one of the forms Korean text is encoded in.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum HangulForm {
+    /// Precomposed Hangul Syllables (U+AC00 '가' -- U+D7A3 '힣'), i.e. NFC.
+    Precomposed,
+    /// The conjoining jamo (Hangul Jamo, Hangul Jamo Extended-A and -B), i.e. NFD, as seen in the
+    /// file names on macOS.
+    Conjoining,
+    /// Hangul Compatibility Jamo (U+3131 'ㄱ' -- U+318E 'ㆎ'), as typed in standalone.
+    Compatibility,
+    /// Halfwidth Hangul (U+FFA0 -- U+FFDC), as seen in the older terminals.
+    Halfwidth,
+}
+
+/// Counts the characters in one of the categories of a [`FormsReport`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FormCount {
+    /// How many characters fall into this category.
+    pub count: usize,
+    /// The byte offset of the first of them, if any.
+    pub first_offset: Option<usize>,
+}
+impl FormCount {
+    fn record(&mut self, offset: usize) {
+        self.count += 1;
+        self.first_offset.get_or_insert(offset);
+    }
+}
+
+/// Reports which forms of Korean text a string contains, as returned by [`analyze_forms`].
+///
+/// The first four categories are mutually exclusive, one for each [`HangulForm`]; the last two
+/// are subsets of them, flagging what is likely to need attention.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FormsReport {
+    /// See [`HangulForm::Precomposed`].
+    pub precomposed: FormCount,
+    /// See [`HangulForm::Conjoining`]. The fillers are included.
+    pub conjoining: FormCount,
+    /// See [`HangulForm::Compatibility`].
+    pub compatibility: FormCount,
+    /// See [`HangulForm::Halfwidth`]. The halfwidth filler is included.
+    pub halfwidth: FormCount,
+    /// The archaic jamo, conjoining or compatibility, e.g. 'ㆍ'; these cannot be normalized into
+    /// the precomposed form.
+    pub archaic: FormCount,
+    /// The conjoining jamo not making up a syllable, e.g. a final consonant right after a space.
+    /// Whatever the Unicode grapheme cluster rules (UAX #29) leave out of a syllable counts, i.e.
+    /// an initial consonant not followed by a vowel, a vowel not preceded by an initial consonant,
+    /// and a final consonant not preceded by a vowel.
+    pub incomplete: FormCount,
+}
+impl FormsReport {
+    /// Returns the form the most characters come in, or [`None`] if there is no Korean at all.
+    ///
+    /// In case of a tie, the one listed first in [`HangulForm`] wins.
+    ///
+    /// ```
+    /// use unikorn::{analyze_forms, HangulForm};
+    ///
+    /// assert_eq!(
+    ///     analyze_forms("한글 \u{1112}").dominant_form(),
+    ///     Some(HangulForm::Precomposed)
+    /// );
+    /// assert_eq!(analyze_forms("abc").dominant_form(), None);
+    /// ```
+    pub fn dominant_form(&self) -> Option<HangulForm> {
+        [
+            (HangulForm::Precomposed, self.precomposed.count),
+            (HangulForm::Conjoining, self.conjoining.count),
+            (HangulForm::Compatibility, self.compatibility.count),
+            (HangulForm::Halfwidth, self.halfwidth.count),
+        ]
+        .iter()
+        .filter(|&&(_, count)| count > 0)
+        // `max_by_key()` picks the last one on a tie.
+        .rev()
+        .max_by_key(|&&(_, count)| count)
+        .map(|&(form, _)| form)
+    }
+}
+
+/// Analyzes which forms of Korean text a given string contains; see [`FormsReport`].
+///
+/// ```
+/// use unikorn::analyze_forms;
+///
+/// let report = analyze_forms("한\u{1100}\u{1173}\u{11AF} ㄱ \u{11A8}");
+/// assert_eq!(report.precomposed.count, 1);
+/// assert_eq!(report.conjoining.count, 4);
+/// assert_eq!(report.compatibility.first_offset, Some(13));
+/// assert_eq!(report.incomplete.first_offset, Some(17));
+/// ```
+pub fn analyze_forms(source: &str) -> FormsReport {
+    let mut report = FormsReport::default();
+
+    let mut previous = Category::Other;
+    // the offsets of the initial consonants yet to be followed by a vowel.
+    let mut leading = FormCount::default();
+    for (offset, character) in source.char_indices() {
+        if Syllable::is_one_of_us(character) {
+            report.precomposed.record(offset);
+        } else if character.is_korean_jamo() {
+            report.conjoining.record(offset);
+        } else if character.is_korean_compatibility_jamo() {
+            report.compatibility.record(offset);
+        } else if character.is_korean_halfwidth() {
+            report.halfwidth.record(offset);
+        }
+        if is_archaic(character) {
+            report.archaic.record(offset);
+        }
+
+        let category = Category::of(character);
+        let complete = match category {
+            Category::L | Category::Other => true,
+            Category::V => matches!(previous, Category::L | Category::V | Category::LV),
+            Category::T => matches!(
+                previous,
+                Category::V | Category::T | Category::LV | Category::LVT
+            ),
+            Category::LV | Category::LVT => true,
+        };
+
+        match category {
+            Category::L => leading.record(offset),
+            Category::V | Category::LV | Category::LVT if leading.count > 0 => {
+                leading = FormCount::default()
+            }
+            _ => flush(&mut report.incomplete, &mut leading),
+        }
+        if !complete {
+            report.incomplete.record(offset);
+        }
+
+        previous = if complete { category } else { Category::Other };
+    }
+    flush(&mut report.incomplete, &mut leading);
+
+    report
+}
+
+// the jamo outside the modern ones, yet not the fillers.
+pub(crate) fn is_archaic(character: char) -> bool {
+    matches!(
+        character,
+        '\u{1113}'..='\u{115E}'
+            | '\u{1176}'..='\u{11A7}'
+            | '\u{11C3}'..='\u{11FF}'
+            | '\u{3165}'..='\u{318E}'
+            | '\u{A960}'..='\u{A97C}'
+            | '\u{D7B0}'..='\u{D7C6}'
+            | '\u{D7CB}'..='\u{D7FB}'
+    )
+}
+
+// moves the initial consonants left without a vowel into `incomplete`.
+fn flush(incomplete: &mut FormCount, leading: &mut FormCount) {
+    if let Some(offset) = leading.first_offset {
+        incomplete.first_offset = Some(
+            incomplete
+                .first_offset
+                .map_or(offset, |first| first.min(offset)),
+        );
+        incomplete.count += leading.count;
+    }
+    *leading = FormCount::default();
+}
+
+// the Hangul_Syllable_Type property, as used by the grapheme cluster rules.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[allow(clippy::upper_case_acronyms)]
+enum Category {
+    L,
+    V,
+    T,
+    LV,
+    LVT,
+    Other,
+}
+impl Category {
+    fn of(character: char) -> Self {
+        match character {
+            '\u{1100}'..='\u{115F}' | '\u{A960}'..='\u{A97C}' => Self::L,
+            '\u{1160}'..='\u{11A7}' | '\u{D7B0}'..='\u{D7C6}' => Self::V,
+            '\u{11A8}'..='\u{11FF}' | '\u{D7CB}'..='\u{D7FB}' => Self::T,
+            _ => match Syllable::try_from_u32(character as u32) {
+                Ok(syllable) if syllable.jongseong.is_some() => Self::LVT,
+                Ok(_) => Self::LV,
+                Err(_) => Self::Other,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{analyze_forms, FormCount, HangulForm};
+
+    fn count(count: usize, first_offset: usize) -> FormCount {
+        FormCount {
+            count,
+            first_offset: Some(first_offset),
+        }
+    }
+
+    #[test]
+    fn test_analyze_forms() {
+        let report = analyze_forms("");
+        assert_eq!(report.precomposed, FormCount::default());
+        assert_eq!(report.dominant_form(), None);
+
+        let report = analyze_forms("a한 \u{1112}\u{1161}\u{11AB} ㄱ\u{FFA1} ㆍ");
+        assert_eq!(report.precomposed, count(1, 1));
+        assert_eq!(report.conjoining, count(3, 5));
+        assert_eq!(report.compatibility, count(2, 15));
+        assert_eq!(report.halfwidth, count(1, 18));
+        assert_eq!(report.archaic, count(1, 22));
+        assert_eq!(report.incomplete, FormCount::default());
+
+        let report = analyze_forms("\u{1140}\u{119E}\u{11EB}");
+        assert_eq!(report.conjoining, count(3, 0));
+        assert_eq!(report.archaic, count(3, 0));
+        assert_eq!(report.incomplete, FormCount::default());
+    }
+
+    #[test]
+    fn test_analyze_forms_incomplete() {
+        // a precomposed syllable takes a conjoining final consonant, or a vowel if it has none.
+        assert_eq!(analyze_forms("가\u{11A8}").incomplete, FormCount::default());
+        assert_eq!(analyze_forms("가\u{1161}").incomplete, FormCount::default());
+        assert_eq!(analyze_forms("각\u{1161}").incomplete, count(1, 3));
+        // the leading ones, wherever they end up.
+        assert_eq!(analyze_forms("\u{1100}").incomplete, count(1, 0));
+        assert_eq!(analyze_forms("\u{1100}\u{1100} ").incomplete, count(2, 0));
+        assert_eq!(
+            analyze_forms("\u{1100}\u{1100}\u{1161}").incomplete,
+            FormCount::default()
+        );
+        assert_eq!(analyze_forms("\u{1100}가").incomplete, FormCount::default());
+        // the vowels and the trailing ones.
+        assert_eq!(analyze_forms(" \u{1161}\u{11A8}").incomplete, count(2, 1));
+        assert_eq!(analyze_forms("\u{11A8}\u{1100}").incomplete, count(2, 0));
+        // the compatibility jamo are not meant to be conjoined.
+        assert_eq!(analyze_forms("ㄱㅏ").incomplete, FormCount::default());
+    }
+
+    #[test]
+    fn test_forms_report_dominant_form() {
+        assert_eq!(
+            analyze_forms("\u{1112}\u{1161}\u{11AB}글").dominant_form(),
+            Some(HangulForm::Conjoining)
+        );
+        assert_eq!(
+            analyze_forms("ㅋㅋㅋ 웃겨").dominant_form(),
+            Some(HangulForm::Compatibility)
+        );
+        assert_eq!(
+            analyze_forms("\u{FFA1}\u{FFC2}").dominant_form(),
+            Some(HangulForm::Halfwidth)
+        );
+        // a tie goes to the one listed first.
+        assert_eq!(
+            analyze_forms("ㄱ가").dominant_form(),
+            Some(HangulForm::Precomposed)
+        );
+    }
+}
@@ -0,0 +1,490 @@
+//! Reading Hanja (한자, Chinese characters) in Korean, given a dictionary of their readings.
+use crate::{ConversionFallback, Syllable};
+#[cfg(feature = "hanja")]
+use alloc::vec;
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+#[cfg(feature = "hanja")]
+use core::convert::TryFrom;
+#[cfg(feature = "std")]
+use std::{collections::HashMap, hash::BuildHasher};
+
+/// Looks up the Korean readings (음, Eum) of a Hanja, for [`hanja_to_hangul`].
+///
+/// This crate does not ship a dictionary of its own, save for a small one for trying things out
+/// behind the `hanja` feature, `BasicHanjaReadings`. A map from a [`char`] into its readings
+/// works as one as well:
+/// ```
+/// use unikorn::{hanja_to_hangul, HanjaReadings, ReadingPolicy, Syllable};
+/// use std::{collections::BTreeMap, convert::TryFrom};
+///
+/// let mut dict = BTreeMap::new();
+/// dict.insert('李', vec![Syllable::try_from('리').unwrap()]);
+/// assert_eq!(dict.readings('李'), [Syllable::try_from('리').unwrap()]);
+/// assert_eq!(hanja_to_hangul("李", &dict, ReadingPolicy::default()), "이");
+/// ```
+pub trait HanjaReadings {
+    /// Returns the readings of a given Hanja, the most common one first, or an empty slice if it
+    /// is not in this dictionary.
+    ///
+    /// Each reading is the dictionary form, i.e. before the initial sound law (두음법칙,
+    /// Dueumbeopchik) applies, e.g. '리' rather than '이' for '李'.
+    fn readings(&self, hanja: char) -> &[Syllable];
+}
+impl<T: HanjaReadings + ?Sized> HanjaReadings for &T {
+    fn readings(&self, hanja: char) -> &[Syllable] {
+        (**self).readings(hanja)
+    }
+}
+impl HanjaReadings for BTreeMap<char, Vec<Syllable>> {
+    fn readings(&self, hanja: char) -> &[Syllable] {
+        self.get(&hanja).map_or(&[], Vec::as_slice)
+    }
+}
+#[cfg(feature = "std")]
+impl<S: BuildHasher> HanjaReadings for HashMap<char, Vec<Syllable>, S> {
+    fn readings(&self, hanja: char) -> &[Syllable] {
+        self.get(&hanja).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Decides how [`hanja_to_hangul`] reads the Hanja.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ReadingPolicy {
+    /// Whether the initial sound law (두음법칙, Dueumbeopchik) applies to a Hanja beginning a word,
+    /// as [`Syllable::apply_initial_sound_law`] does, e.g. '李' into '이' rather than '리'.
+    pub initial_sound_law: bool,
+    /// What to do with a Hanja not in the dictionary.
+    pub on_unknown: ConversionFallback,
+}
+impl Default for ReadingPolicy {
+    fn default() -> Self {
+        Self {
+            initial_sound_law: true,
+            on_unknown: ConversionFallback::Keep,
+        }
+    }
+}
+
+/// Replaces every Hanja (CJK Unified Ideographs and the like) in a given string with its first
+/// reading in `dict`, leaving everything else untouched.
+///
+/// A Hanja begins a word unless it follows a letter or a digit of any script, e.g. a Korean
+/// syllable or another Hanja; the initial sound law applies only there, as
+/// [`ReadingPolicy::initial_sound_law`] says. The exceptions to the law, e.g. '六月' read as
+/// '유월' rather than '육월', are not covered.
+///
+/// ```
+/// use unikorn::{hanja_to_hangul, ReadingPolicy, Syllable};
+/// use std::{collections::BTreeMap, convert::TryFrom};
+///
+/// let dict: BTreeMap<_, _> = [
+///     ('女', '녀'),
+///     ('男', '남'),
+///     ('子', '자'),
+///     ('來', '래'),
+///     ('年', '년'),
+///     ('老', '로'),
+///     ('人', '인'),
+/// ]
+/// .iter()
+/// .map(|&(hanja, reading)| (hanja, vec![Syllable::try_from(reading).unwrap()]))
+/// .collect();
+/// let policy = ReadingPolicy::default();
+/// assert_eq!(hanja_to_hangul("女子와 男子", &dict, policy), "여자와 남자");
+/// assert_eq!(hanja_to_hangul("來年, 老人", &dict, policy), "내년, 노인");
+/// ```
+pub fn hanja_to_hangul(source: &str, dict: &impl HanjaReadings, policy: ReadingPolicy) -> String {
+    let mut hangul = String::with_capacity(source.len());
+    let mut previous: Option<char> = None;
+
+    for character in source.chars() {
+        match dict.readings(character).first() {
+            Some(&reading) => {
+                let word_initial = !previous.map_or(false, char::is_alphanumeric);
+                let reading = if policy.initial_sound_law && word_initial {
+                    reading.apply_initial_sound_law()
+                } else {
+                    reading
+                };
+                hangul.push(reading.into());
+            }
+            None if is_hanja(character) => policy.on_unknown.apply(&mut hangul, character),
+            None => hangul.push(character),
+        }
+        previous = Some(character);
+    }
+
+    hangul
+}
+
+/// A small dictionary of the readings of about two hundred common Hanja, for trying
+/// [`hanja_to_hangul`] out rather than for real use.
+///
+/// ```
+/// use unikorn::{hanja_to_hangul, BasicHanjaReadings, ReadingPolicy};
+///
+/// let dict = BasicHanjaReadings::new();
+/// assert_eq!(
+///     hanja_to_hangul("大韓民國의 歷史", &dict, ReadingPolicy::default()),
+///     "대한민국의 역사"
+/// );
+/// ```
+///
+/// ```
+/// use unikorn::{BasicHanjaReadings, HanjaReadings, Syllable};
+/// use std::convert::TryFrom;
+///
+/// let dict = BasicHanjaReadings::new();
+/// assert_eq!(dict.readings('韓'), [Syllable::try_from('한').unwrap()]);
+/// assert_eq!(dict.readings('金').len(), 2); // 금, 김
+/// assert!(dict.readings('鬱').is_empty());
+/// ```
+#[cfg(feature = "hanja")]
+#[derive(Clone, Debug)]
+pub struct BasicHanjaReadings {
+    // sorted by the Hanja, for the binary search.
+    entries: Vec<(char, Vec<Syllable>)>,
+}
+#[cfg(feature = "hanja")]
+impl Default for BasicHanjaReadings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+#[cfg(feature = "hanja")]
+impl HanjaReadings for BasicHanjaReadings {
+    fn readings(&self, hanja: char) -> &[Syllable] {
+        match self
+            .entries
+            .binary_search_by_key(&hanja, |&(entry, _)| entry)
+        {
+            Ok(index) => &self.entries[index].1,
+            Err(_) => &[],
+        }
+    }
+}
+#[cfg(feature = "hanja")]
+impl BasicHanjaReadings {
+    /// Builds the dictionary.
+    pub fn new() -> Self {
+        let mut entries = vec![];
+        for &(hanja, readings) in BASIC_READINGS {
+            let readings = readings
+                .chars()
+                .map(|reading| Syllable::try_from(reading).unwrap())
+                .collect();
+            entries.push((hanja, readings));
+        }
+        entries.sort_unstable_by_key(|&(hanja, _)| hanja);
+
+        Self { entries }
+    }
+}
+
+// every Hanja along with its readings, the most common one first; in the order of the readings.
+#[cfg(feature = "hanja")]
+const BASIC_READINGS: &[(char, &str)] = &[
+    ('家', "가"),
+    ('歌', "가"),
+    ('間', "간"),
+    ('江', "강"),
+    ('車', "차거"),
+    ('去', "거"),
+    ('見', "견"),
+    ('京', "경"),
+    ('古', "고"),
+    ('高', "고"),
+    ('工', "공"),
+    ('空', "공"),
+    ('口', "구"),
+    ('九', "구"),
+    ('國', "국"),
+    ('軍', "군"),
+    ('金', "금김"),
+    ('今', "금"),
+    ('氣', "기"),
+    ('記', "기"),
+    ('南', "남"),
+    ('男', "남"),
+    ('內', "내"),
+    ('女', "녀"),
+    ('年', "년"),
+    ('尿', "뇨"),
+    ('農', "농"),
+    ('紐', "뉴"),
+    ('能', "능"),
+    ('泥', "니"),
+    ('多', "다"),
+    ('大', "대"),
+    ('道', "도"),
+    ('度', "도탁"),
+    ('冬', "동"),
+    ('東', "동"),
+    ('洞', "동"),
+    ('同', "동"),
+    ('動', "동"),
+    ('登', "등"),
+    ('樂', "락악요"),
+    ('落', "락"),
+    ('卵', "란"),
+    ('朗', "랑"),
+    ('來', "래"),
+    ('冷', "랭"),
+    ('兩', "량"),
+    ('良', "량"),
+    ('量', "량"),
+    ('旅', "려"),
+    ('力', "력"),
+    ('歷', "력"),
+    ('練', "련"),
+    ('例', "례"),
+    ('禮', "례"),
+    ('路', "로"),
+    ('老', "로"),
+    ('勞', "로"),
+    ('綠', "록"),
+    ('論', "론"),
+    ('雷', "뢰"),
+    ('料', "료"),
+    ('龍', "룡"),
+    ('流', "류"),
+    ('留', "류"),
+    ('六', "륙"),
+    ('陸', "륙"),
+    ('律', "률"),
+    ('利', "리"),
+    ('李', "리"),
+    ('里', "리"),
+    ('理', "리"),
+    ('林', "림"),
+    ('立', "립"),
+    ('馬', "마"),
+    ('萬', "만"),
+    ('每', "매"),
+    ('面', "면"),
+    ('名', "명"),
+    ('命', "명"),
+    ('明', "명"),
+    ('母', "모"),
+    ('木', "목"),
+    ('目', "목"),
+    ('文', "문"),
+    ('門', "문"),
+    ('問', "문"),
+    ('物', "물"),
+    ('美', "미"),
+    ('民', "민"),
+    ('半', "반"),
+    ('方', "방"),
+    ('白', "백"),
+    ('百', "백"),
+    ('父', "부"),
+    ('夫', "부"),
+    ('北', "북배"),
+    ('不', "불부"),
+    ('四', "사"),
+    ('事', "사"),
+    ('史', "사"),
+    ('山', "산"),
+    ('三', "삼"),
+    ('上', "상"),
+    ('色', "색"),
+    ('生', "생"),
+    ('西', "서"),
+    ('書', "서"),
+    ('夕', "석"),
+    ('石', "석"),
+    ('先', "선"),
+    ('姓', "성"),
+    ('世', "세"),
+    ('小', "소"),
+    ('少', "소"),
+    ('所', "소"),
+    ('水', "수"),
+    ('手', "수"),
+    ('數', "수"),
+    ('市', "시"),
+    ('時', "시"),
+    ('食', "식"),
+    ('植', "식"),
+    ('心', "심"),
+    ('十', "십"),
+    ('氏', "씨"),
+    ('安', "안"),
+    ('語', "어"),
+    ('然', "연"),
+    ('午', "오"),
+    ('五', "오"),
+    ('王', "왕"),
+    ('外', "외"),
+    ('右', "우"),
+    ('雨', "우"),
+    ('月', "월"),
+    ('有', "유"),
+    ('育', "육"),
+    ('邑', "읍"),
+    ('二', "이"),
+    ('耳', "이"),
+    ('人', "인"),
+    ('一', "일"),
+    ('日', "일"),
+    ('入', "입"),
+    ('子', "자"),
+    ('字', "자"),
+    ('自', "자"),
+    ('長', "장"),
+    ('場', "장"),
+    ('全', "전"),
+    ('前', "전"),
+    ('電', "전"),
+    ('正', "정"),
+    ('弟', "제"),
+    ('足', "족"),
+    ('左', "좌"),
+    ('主', "주"),
+    ('住', "주"),
+    ('中', "중"),
+    ('重', "중"),
+    ('地', "지"),
+    ('紙', "지"),
+    ('直', "직"),
+    ('川', "천"),
+    ('千', "천"),
+    ('天', "천"),
+    ('草', "초"),
+    ('村', "촌"),
+    ('秋', "추"),
+    ('春', "춘"),
+    ('出', "출"),
+    ('七', "칠"),
+    ('土', "토"),
+    ('八', "팔"),
+    ('便', "편변"),
+    ('平', "평"),
+    ('下', "하"),
+    ('夏', "하"),
+    ('學', "학"),
+    ('韓', "한"),
+    ('漢', "한"),
+    ('海', "해"),
+    ('行', "행항"),
+    ('兄', "형"),
+    ('花', "화"),
+    ('話', "화"),
+    ('火', "화"),
+    ('活', "활"),
+    ('孝', "효"),
+    ('後', "후"),
+    ('休', "휴"),
+];
+
+// the ranges of CJK Unified Ideographs, its extensions, and CJK Compatibility Ideographs.
+fn is_hanja(character: char) -> bool {
+    matches!(
+        character,
+        '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{20000}'..='\u{3134F}'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hanja_to_hangul, HanjaReadings, ReadingPolicy};
+    use crate::{ConversionFallback, Syllable};
+    use std::{collections::BTreeMap, convert::TryFrom};
+
+    fn syllables(s: &str) -> Vec<Syllable> {
+        s.chars()
+            .map(|character| Syllable::try_from(character).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_hanja_to_hangul() {
+        let mut dict = BTreeMap::new();
+        dict.insert('李', syllables("리"));
+        dict.insert('女', syllables("녀"));
+        dict.insert('金', syllables("금김"));
+        assert_eq!(dict.readings('金'), syllables("금김"));
+        assert!(dict.readings('鬱').is_empty());
+        let policy = ReadingPolicy::default();
+
+        assert_eq!(hanja_to_hangul("李", &dict, policy), "이");
+        assert_eq!(hanja_to_hangul("金李", &dict, policy), "금리");
+        assert_eq!(hanja_to_hangul("李金", &dict, policy), "이금");
+        assert_eq!(hanja_to_hangul("女李", &dict, policy), "여리");
+        // a word begins after anything but a letter or a digit.
+        assert_eq!(hanja_to_hangul("(李) 女", &dict, policy), "(이) 여");
+        assert_eq!(hanja_to_hangul("신女", &dict, policy), "신녀");
+        assert_eq!(hanja_to_hangul("a李", &dict, policy), "a리");
+        assert_eq!(hanja_to_hangul("", &dict, policy), "");
+
+        let dictionary_form = ReadingPolicy {
+            initial_sound_law: false,
+            ..policy
+        };
+        assert_eq!(hanja_to_hangul("李 女", &dict, dictionary_form), "리 녀");
+    }
+
+    #[test]
+    fn test_hanja_to_hangul_on_unknown() {
+        let mut dict = BTreeMap::new();
+        dict.insert('李', syllables("리"));
+        for (on_unknown, expected) in [
+            (ConversionFallback::Keep, "이鬱 ok"),
+            (ConversionFallback::Skip, "이 ok"),
+            (ConversionFallback::ReplacementChar, "이\u{FFFD} ok"),
+        ] {
+            let policy = ReadingPolicy {
+                on_unknown,
+                ..ReadingPolicy::default()
+            };
+            assert_eq!(hanja_to_hangul("李鬱 ok", &dict, policy), expected);
+        }
+
+        // an unknown Hanja still counts as a letter before the next one.
+        let policy = ReadingPolicy::default();
+        assert_eq!(hanja_to_hangul("鬱李", &dict, policy), "鬱리");
+    }
+
+    #[test]
+    #[cfg(feature = "hanja")]
+    fn test_basic_hanja_readings() {
+        use super::{BasicHanjaReadings, BASIC_READINGS};
+
+        let dict = BasicHanjaReadings::new();
+        for &(hanja, readings) in BASIC_READINGS {
+            assert!(super::is_hanja(hanja), "{}", hanja);
+            assert_eq!(dict.readings(hanja), syllables(readings), "{}", hanja);
+        }
+        assert!(dict.readings('가').is_empty());
+        assert_eq!(
+            BasicHanjaReadings::default().readings('李'),
+            dict.readings('李')
+        );
+
+        let policy = ReadingPolicy::default();
+        for (source, expected) in [
+            ("大韓民國", "대한민국"),
+            ("李氏", "이씨"),
+            ("歷史와 旅行", "역사와 여행"),
+            ("冷水", "냉수"),
+            ("樂園", "낙園"),
+            ("料理", "요리"),
+            ("流行", "유행"),
+            ("論文", "논문"),
+            ("禮 女 年 尿 紐 泥", "예 여 연 요 유 이"),
+            ("新年", "新년"),
+            ("每年", "매년"),
+        ] {
+            assert_eq!(
+                hanja_to_hangul(source, &dict, policy),
+                expected,
+                "{}",
+                source
+            );
+        }
+    }
+}
@@ -0,0 +1,320 @@
+//! Stream-oriented adapters for [`std::io`].
+use crate::{write::compose_conjoining, JamoForm, RomanizationScheme, Syllable};
+use std::{
+    convert::TryFrom,
+    io::{BufRead, Error as IoError, ErrorKind, Result as IoResult, Write},
+    str,
+};
+
+/// Wraps a [`Write`] so that every Precomposed Korean [`Syllable`] written into it is decomposed
+/// into the conjoining jamo (Hangul Jamo range, U+1100 -- U+11FF), or the jamo of another
+/// [`JamoForm`] (see [`DecomposingWriter::with_form`]), on the fly, while everything else is passed
+/// through as is.
+///
+/// The bytes written must form a valid UTF-8 sequence as a whole, but a single [`char`] may well be
+/// split across multiple [`write`](Write::write) calls; the incomplete bytes are kept internally
+/// until the rest of them arrive.
+///
+/// ```
+/// use unikorn::io::DecomposingWriter;
+/// use std::io::Write;
+///
+/// let mut writer = DecomposingWriter::new(Vec::new());
+/// write!(writer, "한글 ok").unwrap();
+/// writer.flush().unwrap();
+///
+/// assert_eq!(
+///     String::from_utf8(writer.into_inner()).unwrap(),
+///     "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF} ok"
+/// );
+/// ```
+#[derive(Debug)]
+pub struct DecomposingWriter<W: Write> {
+    inner: W,
+    form: JamoForm,
+    pending: [u8; 4],
+    pending_len: usize,
+}
+impl<W: Write> DecomposingWriter<W> {
+    /// Creates a new `DecomposingWriter` writing into `inner`, in the conjoining form.
+    pub fn new(inner: W) -> Self {
+        Self::with_form(inner, JamoForm::Conjoining)
+    }
+
+    /// Creates a new `DecomposingWriter` writing into `inner`, in the given form.
+    ///
+    /// ```
+    /// use unikorn::{io::DecomposingWriter, JamoForm};
+    /// use std::io::Write;
+    ///
+    /// let mut writer = DecomposingWriter::with_form(Vec::new(), JamoForm::Compatibility);
+    /// write!(writer, "강").unwrap();
+    ///
+    /// assert_eq!(String::from_utf8(writer.into_inner()).unwrap(), "ㄱㅏㅇ");
+    /// ```
+    pub fn with_form(inner: W, form: JamoForm) -> Self {
+        Self {
+            inner,
+            form,
+            pending: [0; 4],
+            pending_len: 0,
+        }
+    }
+
+    /// Gets a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwraps this `DecomposingWriter`, returning the underlying writer.
+    ///
+    /// Any incomplete [`char`] still pending is discarded.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    fn write_decomposed(&mut self, source: &str) -> IoResult<()> {
+        let mut decomposed = String::with_capacity(source.len() * 3);
+        for character in source.chars() {
+            match Syllable::try_from(character) {
+                Ok(syllable) => self.form.push_decomposed(&mut decomposed, syllable),
+                Err(_) => decomposed.push(character),
+            }
+        }
+
+        self.inner.write_all(decomposed.as_bytes())
+    }
+}
+impl<W: Write> Write for DecomposingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        // complete the pending char first, if any.
+        if self.pending_len > 0 {
+            let width = utf8_width(self.pending[0]);
+            let taken = (width - self.pending_len).min(buf.len());
+            self.pending[self.pending_len..self.pending_len + taken].copy_from_slice(&buf[..taken]);
+            self.pending_len += taken;
+
+            if self.pending[1..self.pending_len]
+                .iter()
+                .any(|byte| byte & 0xC0 != 0x80)
+            {
+                self.pending_len = 0;
+                return Err(IoError::new(
+                    ErrorKind::InvalidData,
+                    "incomplete UTF-8 sequence followed by a non-continuation byte",
+                ));
+            }
+            if self.pending_len < width {
+                return Ok(taken);
+            }
+
+            self.pending_len = 0;
+            let pending = self.pending;
+            let completed = str::from_utf8(&pending[..width])
+                .map_err(|error| IoError::new(ErrorKind::InvalidData, error))?;
+            self.write_decomposed(completed)?;
+
+            // report only the bytes completing the char as written, so that whatever follows is
+            // validated by the next call before anything of it goes out.
+            return Ok(taken);
+        }
+
+        let (valid, incomplete) = match str::from_utf8(buf) {
+            Ok(valid) => (valid, &[][..]),
+            // the input ends in the middle of a char; keep the incomplete bytes for later.
+            Err(error) if error.error_len().is_none() => (
+                str::from_utf8(&buf[..error.valid_up_to()]).unwrap(),
+                &buf[error.valid_up_to()..],
+            ),
+            Err(error) => return Err(IoError::new(ErrorKind::InvalidData, error)),
+        };
+        self.write_decomposed(valid)?;
+        self.pending[..incomplete.len()].copy_from_slice(incomplete);
+        self.pending_len = incomplete.len();
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        // completed chars are written through as soon as they arrive, so there is nothing left to
+        // emit on our side.
+        self.inner.flush()
+    }
+}
+
+/// Romanizes everything read from `r` line by line, following the given scheme, and writes the
+/// result into `w`, without reading the whole input into memory at once.
+///
+/// Every Precomposed Korean [`Syllable`] is romanized by itself, as
+/// [`RomanizationScheme::romanize`] does, after the runs of conjoining jamo are composed, so that
+/// NFD input romanizes the same. Everything else, line endings (`\n` or `\r\n`) included, is
+/// passed through as is.
+///
+/// # Errors
+/// Returns the first error from reading or writing, or an error of [`ErrorKind::InvalidData`] if
+/// a line is not valid UTF-8; the lines before it have been written by then.
+///
+/// ```
+/// use unikorn::{io::romanize_reader, RomanizationScheme};
+///
+/// let mut romanized = Vec::new();
+/// romanize_reader(
+///     "한글\r\nok\n".as_bytes(),
+///     &mut romanized,
+///     RomanizationScheme::RevisedRomanization,
+/// )
+/// .unwrap();
+/// assert_eq!(romanized, b"hangeul\r\nok\n");
+/// ```
+pub fn romanize_reader<R: BufRead, W: Write>(
+    mut r: R,
+    mut w: W,
+    scheme: RomanizationScheme,
+) -> IoResult<()> {
+    let mut buffer = Vec::new();
+    let mut romanized = String::new();
+    loop {
+        buffer.clear();
+        if r.read_until(b'\n', &mut buffer)? == 0 {
+            return Ok(());
+        }
+        let line =
+            str::from_utf8(&buffer).map_err(|error| IoError::new(ErrorKind::InvalidData, error))?;
+
+        romanized.clear();
+        for (_, character) in compose_conjoining(line) {
+            match Syllable::try_from(character) {
+                Ok(syllable) => romanized.push_str(&scheme.romanize(syllable)),
+                Err(_) => romanized.push(character),
+            }
+        }
+        w.write_all(romanized.as_bytes())?;
+    }
+}
+
+// determines the length of a UTF-8 sequence from its leading byte.
+fn utf8_width(leading_byte: u8) -> usize {
+    match leading_byte {
+        0x00..=0x7F => 1,
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        _ => 4,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{romanize_reader, DecomposingWriter};
+    use crate::{JamoForm, RomanizationScheme};
+    use std::io::{ErrorKind, Write};
+
+    #[test]
+    fn test_decomposing_writer() {
+        let mut writer = DecomposingWriter::new(Vec::new());
+        writer.write_all("가나다 abc 각".as_bytes()).unwrap();
+        writer.flush().unwrap();
+
+        assert_eq!(
+            String::from_utf8(writer.into_inner()).unwrap(),
+            "\u{1100}\u{1161}\u{1102}\u{1161}\u{1103}\u{1161} abc \u{1100}\u{1161}\u{11A8}"
+        );
+    }
+
+    #[test]
+    fn test_decomposing_writer_with_form() {
+        let mut writer = DecomposingWriter::with_form(Vec::new(), JamoForm::Compatibility);
+        writer.write_all("강 abc 닭".as_bytes()).unwrap();
+        assert_eq!(
+            String::from_utf8(writer.into_inner()).unwrap(),
+            "ㄱㅏㅇ abc ㄷㅏㄺ"
+        );
+
+        let mut writer = DecomposingWriter::with_form(Vec::new(), JamoForm::Conjoining);
+        writer.write_all("강".as_bytes()).unwrap();
+        assert_eq!(
+            String::from_utf8(writer.into_inner()).unwrap(),
+            "\u{1100}\u{1161}\u{11BC}"
+        );
+    }
+
+    #[test]
+    fn test_decomposing_writer_byte_by_byte() {
+        let mut writer = DecomposingWriter::new(Vec::new());
+        for byte in "한글".as_bytes() {
+            assert_eq!(writer.write(&[*byte]).unwrap(), 1);
+        }
+        writer.flush().unwrap();
+
+        assert_eq!(
+            String::from_utf8(writer.into_inner()).unwrap(),
+            "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}"
+        );
+
+        // `write_all` carries on past the short write completing a pending char.
+        let mut writer = DecomposingWriter::new(Vec::new());
+        writer.write_all(&[0xED]).unwrap();
+        writer.write_all(&[0x95, 0x9C, b'o', b'k']).unwrap();
+        assert_eq!(
+            String::from_utf8(writer.into_inner()).unwrap(),
+            "\u{1112}\u{1161}\u{11AB}ok"
+        );
+    }
+
+    #[test]
+    fn test_decomposing_writer_invalid_utf8() {
+        let mut writer = DecomposingWriter::new(Vec::new());
+        assert_eq!(
+            writer.write(&[b'a', 0xFF]).unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+
+        let mut writer = DecomposingWriter::new(Vec::new());
+        assert_eq!(writer.write(&[0xED]).unwrap(), 1);
+        assert_eq!(
+            writer.write(b"a").unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+
+        // completing a pending char consumes only its own bytes; the invalid ones after it are
+        // rejected by the next call, without anything written for them.
+        let mut writer = DecomposingWriter::new(Vec::new());
+        assert_eq!(writer.write(&[0xED]).unwrap(), 1);
+        assert_eq!(writer.write(&[0x95, 0x9C, b'a', 0xFF]).unwrap(), 2);
+        assert_eq!(writer.get_ref(), "\u{1112}\u{1161}\u{11AB}".as_bytes());
+        assert_eq!(
+            writer.write(&[b'a', 0xFF]).unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+        assert_eq!(writer.get_ref(), "\u{1112}\u{1161}\u{11AB}".as_bytes());
+    }
+
+    #[test]
+    fn test_romanize_reader() {
+        let input: &[u8] = "한글, 밝다\r\n\nabc 123\n\u{1112}\u{1161}\u{11AB}".as_bytes();
+        let mut output = Vec::new();
+        romanize_reader(input, &mut output, RomanizationScheme::RevisedRomanization).unwrap();
+        assert_eq!(output, b"hangeul, balkda\r\n\nabc 123\nhan");
+
+        let mut output = Vec::new();
+        romanize_reader(&b""[..], &mut output, RomanizationScheme::default()).unwrap();
+        assert!(output.is_empty());
+
+        let mut output = Vec::new();
+        assert_eq!(
+            romanize_reader(
+                &b"ok\n\xFF\n"[..],
+                &mut output,
+                RomanizationScheme::default()
+            )
+            .unwrap_err()
+            .kind(),
+            ErrorKind::InvalidData
+        );
+        assert_eq!(output, b"ok\n");
+    }
+}
@@ -0,0 +1,810 @@
+//! The umbrella [`Jamo`] type, and conversions between the compatibility and the conjoining forms
+//! of jamo.
+use crate::{Choseong, Error, Jaeum, Jongseong, Jungseong, Moeum, Syllable};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::{convert::TryFrom, iter};
+
+/// Decides what to do with a [`char`] that looks like a jamo, but cannot be converted into the
+/// requested form.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum ConversionFallback {
+    /// Drops the offending [`char`] from the output.
+    Skip,
+    /// Keeps the offending [`char`] as is in the output.
+    Keep,
+    /// Replaces the offending [`char`] with U+FFFD REPLACEMENT CHARACTER '�'.
+    ReplacementChar,
+}
+#[cfg(feature = "alloc")]
+impl ConversionFallback {
+    pub(crate) fn apply(self, output: &mut String, character: char) {
+        match self {
+            Self::Skip => {}
+            Self::Keep => output.push(character),
+            Self::ReplacementChar => output.push(char::REPLACEMENT_CHARACTER),
+        }
+    }
+}
+
+/// Represents a Halfwidth Hangul jamo (U+FFA1 'ﾡ' -- U+FFDC 'ￜ'), as seen in the older terminals,
+/// either a consonant or a vowel.
+///
+/// The halfwidth consonants and vowels line up with the Hangul Compatibility Jamo ones, so this
+/// wraps a [`Jaeum`] or a [`Moeum`] rather than having the types of its own:
+/// ```
+/// use unikorn::{Halfwidth, Jaeum, Jamo, Moeum};
+/// use core::convert::TryFrom;
+///
+/// assert_eq!(Halfwidth::try_from('\u{FFA1}'), Ok(Halfwidth::Consonant(Jaeum::Kiyeok)));
+/// assert_eq!(Halfwidth::try_from('\u{FFC2}'), Ok(Halfwidth::Vowel(Moeum::A)));
+/// assert_eq!(char::from(Halfwidth::Vowel(Moeum::I)), '\u{FFDC}');
+/// assert_eq!(
+///     Jamo::from(Halfwidth::Consonant(Jaeum::Hieuh)),
+///     Jamo::HalfwidthJaeum(Jaeum::Hieuh)
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Halfwidth {
+    /// A consonant (U+FFA1 'ﾡ' -- U+FFBE 'ﾾ').
+    Consonant(Jaeum),
+    /// A vowel (U+FFC2 'ￂ' -- U+FFDC 'ￜ').
+    Vowel(Moeum),
+}
+impl From<Halfwidth> for char {
+    fn from(halfwidth: Halfwidth) -> Self {
+        let code_point = match halfwidth {
+            Halfwidth::Consonant(jaeum) => 0xFFA1 + jaeum as u32,
+            // two unassigned code points after every six vowels.
+            Halfwidth::Vowel(moeum) => 0xFFC2 + moeum as u32 + moeum as u32 / 6 * 2,
+        };
+
+        char::from_u32(code_point).unwrap()
+    }
+}
+impl From<Halfwidth> for Jamo {
+    fn from(halfwidth: Halfwidth) -> Self {
+        match halfwidth {
+            Halfwidth::Consonant(jaeum) => Self::HalfwidthJaeum(jaeum),
+            Halfwidth::Vowel(moeum) => Self::HalfwidthMoeum(moeum),
+        }
+    }
+}
+impl TryFrom<char> for Halfwidth {
+    type Error = Error;
+
+    fn try_from(character: char) -> Result<Self, Self::Error> {
+        match character as u32 {
+            code_point @ 0xFFA1..=0xFFBE => Ok(Self::Consonant(
+                Jaeum::try_from((code_point - 0xFFA1) as u8).unwrap(),
+            )),
+            code_point @ 0xFFC2..=0xFFDC if (code_point - 0xFFC2) % 8 < 6 => {
+                let offset = code_point - 0xFFC2;
+                Ok(Self::Vowel(
+                    Moeum::try_from((offset - offset / 8 * 2) as u8).unwrap(),
+                ))
+            }
+            _ => Err(Error::NonJamo(character)),
+        }
+    }
+}
+
+/// Represents any modern Korean consonant or vowel, in the conjoining (Hangul Jamo range, U+1100 --
+/// U+11FF), the compatibility (Hangul Compatibility Jamo range, U+3131 -- U+3163), or the
+/// halfwidth (Halfwidth Hangul jamo, U+FFA1 -- U+FFDC) form.
+///
+/// Useful for accepting 'any Korean letter' without trying each of the individual types in turn:
+/// ```
+/// use unikorn::{Choseong, Jaeum, Jamo};
+/// use core::convert::TryFrom;
+///
+/// assert_eq!(Jamo::try_from('\u{1100}'), Ok(Jamo::Choseong(Choseong::Kiyeok)));
+/// assert_eq!(Jamo::try_from('ㄱ'), Ok(Jamo::CompatJaeum(Jaeum::Kiyeok)));
+/// assert_eq!(Jamo::try_from('\u{FFA1}'), Ok(Jamo::HalfwidthJaeum(Jaeum::Kiyeok)));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Jamo {
+    /// An initial consonant in the conjoining form (U+1100 'ᄀ' -- U+1112 'ᄒ').
+    Choseong(Choseong),
+    /// A medial vowel in the conjoining form (U+1161 'ᅡ' -- U+1175 'ᅵ').
+    Jungseong(Jungseong),
+    /// A final consonant in the conjoining form (U+11A8 'ᆨ' -- U+11C2 'ᇂ').
+    Jongseong(Jongseong),
+    /// A consonant in the compatibility form (U+3131 'ㄱ' -- U+314E 'ㅎ').
+    CompatJaeum(Jaeum),
+    /// A vowel in the compatibility form (U+314F 'ㅏ' -- U+3163 'ㅣ').
+    CompatMoeum(Moeum),
+    /// A consonant in the halfwidth form (U+FFA1 'ﾡ' -- U+FFBE 'ﾾ').
+    HalfwidthJaeum(Jaeum),
+    /// A vowel in the halfwidth form (U+FFC2 'ￂ' -- U+FFDC 'ￜ').
+    HalfwidthMoeum(Moeum),
+}
+impl From<Jamo> for char {
+    fn from(jamo: Jamo) -> Self {
+        match jamo {
+            Jamo::Choseong(choseong) => choseong.to_conjoining(),
+            Jamo::Jungseong(jungseong) => jungseong.to_conjoining(),
+            Jamo::Jongseong(jongseong) => jongseong.to_conjoining(),
+            Jamo::CompatJaeum(jaeum) => jaeum.into(),
+            Jamo::CompatMoeum(moeum) => moeum.into(),
+            Jamo::HalfwidthJaeum(jaeum) => Halfwidth::Consonant(jaeum).into(),
+            Jamo::HalfwidthMoeum(moeum) => Halfwidth::Vowel(moeum).into(),
+        }
+    }
+}
+impl TryFrom<char> for Jamo {
+    type Error = Error;
+
+    fn try_from(character: char) -> Result<Self, Self::Error> {
+        if let Ok(choseong) = Choseong::from_conjoining(character) {
+            Ok(Self::Choseong(choseong))
+        } else if let Ok(jungseong) = Jungseong::from_conjoining(character) {
+            Ok(Self::Jungseong(jungseong))
+        } else if let Ok(jongseong) = Jongseong::from_conjoining(character) {
+            Ok(Self::Jongseong(jongseong))
+        } else if let Ok(jaeum) = Jaeum::try_from(character) {
+            Ok(Self::CompatJaeum(jaeum))
+        } else if let Ok(moeum) = Moeum::try_from(character) {
+            Ok(Self::CompatMoeum(moeum))
+        } else if let Ok(halfwidth) = Halfwidth::try_from(character) {
+            Ok(halfwidth.into())
+        } else {
+            Err(Error::NonJamo(character))
+        }
+    }
+}
+impl Jamo {
+    /// Returns this jamo as an initial consonant, if it is one or a consonant applicable to the
+    /// initial consonant position.
+    ///
+    /// ```
+    /// use unikorn::{Choseong, Jamo};
+    /// use core::convert::TryFrom;
+    ///
+    /// assert_eq!(Jamo::try_from('ㄱ').unwrap().as_choseong(), Some(Choseong::Kiyeok));
+    /// assert_eq!(Jamo::try_from('ㄳ').unwrap().as_choseong(), None);
+    /// ```
+    pub fn as_choseong(&self) -> Option<Choseong> {
+        match *self {
+            Self::Choseong(choseong) => Some(choseong),
+            Self::CompatJaeum(jaeum) | Self::HalfwidthJaeum(jaeum) => jaeum.to_choseong(),
+            _ => None,
+        }
+    }
+
+    /// Returns this jamo as a final consonant, if it is one or a consonant applicable to the final
+    /// consonant position.
+    ///
+    /// ```
+    /// use unikorn::{Jamo, Jongseong};
+    /// use core::convert::TryFrom;
+    ///
+    /// assert_eq!(Jamo::try_from('ㄳ').unwrap().as_jongseong(), Some(Jongseong::KiyeokSios));
+    /// assert_eq!(Jamo::try_from('\u{1100}').unwrap().as_jongseong(), None);
+    /// ```
+    pub fn as_jongseong(&self) -> Option<Jongseong> {
+        match *self {
+            Self::Jongseong(jongseong) => Some(jongseong),
+            Self::CompatJaeum(jaeum) | Self::HalfwidthJaeum(jaeum) => jaeum.to_jongseong(),
+            _ => None,
+        }
+    }
+
+    /// Returns this jamo as a medial vowel, if it is a vowel.
+    ///
+    /// ```
+    /// use unikorn::{Jamo, Jungseong};
+    /// use core::convert::TryFrom;
+    ///
+    /// assert_eq!(Jamo::try_from('ㅏ').unwrap().as_jungseong(), Some(Jungseong::A));
+    /// assert_eq!(Jamo::try_from('ㄱ').unwrap().as_jungseong(), None);
+    /// ```
+    pub fn as_jungseong(&self) -> Option<Jungseong> {
+        match *self {
+            Self::Jungseong(jungseong)
+            | Self::CompatMoeum(jungseong)
+            | Self::HalfwidthMoeum(jungseong) => Some(jungseong),
+            _ => None,
+        }
+    }
+
+    /// Returns which kind of jamo this is.
+    ///
+    /// ```
+    /// use unikorn::{Jamo, JamoKind};
+    /// use core::convert::TryFrom;
+    ///
+    /// assert_eq!(Jamo::try_from('\u{11A8}').unwrap().kind(), JamoKind::Jongseong);
+    /// ```
+    pub fn kind(&self) -> JamoKind {
+        match self {
+            Self::Choseong(_) => JamoKind::Choseong,
+            Self::Jungseong(_) => JamoKind::Jungseong,
+            Self::Jongseong(_) => JamoKind::Jongseong,
+            Self::CompatJaeum(_) => JamoKind::CompatJaeum,
+            Self::CompatMoeum(_) => JamoKind::CompatMoeum,
+            Self::HalfwidthJaeum(_) => JamoKind::HalfwidthJaeum,
+            Self::HalfwidthMoeum(_) => JamoKind::HalfwidthMoeum,
+        }
+    }
+
+    /// Converts this jamo into the compatibility form, or [`None`] if it has no such counterpart.
+    ///
+    /// Every modern jamo does have one, so this currently never returns [`None`].
+    ///
+    /// ```
+    /// use unikorn::{Jaeum, Jamo};
+    /// use core::convert::TryFrom;
+    ///
+    /// assert_eq!(
+    ///     Jamo::try_from('\u{11A8}').unwrap().to_compat(),
+    ///     Some(Jamo::CompatJaeum(Jaeum::Kiyeok))
+    /// );
+    /// ```
+    pub fn to_compat(&self) -> Option<Jamo> {
+        match *self {
+            Self::Choseong(choseong) => Some(Self::CompatJaeum(choseong.into())),
+            Self::Jungseong(jungseong) => Some(Self::CompatMoeum(jungseong)),
+            Self::Jongseong(jongseong) => Some(Self::CompatJaeum(jongseong.into())),
+            Self::HalfwidthJaeum(jaeum) => Some(Self::CompatJaeum(jaeum)),
+            Self::HalfwidthMoeum(moeum) => Some(Self::CompatMoeum(moeum)),
+            compat => Some(compat),
+        }
+    }
+}
+
+/// Denotes the kind of a [`Jamo`], i.e. which variant it is.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum JamoKind {
+    /// See [`Jamo::Choseong`].
+    Choseong,
+    /// See [`Jamo::Jungseong`].
+    Jungseong,
+    /// See [`Jamo::Jongseong`].
+    Jongseong,
+    /// See [`Jamo::CompatJaeum`].
+    CompatJaeum,
+    /// See [`Jamo::CompatMoeum`].
+    CompatMoeum,
+    /// See [`Jamo::HalfwidthJaeum`].
+    HalfwidthJaeum,
+    /// See [`Jamo::HalfwidthMoeum`].
+    HalfwidthMoeum,
+}
+
+/// Decides which block of code points a [`Syllable`](crate::Syllable) is decomposed into.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum JamoForm {
+    /// Hangul Jamo range (U+1100 -- U+11FF), e.g. '강' into U+1100 'ᄀ', U+1161 'ᅡ', and U+11BC
+    /// 'ᆼ'.
+    ///
+    /// This is what renders as a syllable block, and the only form that recomposes into the
+    /// syllable, e.g. by Unicode normalization (NFC).
+    Conjoining,
+    /// Hangul Compatibility Jamo range (U+3131 -- U+318E), e.g. '강' into 'ㄱ', 'ㅏ', and 'ㅇ'.
+    ///
+    /// This is what appears on keyboards, and renders as standalone letters side by side.
+    Compatibility,
+}
+impl JamoForm {
+    pub(crate) fn decompose(self, syllable: Syllable) -> impl Iterator<Item = char> {
+        let (choseong, jungseong, jongseong) = match self {
+            Self::Conjoining => (
+                syllable.choseong.to_conjoining(),
+                syllable.jungseong.to_conjoining(),
+                syllable.jongseong.map(Jongseong::to_conjoining),
+            ),
+            Self::Compatibility => (
+                syllable.choseong.into(),
+                syllable.jungseong.into(),
+                syllable.jongseong.map(char::from),
+            ),
+        };
+
+        iter::once(choseong)
+            .chain(iter::once(jungseong))
+            .chain(jongseong)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub(crate) fn push_decomposed(self, output: &mut String, syllable: Syllable) {
+        output.extend(self.decompose(syllable));
+    }
+}
+
+/// Decides which position of a syllable a consonant is converted into, since Hangul Jamo has
+/// separate code points for the initial and the final consonants.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum JamoPosition {
+    /// The initial consonant (초성, [`Choseong`]) position, e.g. 'ㄱ' into U+1100 'ᄀ'.
+    Initial,
+    /// The final consonant (종성, [`Jongseong`]) position, e.g. 'ㄱ' into U+11A8 'ᆨ'.
+    Final,
+}
+
+/// Converts a consonant in Hangul Compatibility Jamo range into its conjoining counterpart in the
+/// initial consonant (초성, [`Choseong`]) position, or [`None`] if it cannot be converted.
+///
+/// ```
+/// use unikorn::compat_to_conjoining_choseong;
+///
+/// assert_eq!(compat_to_conjoining_choseong('ㄱ'), Some('\u{1100}'));
+/// assert_eq!(compat_to_conjoining_choseong('ㄳ'), None);
+/// ```
+pub fn compat_to_conjoining_choseong(character: char) -> Option<char> {
+    Choseong::try_from(character)
+        .ok()
+        .map(Choseong::to_conjoining)
+}
+
+/// Converts a consonant in Hangul Compatibility Jamo range into its conjoining counterpart in the
+/// final consonant (종성, [`Jongseong`]) position, or [`None`] if it cannot be converted.
+///
+/// ```
+/// use unikorn::compat_to_conjoining_jongseong;
+///
+/// assert_eq!(compat_to_conjoining_jongseong('ㄱ'), Some('\u{11A8}'));
+/// assert_eq!(compat_to_conjoining_jongseong('ㄸ'), None);
+/// ```
+pub fn compat_to_conjoining_jongseong(character: char) -> Option<char> {
+    Jongseong::try_from(character)
+        .ok()
+        .map(Jongseong::to_conjoining)
+}
+
+/// Converts a vowel in Hangul Compatibility Jamo range into its conjoining counterpart in the
+/// medial vowel (중성, [`Jungseong`]) position, or [`None`] if it cannot be converted.
+///
+/// ```
+/// use unikorn::compat_to_conjoining_jungseong;
+///
+/// assert_eq!(compat_to_conjoining_jungseong('ㅏ'), Some('\u{1161}'));
+/// assert_eq!(compat_to_conjoining_jungseong('ㄱ'), None);
+/// ```
+pub fn compat_to_conjoining_jungseong(character: char) -> Option<char> {
+    Jungseong::try_from(character)
+        .ok()
+        .map(Jungseong::to_conjoining)
+}
+
+/// Converts a modern conjoining jamo in Hangul Jamo range, in whichever position, into its
+/// counterpart in Hangul Compatibility Jamo range, or [`None`] if it cannot be converted.
+///
+/// ```
+/// use unikorn::conjoining_char_to_compat;
+///
+/// assert_eq!(conjoining_char_to_compat('\u{1100}'), Some('ㄱ'));
+/// assert_eq!(conjoining_char_to_compat('\u{11A8}'), Some('ㄱ'));
+/// assert_eq!(conjoining_char_to_compat('ㄱ'), None);
+/// ```
+pub fn conjoining_char_to_compat(character: char) -> Option<char> {
+    if let Ok(choseong) = Choseong::from_conjoining(character) {
+        Some(choseong.into())
+    } else if let Ok(jungseong) = Jungseong::from_conjoining(character) {
+        Some(jungseong.into())
+    } else if let Ok(jongseong) = Jongseong::from_conjoining(character) {
+        Some(jongseong.into())
+    } else {
+        None
+    }
+}
+
+/// Converts every Hangul Compatibility Jamo or Halfwidth Hangul jamo in a given string into its
+/// conjoining counterpart in Hangul Jamo range.
+///
+/// Consonants are converted into the given `position`, whereas vowels are always converted into
+/// the medial vowel (중성, [`Jungseong`]) position. A consonant not applicable to the given
+/// `position` (e.g. 'ㄳ' as an initial consonant) is handled according to `on_error`. Anything
+/// else passes through untouched.
+///
+/// ```
+/// use unikorn::{compat_to_conjoining, ConversionFallback, JamoPosition};
+///
+/// assert_eq!(
+///     compat_to_conjoining("ㄱㅏ ㄳ", JamoPosition::Initial, ConversionFallback::Keep),
+///     "\u{1100}\u{1161} ㄳ"
+/// );
+/// assert_eq!(
+///     compat_to_conjoining("ㄱㅏ ㄳ", JamoPosition::Final, ConversionFallback::Keep),
+///     "\u{11A8}\u{1161} \u{11AA}"
+/// );
+/// assert_eq!(
+///     compat_to_conjoining("\u{FFA1}\u{FFC2}", JamoPosition::Initial, ConversionFallback::Keep),
+///     "\u{1100}\u{1161}"
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn compat_to_conjoining(
+    source: &str,
+    position: JamoPosition,
+    on_error: ConversionFallback,
+) -> String {
+    let mut output = String::with_capacity(source.len());
+
+    for original in source.chars() {
+        let character = halfwidth_to_compat(original).unwrap_or(original);
+        if Jaeum::try_from(character).is_ok() {
+            let converted = match position {
+                JamoPosition::Initial => compat_to_conjoining_choseong(character),
+                JamoPosition::Final => compat_to_conjoining_jongseong(character),
+            };
+            match converted {
+                Some(converted) => output.push(converted),
+                None => on_error.apply(&mut output, original),
+            }
+        } else if let Some(converted) = compat_to_conjoining_jungseong(character) {
+            output.push(converted);
+        } else {
+            output.push(original);
+        }
+    }
+
+    output
+}
+
+/// Converts every conjoining jamo or Halfwidth Hangul jamo in a given string into its counterpart
+/// in Hangul Compatibility Jamo range.
+///
+/// A [`char`] in Hangul Jamo range (U+1100 -- U+11FF) without a modern counterpart (e.g. archaic
+/// jamo, or the fillers) is handled according to `on_error`. Anything else passes through
+/// untouched.
+///
+/// ```
+/// use unikorn::{conjoining_to_compat, ConversionFallback};
+///
+/// assert_eq!(
+///     conjoining_to_compat("\u{1100}\u{1161}\u{11A8}", ConversionFallback::Keep),
+///     "ㄱㅏㄱ"
+/// );
+/// assert_eq!(
+///     conjoining_to_compat("\u{1140}\u{1161}", ConversionFallback::ReplacementChar),
+///     "\u{FFFD}ㅏ"
+/// );
+/// assert_eq!(conjoining_to_compat("\u{FFA1}\u{FFC2}", ConversionFallback::Keep), "ㄱㅏ");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn conjoining_to_compat(source: &str, on_error: ConversionFallback) -> String {
+    let mut output = String::with_capacity(source.len());
+
+    for character in source.chars() {
+        if let Some(converted) =
+            conjoining_char_to_compat(character).or_else(|| halfwidth_to_compat(character))
+        {
+            output.push(converted);
+        } else if ('\u{1100}'..='\u{11FF}').contains(&character) {
+            on_error.apply(&mut output, character);
+        } else {
+            output.push(character);
+        }
+    }
+
+    output
+}
+
+// the counterpart in Hangul Compatibility Jamo range, if a given character is a Halfwidth Hangul
+// jamo.
+#[cfg(feature = "alloc")]
+pub(crate) fn halfwidth_to_compat(character: char) -> Option<char> {
+    Halfwidth::try_from(character)
+        .ok()
+        .and_then(|halfwidth| Jamo::from(halfwidth).to_compat())
+        .map(char::from)
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alloc")]
+    use super::{compat_to_conjoining, conjoining_to_compat, ConversionFallback, JamoPosition};
+    use super::{
+        compat_to_conjoining_choseong, compat_to_conjoining_jongseong,
+        compat_to_conjoining_jungseong, conjoining_char_to_compat, Halfwidth, Jamo, JamoKind,
+    };
+    use crate::{Choseong, Error, Jaeum, Jongseong, Jungseong, Moeum};
+    use core::convert::TryFrom;
+
+    #[test]
+    fn test_from_halfwidth_for_char() {
+        assert_eq!(char::from(Halfwidth::Consonant(Jaeum::Kiyeok)), '\u{FFA1}');
+        assert_eq!(char::from(Halfwidth::Consonant(Jaeum::Hieuh)), '\u{FFBE}');
+        assert_eq!(char::from(Halfwidth::Vowel(Moeum::A)), '\u{FFC2}');
+        assert_eq!(char::from(Halfwidth::Vowel(Moeum::E)), '\u{FFC7}');
+        assert_eq!(char::from(Halfwidth::Vowel(Moeum::Yeo)), '\u{FFCA}');
+        assert_eq!(char::from(Halfwidth::Vowel(Moeum::I)), '\u{FFDC}');
+    }
+
+    #[test]
+    fn test_tryfrom_char_for_halfwidth() {
+        assert_eq!(
+            Halfwidth::try_from('\u{FFA4}'),
+            Ok(Halfwidth::Consonant(Jaeum::Nieun))
+        );
+        assert_eq!(
+            Halfwidth::try_from('\u{FFCA}'),
+            Ok(Halfwidth::Vowel(Moeum::Yeo))
+        );
+        // the filler, the unassigned code points in between the vowels, and the fullwidth ones.
+        for character in ['\u{FFA0}', '\u{FFC8}', '\u{FFDD}', 'ㄱ', 'ㅏ', 'a'] {
+            assert_eq!(
+                Halfwidth::try_from(character),
+                Err(Error::NonJamo(character))
+            );
+        }
+
+        // every one of them, round trip.
+        for jaeum in Jaeum::iter() {
+            let halfwidth = Halfwidth::Consonant(jaeum);
+            assert_eq!(Halfwidth::try_from(char::from(halfwidth)), Ok(halfwidth));
+            assert_eq!(Jamo::from(halfwidth), Jamo::HalfwidthJaeum(jaeum));
+        }
+        for moeum in Moeum::iter() {
+            let halfwidth = Halfwidth::Vowel(moeum);
+            assert_eq!(Halfwidth::try_from(char::from(halfwidth)), Ok(halfwidth));
+            assert_eq!(Jamo::from(halfwidth), Jamo::HalfwidthMoeum(moeum));
+        }
+    }
+
+    #[test]
+    fn test_from_jamo_for_char() {
+        assert_eq!(char::from(Jamo::Choseong(Choseong::Hieuh)), '\u{1112}');
+        assert_eq!(char::from(Jamo::Jungseong(Jungseong::I)), '\u{1175}');
+        assert_eq!(char::from(Jamo::Jongseong(Jongseong::Hieuh)), '\u{11C2}');
+        assert_eq!(char::from(Jamo::CompatJaeum(Jaeum::Hieuh)), 'ㅎ');
+        assert_eq!(char::from(Jamo::CompatMoeum(Jungseong::I)), 'ㅣ');
+        assert_eq!(char::from(Jamo::HalfwidthJaeum(Jaeum::Hieuh)), '\u{FFBE}');
+        assert_eq!(char::from(Jamo::HalfwidthMoeum(Jungseong::I)), '\u{FFDC}');
+    }
+
+    #[test]
+    fn test_tryfrom_char_for_jamo() {
+        assert_eq!(
+            Jamo::try_from('\u{1100}'),
+            Ok(Jamo::Choseong(Choseong::Kiyeok))
+        );
+        assert_eq!(
+            Jamo::try_from('\u{1161}'),
+            Ok(Jamo::Jungseong(Jungseong::A))
+        );
+        assert_eq!(
+            Jamo::try_from('\u{11A8}'),
+            Ok(Jamo::Jongseong(Jongseong::Kiyeok))
+        );
+        assert_eq!(
+            Jamo::try_from('ㄳ'),
+            Ok(Jamo::CompatJaeum(Jaeum::KiyeokSios))
+        );
+        assert_eq!(Jamo::try_from('ㅏ'), Ok(Jamo::CompatMoeum(Jungseong::A)));
+        assert_eq!(
+            Jamo::try_from('\u{FFA1}'),
+            Ok(Jamo::HalfwidthJaeum(Jaeum::Kiyeok))
+        );
+        assert_eq!(
+            Jamo::try_from('\u{FFC2}'),
+            Ok(Jamo::HalfwidthMoeum(Jungseong::A))
+        );
+
+        assert_eq!(Jamo::try_from('\u{115F}'), Err(Error::NonJamo('\u{115F}')));
+        assert_eq!(Jamo::try_from('\u{FFA0}'), Err(Error::NonJamo('\u{FFA0}')));
+        assert_eq!(Jamo::try_from('가'), Err(Error::NonJamo('가')));
+        assert_eq!(Jamo::try_from('a'), Err(Error::NonJamo('a')));
+
+        for code_point in (0x1100..=0x3163).chain(0xFFA0..=0xFFDC) {
+            if let Some(character) = char::from_u32(code_point) {
+                if let Ok(jamo) = Jamo::try_from(character) {
+                    assert_eq!(char::from(jamo), character);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_jamo_as_choseong() {
+        assert_eq!(
+            Jamo::Choseong(Choseong::Kiyeok).as_choseong(),
+            Some(Choseong::Kiyeok)
+        );
+        assert_eq!(
+            Jamo::CompatJaeum(Jaeum::SsangTikeut).as_choseong(),
+            Some(Choseong::SsangTikeut)
+        );
+
+        assert_eq!(Jamo::CompatJaeum(Jaeum::RieulKiyeok).as_choseong(), None);
+        assert_eq!(Jamo::Jongseong(Jongseong::Kiyeok).as_choseong(), None);
+        assert_eq!(Jamo::CompatMoeum(Jungseong::A).as_choseong(), None);
+    }
+
+    #[test]
+    fn test_jamo_as_jongseong() {
+        assert_eq!(
+            Jamo::Jongseong(Jongseong::Kiyeok).as_jongseong(),
+            Some(Jongseong::Kiyeok)
+        );
+        assert_eq!(
+            Jamo::CompatJaeum(Jaeum::RieulKiyeok).as_jongseong(),
+            Some(Jongseong::RieulKiyeok)
+        );
+
+        assert_eq!(Jamo::CompatJaeum(Jaeum::SsangTikeut).as_jongseong(), None);
+        assert_eq!(Jamo::Choseong(Choseong::Kiyeok).as_jongseong(), None);
+    }
+
+    #[test]
+    fn test_jamo_as_jungseong() {
+        assert_eq!(
+            Jamo::Jungseong(Jungseong::Wa).as_jungseong(),
+            Some(Jungseong::Wa)
+        );
+        assert_eq!(
+            Jamo::CompatMoeum(Jungseong::Wa).as_jungseong(),
+            Some(Jungseong::Wa)
+        );
+
+        assert_eq!(Jamo::CompatJaeum(Jaeum::Ieung).as_jungseong(), None);
+    }
+
+    #[test]
+    fn test_jamo_kind() {
+        assert_eq!(Jamo::Choseong(Choseong::Kiyeok).kind(), JamoKind::Choseong);
+        assert_eq!(Jamo::Jungseong(Jungseong::A).kind(), JamoKind::Jungseong);
+        assert_eq!(
+            Jamo::Jongseong(Jongseong::Kiyeok).kind(),
+            JamoKind::Jongseong
+        );
+        assert_eq!(
+            Jamo::CompatJaeum(Jaeum::Kiyeok).kind(),
+            JamoKind::CompatJaeum
+        );
+        assert_eq!(
+            Jamo::CompatMoeum(Jungseong::A).kind(),
+            JamoKind::CompatMoeum
+        );
+        assert_eq!(
+            Jamo::HalfwidthJaeum(Jaeum::Kiyeok).kind(),
+            JamoKind::HalfwidthJaeum
+        );
+        assert_eq!(
+            Jamo::HalfwidthMoeum(Jungseong::A).kind(),
+            JamoKind::HalfwidthMoeum
+        );
+    }
+
+    #[test]
+    fn test_jamo_to_compat() {
+        assert_eq!(
+            Jamo::Choseong(Choseong::SsangKiyeok).to_compat(),
+            Some(Jamo::CompatJaeum(Jaeum::SsangKiyeok))
+        );
+        assert_eq!(
+            Jamo::Jungseong(Jungseong::Wa).to_compat(),
+            Some(Jamo::CompatMoeum(Jungseong::Wa))
+        );
+        assert_eq!(
+            Jamo::Jongseong(Jongseong::RieulKiyeok).to_compat(),
+            Some(Jamo::CompatJaeum(Jaeum::RieulKiyeok))
+        );
+        assert_eq!(
+            Jamo::CompatJaeum(Jaeum::Kiyeok).to_compat(),
+            Some(Jamo::CompatJaeum(Jaeum::Kiyeok))
+        );
+        assert_eq!(
+            Jamo::HalfwidthJaeum(Jaeum::Kiyeok).to_compat(),
+            Some(Jamo::CompatJaeum(Jaeum::Kiyeok))
+        );
+        assert_eq!(
+            Jamo::HalfwidthMoeum(Jungseong::Wa).to_compat(),
+            Some(Jamo::CompatMoeum(Jungseong::Wa))
+        );
+    }
+
+    #[test]
+    fn test_compat_to_conjoining_choseong() {
+        assert_eq!(compat_to_conjoining_choseong('ㄱ'), Some('ᄀ'));
+        assert_eq!(compat_to_conjoining_choseong('ㅉ'), Some('ᄍ'));
+
+        assert_eq!(compat_to_conjoining_choseong('ㄺ'), None);
+        assert_eq!(compat_to_conjoining_choseong('ㅏ'), None);
+        assert_eq!(compat_to_conjoining_choseong('ᄀ'), None);
+    }
+
+    #[test]
+    fn test_compat_to_conjoining_jongseong() {
+        assert_eq!(compat_to_conjoining_jongseong('ㄱ'), Some('ᆨ'));
+        assert_eq!(compat_to_conjoining_jongseong('ㄺ'), Some('ᆰ'));
+
+        assert_eq!(compat_to_conjoining_jongseong('ㅉ'), None);
+        assert_eq!(compat_to_conjoining_jongseong('a'), None);
+    }
+
+    #[test]
+    fn test_compat_to_conjoining_jungseong() {
+        assert_eq!(compat_to_conjoining_jungseong('ㅘ'), Some('ᅪ'));
+
+        assert_eq!(compat_to_conjoining_jungseong('ㄱ'), None);
+        assert_eq!(compat_to_conjoining_jungseong('ᅪ'), None);
+    }
+
+    #[test]
+    fn test_conjoining_char_to_compat() {
+        assert_eq!(conjoining_char_to_compat('ᄀ'), Some('ㄱ'));
+        assert_eq!(conjoining_char_to_compat('ᅪ'), Some('ㅘ'));
+        assert_eq!(conjoining_char_to_compat('ᆰ'), Some('ㄺ'));
+
+        assert_eq!(conjoining_char_to_compat('ᅟ'), None);
+        assert_eq!(conjoining_char_to_compat('ㄱ'), None);
+        assert_eq!(conjoining_char_to_compat('가'), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_compat_to_conjoining() {
+        assert_eq!(
+            compat_to_conjoining("ㅎㅏㄴ", JamoPosition::Initial, ConversionFallback::Keep),
+            "\u{1112}\u{1161}\u{1102}"
+        );
+        assert_eq!(
+            compat_to_conjoining("ㄸㅓㄱ", JamoPosition::Final, ConversionFallback::Keep),
+            "ㄸ\u{1165}\u{11A8}"
+        );
+        assert_eq!(
+            compat_to_conjoining("ㄸㅓㄱ", JamoPosition::Final, ConversionFallback::Skip),
+            "\u{1165}\u{11A8}"
+        );
+        assert_eq!(
+            compat_to_conjoining(
+                "ㄸㅓㄱ",
+                JamoPosition::Final,
+                ConversionFallback::ReplacementChar
+            ),
+            "\u{FFFD}\u{1165}\u{11A8}"
+        );
+
+        assert_eq!(
+            compat_to_conjoining("abc 한글", JamoPosition::Initial, ConversionFallback::Skip),
+            "abc 한글"
+        );
+
+        // halfwidth 'ﾸ', 'ￃ', and 'ﾴ'.
+        assert_eq!(
+            compat_to_conjoining(
+                "\u{FFB8}\u{FFC3}\u{FFB4}",
+                JamoPosition::Initial,
+                ConversionFallback::Keep
+            ),
+            "\u{110C}\u{1162}\u{FFB4}"
+        );
+        assert_eq!(
+            compat_to_conjoining(
+                "\u{FFB8}\u{FFC3}\u{FFB4}",
+                JamoPosition::Final,
+                ConversionFallback::Keep
+            ),
+            "\u{11BD}\u{1162}\u{11B9}"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_conjoining_to_compat() {
+        assert_eq!(
+            conjoining_to_compat("\u{1112}\u{1161}\u{11AB}", ConversionFallback::Keep),
+            "ㅎㅏㄴ"
+        );
+        assert_eq!(
+            conjoining_to_compat("\u{115F}\u{1161}", ConversionFallback::Keep),
+            "\u{115F}ㅏ"
+        );
+        assert_eq!(
+            conjoining_to_compat("\u{115F}\u{1161}", ConversionFallback::Skip),
+            "ㅏ"
+        );
+
+        assert_eq!(
+            conjoining_to_compat("abc 한글 ㄱ", ConversionFallback::Skip),
+            "abc 한글 ㄱ"
+        );
+
+        assert_eq!(
+            conjoining_to_compat("\u{FFB8}\u{FFC3}\u{FFB4}", ConversionFallback::Skip),
+            "ㅈㅐㅄ"
+        );
+    }
+}
@@ -0,0 +1,282 @@
+//! Conversions from and into Johab (조합형, Johab-hyeong), the legacy 2-byte encoding which packs
+//! the initial consonant, the medial vowel, and the final consonant of a syllable into separate
+//! 5-bit fields.
+//!
+//! Only the Korean portion of Johab, i.e. syllables and standalone jamo, and ASCII are supported;
+//! symbols and Hanja are not.
+use crate::{Choseong, Error, Jongseong, Jungseong, Syllable};
+#[cfg(feature = "alloc")]
+use crate::{Jaeum, Moeum};
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::convert::TryFrom;
+
+// Johab uses these values in each field to denote the absence of a component.
+const CHOSEONG_FILL: u16 = 1;
+const JUNGSEONG_FILL: u16 = 2;
+const JONGSEONG_FILL: u16 = 1;
+
+// indexed by `Jungseong as usize`; Johab leaves gaps in between the vowels.
+const JUNGSEONG_TO_JOHAB: [u16; 21] = [
+    3, 4, 5, 6, 7, 10, 11, 12, 13, 14, 15, 18, 19, 20, 21, 22, 23, 26, 27, 28, 29,
+];
+
+fn choseong_to_johab(choseong: Option<Choseong>) -> u16 {
+    choseong.map_or(CHOSEONG_FILL, |choseong| choseong as u16 + 2)
+}
+
+fn choseong_from_johab(value: u16) -> Option<Option<Choseong>> {
+    match value {
+        CHOSEONG_FILL => Some(None),
+        2..=20 => Some(Some(Choseong::try_from(value as u8 - 2).unwrap())),
+        _ => None,
+    }
+}
+
+fn jungseong_to_johab(jungseong: Option<Jungseong>) -> u16 {
+    jungseong.map_or(JUNGSEONG_FILL, |jungseong| {
+        JUNGSEONG_TO_JOHAB[jungseong as usize]
+    })
+}
+
+fn jungseong_from_johab(value: u16) -> Option<Option<Jungseong>> {
+    if value == JUNGSEONG_FILL {
+        return Some(None);
+    }
+
+    JUNGSEONG_TO_JOHAB
+        .iter()
+        .position(|&johab| johab == value)
+        .map(|index| Some(Jungseong::try_from(index as u8).unwrap()))
+}
+
+fn jongseong_to_johab(jongseong: Option<Jongseong>) -> u16 {
+    // Johab skips 18 right after 'ㅁ'.
+    jongseong.map_or(JONGSEONG_FILL, |jongseong| match jongseong as u16 {
+        index @ 1..=16 => index + 1,
+        index => index + 2,
+    })
+}
+
+fn jongseong_from_johab(value: u16) -> Option<Option<Jongseong>> {
+    match value {
+        JONGSEONG_FILL => Some(None),
+        2..=17 => Some(Some(Jongseong::try_from(value as u8 - 1).unwrap())),
+        19..=29 => Some(Some(Jongseong::try_from(value as u8 - 2).unwrap())),
+        _ => None,
+    }
+}
+
+fn pack(
+    choseong: Option<Choseong>,
+    jungseong: Option<Jungseong>,
+    jongseong: Option<Jongseong>,
+) -> u16 {
+    0x8000
+        | choseong_to_johab(choseong) << 10
+        | jungseong_to_johab(jungseong) << 5
+        | jongseong_to_johab(jongseong)
+}
+
+#[allow(clippy::type_complexity)]
+fn unpack(code: u16) -> Option<(Option<Choseong>, Option<Jungseong>, Option<Jongseong>)> {
+    if code & 0x8000 == 0 {
+        return None;
+    }
+
+    Some((
+        choseong_from_johab((code >> 10) & 0x1F)?,
+        jungseong_from_johab((code >> 5) & 0x1F)?,
+        jongseong_from_johab(code & 0x1F)?,
+    ))
+}
+
+impl Syllable {
+    /// Converts a 2-byte Johab code into a [`Syllable`].
+    ///
+    /// ```
+    /// use unikorn::{Error, Syllable};
+    ///
+    /// assert_eq!(Syllable::from_johab(0x8861), Ok(Syllable::try_from('가').unwrap()));
+    /// assert_eq!(Syllable::from_johab(0x8841), Err(Error::NonJohab(0x8841))); // no vowel
+    /// ```
+    pub fn from_johab(code: u16) -> Result<Self, Error> {
+        match unpack(code) {
+            Some((Some(choseong), Some(jungseong), jongseong)) => Ok(Self {
+                choseong,
+                jungseong,
+                jongseong,
+            }),
+            _ => Err(Error::NonJohab(code)),
+        }
+    }
+
+    /// Converts this syllable into a 2-byte Johab code.
+    ///
+    /// Every modern syllable is representable in Johab, so this never fails.
+    ///
+    /// ```
+    /// use unikorn::Syllable;
+    ///
+    /// assert_eq!(Syllable::try_from('가').unwrap().to_johab(), 0x8861);
+    /// assert_eq!(Syllable::try_from('힣').unwrap().to_johab(), 0xD3BD);
+    /// ```
+    pub fn to_johab(self) -> u16 {
+        pack(Some(self.choseong), Some(self.jungseong), self.jongseong)
+    }
+}
+
+/// Decodes a Johab-encoded byte sequence into a [`String`].
+///
+/// Bytes below 0x80 are treated as ASCII, and every other pair of bytes is treated as either a
+/// syllable, or a standalone consonant or vowel, which decodes into Hangul Compatibility Jamo.
+///
+/// ```
+/// use unikorn::{decode_johab, Error};
+///
+/// assert_eq!(decode_johab(&[0x88, 0x61, b'!', 0x88, 0x41]), Ok(String::from("가!ㄱ")));
+/// assert_eq!(decode_johab(&[0x88]), Err(Error::NonJohab(0x88)));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_johab(bytes: &[u8]) -> Result<String, Error> {
+    let mut decoded = String::with_capacity(bytes.len() * 3 / 2);
+
+    let mut bytes = bytes.iter().copied();
+    while let Some(leading) = bytes.next() {
+        if leading < 0x80 {
+            decoded.push(leading as char);
+            continue;
+        }
+
+        let trailing = bytes.next().ok_or(Error::NonJohab(leading as u16))?;
+        let code = (leading as u16) << 8 | trailing as u16;
+        let character = match unpack(code) {
+            Some((Some(choseong), Some(jungseong), jongseong)) => {
+                char::from(Syllable::from((choseong, jungseong, jongseong)))
+            }
+            Some((Some(choseong), None, None)) => char::from(choseong),
+            Some((None, Some(jungseong), None)) => char::from(jungseong),
+            Some((None, None, Some(jongseong))) => char::from(jongseong),
+            _ => return Err(Error::NonJohab(code)),
+        };
+        decoded.push(character);
+    }
+
+    Ok(decoded)
+}
+
+/// Encodes a string into a Johab-encoded byte sequence.
+///
+/// Only ASCII, Precomposed Korean [`Syllable`]s, and Hangul Compatibility Jamo are representable.
+/// Consonants are encoded as initial consonants where possible, and as final consonants otherwise.
+///
+/// ```
+/// use unikorn::{encode_johab, Error};
+///
+/// assert_eq!(encode_johab("가!"), Ok(vec![0x88, 0x61, b'!']));
+/// assert_eq!(encode_johab("é"), Err(Error::NotRepresentableInJohab('é')));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_johab(source: &str) -> Result<Vec<u8>, Error> {
+    let mut encoded = Vec::with_capacity(source.len());
+
+    for character in source.chars() {
+        if character.is_ascii() {
+            encoded.push(character as u8);
+            continue;
+        }
+
+        let code = if let Ok(syllable) = Syllable::try_from(character) {
+            syllable.to_johab()
+        } else if let Ok(jaeum) = Jaeum::try_from(character) {
+            match jaeum.to_choseong() {
+                Some(choseong) => pack(Some(choseong), None, None),
+                None => pack(None, None, jaeum.to_jongseong()),
+            }
+        } else if let Ok(moeum) = Moeum::try_from(character) {
+            pack(None, Some(moeum), None)
+        } else {
+            return Err(Error::NotRepresentableInJohab(character));
+        };
+        encoded.extend_from_slice(&code.to_be_bytes());
+    }
+
+    Ok(encoded)
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alloc")]
+    use super::{decode_johab, encode_johab};
+    use crate::{Error, Syllable};
+    use core::convert::TryFrom;
+
+    #[test]
+    fn test_syllable_from_johab() {
+        assert_eq!(
+            Syllable::from_johab(0x8861),
+            Ok(Syllable::try_from('가').unwrap())
+        );
+        assert_eq!(
+            Syllable::from_johab(0xA2A1),
+            Ok(Syllable::try_from('뭐').unwrap())
+        );
+        assert_eq!(
+            Syllable::from_johab(0xD3BD),
+            Ok(Syllable::try_from('힣').unwrap())
+        );
+
+        assert_eq!(Syllable::from_johab(0x0041), Err(Error::NonJohab(0x0041)));
+        assert_eq!(Syllable::from_johab(0x8441), Err(Error::NonJohab(0x8441)));
+        assert_eq!(Syllable::from_johab(0x8872), Err(Error::NonJohab(0x8872)));
+    }
+
+    #[test]
+    fn test_syllable_to_johab() {
+        assert_eq!(Syllable::try_from('가').unwrap().to_johab(), 0x8861);
+        assert_eq!(Syllable::try_from('뭐').unwrap().to_johab(), 0xA2A1);
+        assert_eq!(Syllable::try_from('힣').unwrap().to_johab(), 0xD3BD);
+    }
+
+    #[test]
+    fn test_syllable_johab_round_trip() {
+        for code_point in 0xAC00..=0xD7A3 {
+            let syllable = Syllable::try_from(char::from_u32(code_point).unwrap()).unwrap();
+            assert_eq!(Syllable::from_johab(syllable.to_johab()), Ok(syllable));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_johab() {
+        assert_eq!(
+            decode_johab(&[0xD0, 0x65, 0x8B, 0x69, b' ', b'o', b'k']),
+            Ok(String::from("한글 ok"))
+        );
+        assert_eq!(
+            decode_johab(&[0x88, 0x41, 0x84, 0x61, 0x84, 0x44]),
+            Ok(String::from("ㄱㅏㄳ"))
+        );
+
+        assert_eq!(decode_johab(&[b'a', 0xD0]), Err(Error::NonJohab(0xD0)));
+        assert_eq!(decode_johab(&[0xD9, 0xA1]), Err(Error::NonJohab(0xD9A1)));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_encode_johab() {
+        assert_eq!(
+            encode_johab("한글 ok"),
+            Ok(vec![0xD0, 0x65, 0x8B, 0x69, b' ', b'o', b'k'])
+        );
+        assert_eq!(
+            encode_johab("ㄱㅏㄳ"),
+            Ok(vec![0x88, 0x41, 0x84, 0x61, 0x84, 0x44])
+        );
+
+        assert_eq!(
+            encode_johab("漢字"),
+            Err(Error::NotRepresentableInJohab('漢'))
+        );
+    }
+}
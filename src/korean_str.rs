@@ -0,0 +1,364 @@
+//! String types guaranteed to consist of the Precomposed Korean [`Syllable`]s only.
+use crate::{Error, Syllable};
+#[cfg(feature = "alloc")]
+use alloc::{borrow::ToOwned, string::String};
+#[cfg(feature = "alloc")]
+use core::{borrow::Borrow, iter::FromIterator};
+use core::{
+    convert::TryFrom,
+    fmt::{self, Display, Formatter},
+    ops::{Bound, Deref, RangeBounds},
+};
+
+/// A string slice made up of the Precomposed Korean [`Syllable`]s only, e.g. '한글', but not
+/// '한글 ok' or 'ㅎ'.
+///
+/// As every syllable takes up exactly 3 bytes in UTF-8, counting, indexing, and slicing by
+/// syllables take constant time, unlike on a [`str`] in general; see [`KoreanStr::len_syllables`],
+/// [`KoreanStr::nth_syllable`], and [`KoreanStr::get_syllables`]. An empty string counts as one as
+/// well.
+///
+/// It dereferences into a [`str`], for everything else.
+///
+/// ```
+/// use unikorn::KoreanStr;
+///
+/// let korean = KoreanStr::try_from_str("대한민국").unwrap();
+/// assert_eq!(korean.len_syllables(), 4);
+/// assert_eq!(korean.nth_syllable(1).unwrap(), '한');
+/// assert_eq!(korean.get_syllables(2..).unwrap().as_str(), "민국");
+///
+/// assert!(KoreanStr::try_from_str("한글 ok").is_err());
+/// ```
+#[derive(Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct KoreanStr(str);
+impl AsRef<str> for KoreanStr {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl Deref for KoreanStr {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl Display for KoreanStr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+#[cfg(feature = "alloc")]
+impl ToOwned for KoreanStr {
+    type Owned = KoreanString;
+
+    fn to_owned(&self) -> Self::Owned {
+        KoreanString(self.0.into())
+    }
+}
+impl<'a> TryFrom<&'a str> for &'a KoreanStr {
+    type Error = Error;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        KoreanStr::try_from_str(s)
+    }
+}
+impl KoreanStr {
+    /// Checks if every [`char`] in a given string is a Precomposed Korean [`Syllable`], and if so,
+    /// returns it as a `KoreanStr`.
+    ///
+    /// # Errors
+    /// Returns the error [`Syllable::try_from`] gives for the first [`char`] that is not a
+    /// syllable, i.e. [`Error::NonKorean`].
+    ///
+    /// ```
+    /// use unikorn::{Error, KoreanStr};
+    ///
+    /// assert!(KoreanStr::try_from_str("한글").is_ok());
+    /// assert_eq!(
+    ///     KoreanStr::try_from_str("한 글"),
+    ///     Err(Error::NonKorean(' ', None))
+    /// );
+    /// ```
+    pub fn try_from_str(s: &str) -> Result<&Self, Error> {
+        for character in s.chars() {
+            Syllable::try_from(character)?;
+        }
+
+        Ok(Self::from_str_unchecked(s))
+    }
+
+    /// Returns this as a plain [`str`].
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns a sub-slice of this, by the syllable indices rather than the byte offsets
+    /// [`str::get`] takes, or [`None`] if `range` is out of bounds or reversed.
+    ///
+    /// ```
+    /// use unikorn::KoreanStr;
+    ///
+    /// let korean = KoreanStr::try_from_str("대한민국").unwrap();
+    /// assert_eq!(korean.get_syllables(1..3).unwrap().as_str(), "한민");
+    /// assert_eq!(korean.get_syllables(..=0).unwrap().as_str(), "대");
+    /// assert!(korean.get_syllables(3..5).is_none());
+    /// ```
+    pub fn get_syllables<R: RangeBounds<usize>>(&self, range: R) -> Option<&Self> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1)?,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len_syllables(),
+        };
+        if start > end || end > self.len_syllables() {
+            return None;
+        }
+
+        Some(Self::from_str_unchecked(&self.0[start * 3..end * 3]))
+    }
+
+    /// Returns how many syllables this consists of, in constant time.
+    ///
+    /// ```
+    /// use unikorn::KoreanStr;
+    ///
+    /// assert_eq!(KoreanStr::try_from_str("한글").unwrap().len_syllables(), 2);
+    /// assert_eq!(KoreanStr::try_from_str("").unwrap().len_syllables(), 0);
+    /// ```
+    pub fn len_syllables(&self) -> usize {
+        self.0.len() / 3
+    }
+
+    /// Returns the `n`th syllable (0-based) of this, in constant time, or [`None`] if there are not
+    /// that many.
+    ///
+    /// ```
+    /// use unikorn::KoreanStr;
+    ///
+    /// let korean = KoreanStr::try_from_str("한글").unwrap();
+    /// assert_eq!(korean.nth_syllable(1).unwrap(), '글');
+    /// assert_eq!(korean.nth_syllable(2), None);
+    /// ```
+    pub fn nth_syllable(&self, n: usize) -> Option<Syllable> {
+        let start = n.checked_mul(3)?;
+        self.0
+            .get(start..start.checked_add(3)?)?
+            .chars()
+            .next()
+            .and_then(|character| Syllable::try_from(character).ok())
+    }
+
+    /// Iterates over the syllables of this, from left to right.
+    ///
+    /// ```
+    /// use unikorn::KoreanStr;
+    ///
+    /// let korean = KoreanStr::try_from_str("한글").unwrap();
+    /// assert_eq!(korean.syllables().len(), 2);
+    /// assert_eq!(korean.syllables().rev().next().unwrap(), '글');
+    /// ```
+    pub fn syllables(&self) -> impl DoubleEndedIterator<Item = Syllable> + ExactSizeIterator + '_ {
+        (0..self.len_syllables()).map(move |n| self.nth_syllable(n).unwrap())
+    }
+
+    // wraps a string already known to be made up of syllables only.
+    fn from_str_unchecked(s: &str) -> &Self {
+        // SAFETY: `KoreanStr` is a `repr(transparent)` wrapper around `str`, so the two have the
+        // same layout.
+        unsafe { &*(s as *const str as *const Self) }
+    }
+}
+
+/// An owned string made up of the Precomposed Korean [`Syllable`]s only; the owned counterpart of
+/// [`KoreanStr`], which it dereferences into.
+///
+/// ```
+/// use unikorn::{KoreanString, Syllable};
+/// use std::convert::TryFrom;
+///
+/// let mut korean = KoreanString::try_from_string(String::from("한")).unwrap();
+/// korean.push(Syllable::try_from('글').unwrap());
+/// assert_eq!(korean.len_syllables(), 2);
+/// assert_eq!(korean.into_string(), "한글");
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct KoreanString(String);
+#[cfg(feature = "alloc")]
+impl AsRef<KoreanStr> for KoreanString {
+    fn as_ref(&self) -> &KoreanStr {
+        self
+    }
+}
+#[cfg(feature = "alloc")]
+impl AsRef<str> for KoreanString {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+#[cfg(feature = "alloc")]
+impl Borrow<KoreanStr> for KoreanString {
+    fn borrow(&self) -> &KoreanStr {
+        self
+    }
+}
+#[cfg(feature = "alloc")]
+impl Deref for KoreanString {
+    type Target = KoreanStr;
+
+    fn deref(&self) -> &Self::Target {
+        KoreanStr::from_str_unchecked(&self.0)
+    }
+}
+#[cfg(feature = "alloc")]
+impl Display for KoreanString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+#[cfg(feature = "alloc")]
+impl From<&KoreanStr> for KoreanString {
+    fn from(korean: &KoreanStr) -> Self {
+        korean.to_owned()
+    }
+}
+#[cfg(feature = "alloc")]
+impl From<KoreanString> for String {
+    fn from(korean: KoreanString) -> Self {
+        korean.0
+    }
+}
+#[cfg(feature = "alloc")]
+impl FromIterator<Syllable> for KoreanString {
+    fn from_iter<I: IntoIterator<Item = Syllable>>(iter: I) -> Self {
+        Self(iter.into_iter().map(char::from).collect())
+    }
+}
+#[cfg(feature = "alloc")]
+impl TryFrom<String> for KoreanString {
+    type Error = (String, Error);
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::try_from_string(s)
+    }
+}
+#[cfg(feature = "alloc")]
+impl KoreanString {
+    /// Checks if every [`char`] in a given string is a Precomposed Korean [`Syllable`], and if so,
+    /// wraps it into a `KoreanString` without copying.
+    ///
+    /// # Errors
+    /// Returns the string given back, along with the error [`KoreanStr::try_from_str`] gives.
+    ///
+    /// ```
+    /// use unikorn::{Error, KoreanString};
+    ///
+    /// assert!(KoreanString::try_from_string(String::from("한글")).is_ok());
+    /// assert_eq!(
+    ///     KoreanString::try_from_string(String::from("한글!")),
+    ///     Err((String::from("한글!"), Error::NonKorean('!', None)))
+    /// );
+    /// ```
+    pub fn try_from_string(s: String) -> Result<Self, (String, Error)> {
+        match KoreanStr::try_from_str(&s) {
+            Ok(_) => Ok(Self(s)),
+            Err(error) => Err((s, error)),
+        }
+    }
+
+    /// Returns this as a [`KoreanStr`].
+    pub fn as_korean_str(&self) -> &KoreanStr {
+        self
+    }
+
+    /// Unwraps this `KoreanString`, returning the underlying [`String`].
+    pub fn into_string(self) -> String {
+        self.0
+    }
+
+    /// Appends a syllable to the end of this.
+    pub fn push(&mut self, syllable: Syllable) {
+        self.0.push(syllable.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KoreanStr;
+    #[cfg(feature = "alloc")]
+    use super::KoreanString;
+    use crate::{Error, Syllable};
+    #[cfg(feature = "alloc")]
+    use alloc::{borrow::ToOwned, string::String};
+    use core::convert::TryFrom;
+
+    #[test]
+    fn test_korean_str_try_from_str() {
+        assert_eq!(KoreanStr::try_from_str("한글").unwrap().as_str(), "한글");
+        assert_eq!(KoreanStr::try_from_str("").unwrap().as_str(), "");
+        assert_eq!(
+            KoreanStr::try_from_str("한ㄱ"),
+            Err(Error::NonKorean('ㄱ', None))
+        );
+        // conjoining jamo do not count, even if they render as a syllable.
+        assert!(KoreanStr::try_from_str("\u{1112}\u{1161}\u{11AB}").is_err());
+        assert!(<&KoreanStr>::try_from("a").is_err());
+    }
+
+    #[test]
+    fn test_korean_str_get_syllables() {
+        let korean = KoreanStr::try_from_str("가나다").unwrap();
+        assert_eq!(korean.get_syllables(..).unwrap().as_str(), "가나다");
+        assert_eq!(korean.get_syllables(1..2).unwrap().as_str(), "나");
+        assert_eq!(korean.get_syllables(3..).unwrap().as_str(), "");
+        assert_eq!(korean.get_syllables(1..1).unwrap().as_str(), "");
+        assert!(korean.get_syllables(2..=3).is_none());
+        assert!(korean.get_syllables(4..).is_none());
+        assert!(korean
+            .get_syllables((core::ops::Bound::Included(2), core::ops::Bound::Excluded(1)))
+            .is_none());
+    }
+
+    #[test]
+    fn test_korean_str_nth_syllable() {
+        let korean = KoreanStr::try_from_str("가나다").unwrap();
+        assert_eq!(korean.len_syllables(), 3);
+        assert_eq!(korean.nth_syllable(0), Syllable::try_from('가').ok());
+        assert_eq!(korean.nth_syllable(2), Syllable::try_from('다').ok());
+        assert_eq!(korean.nth_syllable(3), None);
+        assert_eq!(korean.nth_syllable(usize::MAX), None);
+        assert!(korean.syllables().eq("가나다"
+            .chars()
+            .map(|character| Syllable::try_from(character).unwrap())));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_korean_string() {
+        let mut korean = KoreanString::try_from_string(String::from("가")).unwrap();
+        korean.push(Syllable::try_from('각').unwrap());
+        assert_eq!(korean.as_str(), "가각");
+        assert_eq!(korean.get_syllables(1..).unwrap().as_str(), "각");
+        assert_eq!(korean.as_korean_str().to_owned(), korean);
+
+        assert_eq!(
+            KoreanString::try_from("가 ".to_owned()),
+            Err(("가 ".to_owned(), Error::NonKorean(' ', None)))
+        );
+        assert_eq!(
+            "한글"
+                .chars()
+                .map(|character| Syllable::try_from(character).unwrap())
+                .collect::<KoreanString>()
+                .into_string(),
+            "한글"
+        );
+    }
+}
@@ -4,20 +4,206 @@
 //! * Decompose a Precomposed Korean [`Syllable`] into individual 'consonants and vowels' (자모,
 //!   Jamo), and
 //! * Do the reverse of above action, i.e., compose a set of individual consonants and vowels
-//!   into a Precomposed Korean Syllable.
-use num_enum::{IntoPrimitive, TryFromPrimitive};
-use std::{
+//!   into a Precomposed Korean Syllable, and
+//! * Sort strings in Korean dictionary order with [`korean_cmp`].
+//!
+//! # Features
+//! * `std` (default): implements [`std::error::Error`] for [`Error`], and enables [`io`],
+//!   [`jamo_frequencies`], and [`syllable_ngram_counts`]. Implies `alloc`.
+//! * `alloc`: enables the functions returning a [`String`] or a [`Vec`], e.g.
+//!   [`normalize_halfwidth`] or [`decode_johab`], and [`transform`]. [`write_decomposed`] and
+//!   [`write_composed`] write into a [`core::fmt::Write`] instead, without this feature.
+//! * `cli`: builds the `unikorn` binary, a command line front end to the string transformations,
+//!   e.g. `unikorn decompose --form compat < input.txt`. Implies `std`.
+//! * `arbitrary`: implements `arbitrary::Arbitrary` for the consonant, vowel, and syllable types,
+//!   for fuzzing.
+//! * `euc-kr`: enables the conversions from and into EUC-KR.
+//! * `hanja`: enables `BasicHanjaReadings`, a small dictionary of the readings of common Hanja
+//!   for trying [`hanja_to_hangul`] out. Implies `alloc`.
+//! * `fast-lookup`: converts a [`char`] into a [`Choseong`], a [`Jungseong`], or a [`Jongseong`]
+//!   through a single lookup table, at the cost of 153 bytes of static data.
+//! * `proptest`: implements `proptest::arbitrary::Arbitrary` for the consonant, vowel, and
+//!   syllable types, for property-based testing.
+//! * `rayon`: enables the parallel counterparts of the syllable-by-syllable transformations, e.g.
+//!   `par_decompose` and `par_map_syllables`.
+//! * `simd`: scans for Korean syllables 16 bytes at a time in [`is_any_korean`],
+//!   [`count_korean_syllables`], [`find_korean_syllable`], and whatever builds on them, using SSE2
+//!   on x86 and x86-64.
+//! * `wasm`: enables the `wasm` module, the bindings for JavaScript and TypeScript. Implies
+//!   `alloc`.
+//!
+//! Without `std`, the crate is `#![no_std]`; the consonant, vowel, and syllable types work all the
+//! same.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+// `rayon` needs `std` anyway, so `alloc` is always there with it.
+#[cfg(any(feature = "alloc", feature = "rayon"))]
+extern crate alloc;
+
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+mod arbitrary;
+#[cfg(feature = "alloc")]
+mod builder;
+#[cfg(feature = "alloc")]
+mod bulk;
+mod char_ext;
+mod collation;
+mod conjugation;
+#[cfg(feature = "euc-kr")]
+mod euckr;
+mod fold;
+mod forms;
+#[cfg(feature = "alloc")]
+mod hanja;
+#[cfg(feature = "std")]
+pub mod io;
+mod jamo;
+mod johab;
+mod korean_str;
+#[cfg(feature = "fast-lookup")]
+mod lookup;
+#[cfg(feature = "alloc")]
+mod ngram;
+#[cfg(feature = "rayon")]
+mod par;
+#[cfg(test)]
+mod proptests;
+mod rhyme;
+mod scan;
+#[cfg(feature = "std")]
+mod stats;
+#[cfg(feature = "alloc")]
+mod text;
+#[cfg(feature = "alloc")]
+pub mod transform;
+mod typing;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+mod write;
+
+#[cfg(feature = "alloc")]
+pub use builder::HangulBuilder;
+#[cfg(feature = "alloc")]
+pub use bulk::{compose_from_indices, decompose_to_indices, NON_KOREAN};
+pub use char_ext::CharExt;
+pub use collation::{korean_cmp, KoreanOrd};
+#[cfg(feature = "alloc")]
+pub use collation::{korean_collation_key, korean_sort_key};
+pub use conjugation::contract_vowels;
+#[cfg(feature = "euc-kr")]
+pub use euckr::is_ksx1001_syllable;
+#[cfg(all(feature = "alloc", feature = "euc-kr"))]
+pub use euckr::{decode_euckr, encode_euckr};
+#[cfg(feature = "alloc")]
+pub use fold::{fold_korean, korean_soundex};
+pub use fold::{fold_syllable, FoldOptions};
+pub use forms::{analyze_forms, FormCount, FormsReport, HangulForm};
+#[cfg(feature = "hanja")]
+pub use hanja::BasicHanjaReadings;
+#[cfg(feature = "alloc")]
+pub use hanja::{hanja_to_hangul, HanjaReadings, ReadingPolicy};
+#[cfg(feature = "alloc")]
+pub use jamo::{compat_to_conjoining, conjoining_to_compat};
+pub use jamo::{
+    compat_to_conjoining_choseong, compat_to_conjoining_jongseong, compat_to_conjoining_jungseong,
+    conjoining_char_to_compat, ConversionFallback, Halfwidth, Jamo, JamoForm, JamoKind,
+    JamoPosition,
+};
+#[cfg(feature = "alloc")]
+pub use johab::{decode_johab, encode_johab};
+pub use korean_str::KoreanStr;
+#[cfg(feature = "alloc")]
+pub use korean_str::KoreanString;
+#[cfg(feature = "std")]
+pub use ngram::syllable_ngram_counts;
+#[cfg(feature = "alloc")]
+pub use ngram::{jamo_ngrams, syllable_ngrams};
+#[cfg(feature = "rayon")]
+pub use par::par_decompose;
+#[cfg(all(feature = "rayon", feature = "alloc"))]
+pub use par::{par_decompose_syllables, par_map_syllables};
+pub use rhyme::{
+    count_alliterating_pairs, find_alliterating_syllables, find_rhyming_syllables,
+    syllables_alliterate, syllables_rhyme, syllables_rhyme_vowel_only,
+};
+pub use scan::{
+    count_korean_syllables, decompose_spans, decompose_with_offsets, find_korean_syllable,
+    get_syllable_slice, is_any_korean, syllable_slice,
+};
+#[cfg(feature = "std")]
+pub use stats::{jamo_frequencies, JamoStats};
+#[cfg(feature = "alloc")]
+pub use text::{
+    compose_orphans, compose_syllables, cuteify, decompose_syllables, display_width,
+    display_width_with, find_orphan_jamo, flip_chojongseong_horizontally, is_jamo_palindrome,
+    is_syllable_palindrome, levenshtein_jamo, levenshtein_syllable, map_syllable_pairs,
+    map_syllables, modern_equivalent, modernize_text, normalize_halfwidth, parse_korean_string,
+    pullup_choseong, pullup_choseong_with, pushdown_jongseong, pushdown_jongseong_with,
+    recompose_nfd, reverse_jamo, reverse_syllables, reverse_syllables_in_place, segment,
+    set_jongseong, strip_jongseong, truncate_to_width, validate_korean_str, CuteStyle,
+    ModernizePolicy, PullupOptions, PushdownOptions, SyllableOrChar,
+};
+pub use typing::keystroke_count;
+pub use write::{write_composed, write_decomposed};
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec, vec::Vec};
+use core::{
     convert::TryFrom,
-    error::Error as StdError,
     fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
 };
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+#[cfg(feature = "std")]
+use std::error::Error as StdError;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+// implements `FromStr` via `parse_jamo()` for each of the jamo types; those with the `conjoining`
+// marker accept their conjoining form as well.
+macro_rules! impl_from_str {
+    ($type:ident, $names:ident, $offset:literal) => {
+        impl FromStr for $type {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                parse_jamo(
+                    s,
+                    &$names,
+                    |index| Self::try_from(index as u8 + $offset).unwrap(),
+                    Self::try_from,
+                )
+            }
+        }
+    };
+    ($type:ident, $names:ident, $offset:literal, conjoining) => {
+        impl FromStr for $type {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                parse_jamo(
+                    s,
+                    &$names,
+                    |index| Self::try_from(index as u8 + $offset).unwrap(),
+                    |character| {
+                        Self::try_from(character)
+                            .or_else(|error| Self::from_conjoining(character).map_err(|_| error))
+                    },
+                )
+            }
+        }
+    };
+}
 
 /// Groups all the consonants applicable to the 'initial consonant' (초성, Choseong) position of a
 /// Korean syllable.
 ///
 /// These consonants do reside by themselves as an individual Unicode characters, but not in this
 /// particular order; for that, see [`Jaeum`].
-#[derive(Clone, Copy, Debug, Eq, IntoPrimitive, Ord, PartialEq, PartialOrd, TryFromPrimitive)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(
+    Clone, Copy, Debug, Eq, Hash, IntoPrimitive, Ord, PartialEq, PartialOrd, TryFromPrimitive,
+)]
 #[repr(u8)]
 pub enum Choseong {
     /// Represents 'ㄱ'.
@@ -61,77 +247,613 @@ pub enum Choseong {
 }
 impl From<Choseong> for char {
     fn from(choseong: Choseong) -> Self {
-        Jaeum::from(choseong).into()
+        choseong.to_char()
     }
 }
+// indexed by `Choseong as usize`.
+const CHOSEONG_NAMES: [&str; 19] = [
+    "Kiyeok",
+    "SsangKiyeok",
+    "Nieun",
+    "Tikeut",
+    "SsangTikeut",
+    "Rieul",
+    "Mieum",
+    "Pieup",
+    "SsangPieup",
+    "Sios",
+    "SsangSios",
+    "Ieung",
+    "Cieuc",
+    "SsangCieuc",
+    "Chieuch",
+    "Khieukh",
+    "Thieuth",
+    "Phieuph",
+    "Hieuh",
+];
+impl_from_str!(Choseong, CHOSEONG_NAMES, 0, conjoining);
 impl TryFrom<char> for Choseong {
     type Error = Error;
 
     fn try_from(character: char) -> Result<Self, Self::Error> {
+        #[cfg(feature = "fast-lookup")]
+        if let Some(choseong) = lookup::choseong(character) {
+            return Ok(choseong);
+        }
+
         Self::try_from(Jaeum::try_from(character)?)
     }
 }
+// indexed by `Jaeum as usize`.
+const JAEUM_TO_CHOSEONG: [Option<Choseong>; 30] = [
+    Some(Choseong::Kiyeok),
+    Some(Choseong::SsangKiyeok),
+    None,
+    Some(Choseong::Nieun),
+    None,
+    None,
+    Some(Choseong::Tikeut),
+    Some(Choseong::SsangTikeut),
+    Some(Choseong::Rieul),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(Choseong::Mieum),
+    Some(Choseong::Pieup),
+    Some(Choseong::SsangPieup),
+    None,
+    Some(Choseong::Sios),
+    Some(Choseong::SsangSios),
+    Some(Choseong::Ieung),
+    Some(Choseong::Cieuc),
+    Some(Choseong::SsangCieuc),
+    Some(Choseong::Chieuch),
+    Some(Choseong::Khieukh),
+    Some(Choseong::Thieuth),
+    Some(Choseong::Phieuph),
+    Some(Choseong::Hieuh),
+];
 impl TryFrom<Jaeum> for Choseong {
     type Error = Error;
 
     fn try_from(jaeum: Jaeum) -> Result<Self, Self::Error> {
-        match jaeum {
-            Jaeum::Kiyeok => Ok(Self::Kiyeok),
-            Jaeum::SsangKiyeok => Ok(Self::SsangKiyeok),
-            Jaeum::Nieun => Ok(Self::Nieun),
-            Jaeum::Tikeut => Ok(Self::Tikeut),
-            Jaeum::SsangTikeut => Ok(Self::SsangTikeut),
-            Jaeum::Rieul => Ok(Self::Rieul),
-            Jaeum::Mieum => Ok(Self::Mieum),
-            Jaeum::Pieup => Ok(Self::Pieup),
-            Jaeum::SsangPieup => Ok(Self::SsangPieup),
-            Jaeum::Sios => Ok(Self::Sios),
-            Jaeum::SsangSios => Ok(Self::SsangSios),
-            Jaeum::Ieung => Ok(Self::Ieung),
-            Jaeum::Cieuc => Ok(Self::Cieuc),
-            Jaeum::SsangCieuc => Ok(Self::SsangCieuc),
-            Jaeum::Chieuch => Ok(Self::Chieuch),
-            Jaeum::Khieukh => Ok(Self::Khieukh),
-            Jaeum::Thieuth => Ok(Self::Thieuth),
-            Jaeum::Phieuph => Ok(Self::Phieuph),
-            Jaeum::Hieuh => Ok(Self::Hieuh),
-            anything_else => Err(Error::NotApplicableToChoseong(anything_else)),
+        JAEUM_TO_CHOSEONG[jaeum as usize].ok_or(Error::NotApplicableToChoseong(jaeum))
+    }
+}
+impl Choseong {
+    /// Every modern initial consonant, in Korean dictionary order.
+    ///
+    /// ```
+    /// use unikorn::Choseong;
+    ///
+    /// assert_eq!(Choseong::ALL[0], Choseong::Kiyeok); // ㄱ
+    /// assert_eq!(Choseong::ALL[Choseong::COUNT - 1], Choseong::Hieuh); // ㅎ
+    /// ```
+    pub const ALL: [Self; 19] = [
+        Self::Kiyeok,
+        Self::SsangKiyeok,
+        Self::Nieun,
+        Self::Tikeut,
+        Self::SsangTikeut,
+        Self::Rieul,
+        Self::Mieum,
+        Self::Pieup,
+        Self::SsangPieup,
+        Self::Sios,
+        Self::SsangSios,
+        Self::Ieung,
+        Self::Cieuc,
+        Self::SsangCieuc,
+        Self::Chieuch,
+        Self::Khieukh,
+        Self::Thieuth,
+        Self::Phieuph,
+        Self::Hieuh,
+    ];
+
+    /// The number of the modern initial consonants, i.e. the length of [`Choseong::ALL`].
+    pub const COUNT: usize = 19;
+
+    /// Determines if this initial consonant can be placed in the final consonant (종성,
+    /// [`Jongseong`]) position as well.
+    ///
+    /// ```
+    /// use unikorn::Choseong;
+    ///
+    /// assert!(Choseong::Kiyeok.can_be_jongseong());
+    /// assert!(!Choseong::SsangTikeut.can_be_jongseong()); // ㄸ
+    /// ```
+    pub const fn can_be_jongseong(self) -> bool {
+        JAEUM_TO_JONGSEONG[CHOSEONG_TO_JAEUM[self as usize] as usize].is_some()
+    }
+
+    /// Converts a [`char`] in Hangul Jamo range (U+1100 'ᄀ' -- U+1112 'ᄒ'), i.e. the conjoining
+    /// form, into an initial consonant.
+    ///
+    /// ```
+    /// use unikorn::{Choseong, Error};
+    ///
+    /// assert_eq!(Choseong::from_conjoining('\u{1100}'), Ok(Choseong::Kiyeok));
+    /// assert_eq!(
+    ///     Choseong::from_conjoining('ㄱ'),
+    ///     Err(Error::NonConjoiningJamo('ㄱ'))
+    /// );
+    /// ```
+    pub fn from_conjoining(character: char) -> Result<Self, Error> {
+        if !(0x1100..=0x1112).contains(&(character as u32)) {
+            return Err(Error::NonConjoiningJamo(character));
+        }
+
+        Ok(Self::try_from((character as u32 - 0x1100) as u8).unwrap())
+    }
+
+    /// Converts a 0-based index among the modern initial consonants, i.e. the position in
+    /// [`Choseong::ALL`], into one.
+    ///
+    /// This numbering stays the same regardless of how the variants are represented, and thus is
+    /// the one to rely on when migrating from the other crates, or doing arithmetic on the
+    /// Precomposed Korean [`Syllable`]s.
+    ///
+    /// ```
+    /// use unikorn::{Error, Choseong};
+    ///
+    /// assert_eq!(Choseong::from_modern_index(0), Ok(Choseong::Kiyeok));
+    /// assert_eq!(Choseong::from_modern_index(18), Ok(Choseong::Hieuh));
+    /// assert_eq!(Choseong::from_modern_index(19), Err(Error::NonModernIndex(19)));
+    /// ```
+    pub fn from_modern_index(index: u8) -> Result<Self, Error> {
+        Self::try_from(index).map_err(|_| Error::NonModernIndex(index))
+    }
+
+    /// Checks if this initial consonant is an aspirated consonant (격음, Gyeogeum), i.e. one of
+    /// 'ㅊ', 'ㅋ', 'ㅌ', and 'ㅍ'.
+    ///
+    /// ```
+    /// use unikorn::Choseong;
+    ///
+    /// assert!(Choseong::Khieukh.is_aspirated());
+    /// assert!(!Choseong::Kiyeok.is_aspirated());
+    /// ```
+    pub const fn is_aspirated(self) -> bool {
+        matches!(
+            self,
+            Self::Chieuch | Self::Khieukh | Self::Thieuth | Self::Phieuph
+        )
+    }
+
+    /// Checks if this initial consonant is a tense consonant (경음, Gyeongeum), i.e. one of 'ㄲ',
+    /// 'ㄸ', 'ㅃ', 'ㅆ', and 'ㅉ'.
+    ///
+    /// ```
+    /// use unikorn::Choseong;
+    ///
+    /// assert!(Choseong::SsangKiyeok.is_tense());
+    /// assert!(!Choseong::Kiyeok.is_tense());
+    /// ```
+    pub const fn is_tense(self) -> bool {
+        matches!(
+            self,
+            Self::SsangKiyeok
+                | Self::SsangTikeut
+                | Self::SsangPieup
+                | Self::SsangSios
+                | Self::SsangCieuc
+        )
+    }
+
+    /// Iterates over every modern initial consonant, in Korean dictionary order.
+    ///
+    /// ```
+    /// use unikorn::Choseong;
+    ///
+    /// assert_eq!(Choseong::iter().count(), 19);
+    /// assert_eq!(Choseong::iter().next(), Some(Choseong::Kiyeok));
+    /// ```
+    pub fn iter() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        Self::ALL.into_iter()
+    }
+
+    /// Returns the name of this initial consonant, as accepted by [`FromStr`].
+    ///
+    /// ```
+    /// use unikorn::Choseong;
+    ///
+    /// assert_eq!(Choseong::SsangKiyeok.name(), "SsangKiyeok");
+    /// assert_eq!("SsangKiyeok".parse(), Ok(Choseong::SsangKiyeok));
+    /// ```
+    pub const fn name(self) -> &'static str {
+        CHOSEONG_NAMES[self as usize]
+    }
+
+    /// Converts this initial consonant into its counterpart in Hangul Compatibility Jamo range
+    /// (U+3131 'ㄱ' -- U+314E 'ㅎ'); the same as `char::from`, but usable in a `const` context.
+    ///
+    /// ```
+    /// use unikorn::Choseong;
+    ///
+    /// const SSANG_KIYEOK: char = Choseong::SsangKiyeok.to_char();
+    /// assert_eq!(SSANG_KIYEOK, 'ㄲ');
+    /// ```
+    pub const fn to_char(self) -> char {
+        CHOSEONG_TO_JAEUM[self as usize].to_char()
+    }
+
+    /// Converts this initial consonant into its counterpart in Hangul Jamo range (U+1100 'ᄀ' --
+    /// U+1112 'ᄒ'), i.e. the conjoining form.
+    ///
+    /// ```
+    /// use unikorn::Choseong;
+    ///
+    /// assert_eq!(Choseong::Kiyeok.to_conjoining(), '\u{1100}');
+    /// ```
+    pub const fn to_conjoining(self) -> char {
+        char_from_code_point(0x1100 + self as u32)
+    }
+
+    /// Returns the compatibility consonant (자음, [`Jaeum`]) of this initial consonant, the same as
+    /// [`Jaeum::from`] does, e.g. 'ㄱ' for U+1100 'ᄀ'.
+    ///
+    /// Always returns [`Some`] for now; the [`Option`] is only there for the archaic initial
+    /// consonants to be supported in the future.
+    ///
+    /// ```
+    /// use unikorn::{Choseong, Jaeum};
+    ///
+    /// assert_eq!(Choseong::SsangKiyeok.to_jaeum_lossy(), Some(Jaeum::SsangKiyeok));
+    /// ```
+    pub const fn to_jaeum_lossy(self) -> Option<Jaeum> {
+        Some(CHOSEONG_TO_JAEUM[self as usize])
+    }
+
+    /// Returns the 0-based index of this initial consonant among the modern ones; the reverse of
+    /// [`Choseong::from_modern_index`].
+    ///
+    /// ```
+    /// use unikorn::Choseong;
+    ///
+    /// assert_eq!(Choseong::Kiyeok.to_modern_index(), Some(0));
+    /// assert_eq!(Choseong::Hieuh.to_modern_index(), Some(18));
+    /// ```
+    pub const fn to_modern_index(self) -> Option<u8> {
+        Some(self as u8)
+    }
+
+    /// Returns the plain (평음, Pyeongeum) counterpart of this initial consonant if it is an
+    /// aspirated one (격음, Gyeogeum), e.g. 'ㄷ' for 'ㅌ', or itself otherwise.
+    ///
+    /// ```
+    /// use unikorn::Choseong;
+    ///
+    /// assert_eq!(Choseong::Chieuch.without_aspiration(), Choseong::Cieuc);
+    /// assert_eq!(Choseong::Hieuh.without_aspiration(), Choseong::Hieuh);
+    /// ```
+    pub const fn without_aspiration(self) -> Self {
+        match self {
+            Self::Chieuch => Self::Cieuc,
+            Self::Khieukh => Self::Kiyeok,
+            Self::Thieuth => Self::Tikeut,
+            Self::Phieuph => Self::Pieup,
+            other => other,
+        }
+    }
+
+    /// Returns the plain (평음, Pyeongeum) counterpart of this initial consonant if it is a tense
+    /// one (경음, Gyeongeum), e.g. 'ㄱ' for 'ㄲ', or itself otherwise.
+    ///
+    /// ```
+    /// use unikorn::Choseong;
+    ///
+    /// assert_eq!(Choseong::SsangKiyeok.without_tension(), Choseong::Kiyeok);
+    /// assert_eq!(Choseong::Kiyeok.without_tension(), Choseong::Kiyeok);
+    /// ```
+    pub const fn without_tension(self) -> Self {
+        match self {
+            Self::SsangKiyeok => Self::Kiyeok,
+            Self::SsangTikeut => Self::Tikeut,
+            Self::SsangPieup => Self::Pieup,
+            Self::SsangSios => Self::Sios,
+            Self::SsangCieuc => Self::Cieuc,
+            other => other,
         }
     }
 }
 
+// the cause of a consonant not applicable to a position, naming the only position it can take;
+// every consonant is applicable to at least one of the two.
+#[derive(Debug)]
+struct OnlyUsableAs(&'static str);
+impl Display for OnlyUsableAs {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "it is a valid consonant, but only usable as {}", self.0)
+    }
+}
+#[cfg(feature = "std")]
+impl StdError for OnlyUsableAs {}
+static ONLY_USABLE_AS_CHOSEONG: OnlyUsableAs = OnlyUsableAs("an initial consonant");
+static ONLY_USABLE_AS_JONGSEONG: OnlyUsableAs = OnlyUsableAs("a final consonant");
+
 /// Contains all the possible error conditions that can arise within this crate.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Error {
+    /// Denotes that a [`char`] outside the modern portion of Hangul Jamo range (U+1100 'ᄀ' --
+    /// U+1112 'ᄒ', U+1161 'ᅡ' -- U+1175 'ᅵ', and U+11A8 'ᆨ' -- U+11C2 'ᇂ' respectively) was
+    /// tried converting into a [`Choseong`], [`Jungseong`], or [`Jongseong`] respectively.
+    NonConjoiningJamo(char),
+    /// Denotes that a 2-byte code does not represent a Korean syllable, nor a Hangul Compatibility
+    /// Jamo in EUC-KR.
+    NonEucKr(u16),
     /// Denotes that a [`char`] outside the Hangul Compatibility Jamo range (U+3131 'ㄱ' -- U+3163
     /// 'ㅣ') was tried converting into a [`Jaeum`], [`Moeum`], [`Choseong`], [`Jungseong`], or
     /// [`Jongseong`] respectively.
+    ///
+    /// Also used when a [`char`] in neither Hangul Compatibility Jamo range nor the modern portion
+    /// of Hangul Jamo range was tried converting into a [`Jamo`], or a [`char`] other than a
+    /// Halfwidth Hangul consonant or vowel into a [`Halfwidth`].
     NonJamo(char),
+    /// Denotes that a 2-byte code does not represent a Korean syllable, nor a standalone
+    /// consonant or vowel in Johab (조합형, Johab-hyeong).
+    NonJohab(u16),
     /// Denotes that a [`char`] outside the Precomposed Korean Syllables range (U+AC00 '가' --
     /// U+D7A3 '힣') was tried converting into a [`Syllable`].
-    NonKorean(char),
+    ///
+    /// Holds the byte offset of the [`char`] as well, if it came from a string, e.g. through
+    /// [`parse_korean_string`].
+    NonKorean(char, Option<usize>),
+    /// Denotes that a 3-byte sequence does not represent a single Precomposed Korean [`Syllable`]
+    /// in UTF-8.
+    NonKoreanUtf8([u8; 3]),
+    /// Denotes that an index is beyond the number of the modern consonants or vowels of a given
+    /// position, e.g. 19 for a [`Choseong`]; see [`Choseong::from_modern_index`].
+    NonModernIndex(u8),
+    /// Denotes that a [`u32`] is not a Unicode scalar value, i.e. not a valid [`char`], hence not a
+    /// Precomposed Korean [`Syllable`] either.
+    NonUnicode(u32),
     /// Denotes that a consonant (자음, [`Jaeum`]) cannot be placed in the initial consonant (초성,
     /// [`Choseong`]) position.
+    ///
+    /// Converting a [`char`] into a [`Choseong`] reports this as well, rather than
+    /// [`Error::NonJamo`], if the [`char`] is a valid consonant yet not applicable to the position.
     NotApplicableToChoseong(Jaeum),
     /// Denotes that a consonant (자음, [`Jaeum`]) cannot be placed in the final consonant (종성,
     /// [`Jongseong`]) position.
+    ///
+    /// Converting a [`char`] into a [`Jongseong`] reports this as well, rather than
+    /// [`Error::NonJamo`], if the [`char`] is a valid consonant yet not applicable to the position.
     NotApplicableToJongseong(Jaeum),
+    /// Denotes that a [`char`] other than ASCII, the Precomposed Korean [`Syllable`]s in KS X
+    /// 1001, or Hangul Compatibility Jamo was tried encoding into EUC-KR.
+    NotRepresentableInEucKr(char),
+    /// Denotes that a [`char`] other than ASCII, Precomposed Korean [`Syllable`]s, or Hangul
+    /// Compatibility Jamo was tried encoding into Johab (조합형, Johab-hyeong).
+    NotRepresentableInJohab(char),
+    /// Denotes that a string is neither the name of a consonant or vowel, e.g. "SsangKiyeok", nor
+    /// the consonant or vowel itself, e.g. "ㄲ" or "U+3132".
+    ///
+    /// Holds the closest name, if any, for the typos.
+    UnknownJamoName(Option<&'static str>),
+}
+/// Lets `?` turn an [`Error`] into an [`io::Error`](std::io::Error) of
+/// [`ErrorKind::InvalidData`](std::io::ErrorKind::InvalidData), carrying the same message.
+///
+/// ```
+/// use unikorn::Syllable;
+/// use std::{convert::TryFrom, io};
+///
+/// fn first_syllable(s: &str) -> io::Result<Syllable> {
+///     Ok(Syllable::try_from(s.chars().next().unwrap_or_default())?)
+/// }
+///
+/// assert_eq!(
+///     first_syllable("a").unwrap_err().kind(),
+///     io::ErrorKind::InvalidData
+/// );
+/// ```
+#[cfg(feature = "std")]
+impl From<Error> for std::io::Error {
+    fn from(error: Error) -> Self {
+        Self::new(std::io::ErrorKind::InvalidData, error)
+    }
 }
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
         match self {
+            Self::NonConjoiningJamo(coi) => {
+                write!(f, "'{}' is not a modern conjoining Hangul Jamo", coi)
+            }
+            Self::NonEucKr(coi) => write!(f, "0x{:04X} is not a Korean EUC-KR code", coi),
             Self::NonJamo(coi) => write!(f, "'{}' is not a Hangul Compatibility Jamo", coi),
-            Self::NonKorean(coi) => write!(f, "'{}' is not a Precomposed Korean Sylable", coi),
-            Self::NotApplicableToChoseong(jaeum) => {
-                write!(f, "{:?} cannot be used as an initial consonant", jaeum)
+            Self::NonJohab(coi) => write!(f, "0x{:04X} is not a Korean Johab code", coi),
+            Self::NonKorean(coi, None) => {
+                write!(f, "'{}' is not a Precomposed Korean Sylable", coi)
+            }
+            Self::NonKorean(coi, Some(offset)) => write!(
+                f,
+                "'{}' at byte {} is not a Precomposed Korean Sylable",
+                coi, offset
+            ),
+            Self::NonKoreanUtf8(coi) => write!(
+                f,
+                "{:02X?} is not a Precomposed Korean Sylable in UTF-8",
+                coi
+            ),
+            Self::NonModernIndex(coi) => {
+                write!(f, "{} is not an index of a modern consonant or vowel", coi)
+            }
+            Self::NonUnicode(coi) => write!(f, "0x{:X} is not a Unicode scalar value", coi),
+            Self::NotApplicableToChoseong(jaeum) => write!(
+                f,
+                "'{}' is not valid as an initial consonant ({})",
+                char::from(*jaeum),
+                ONLY_USABLE_AS_JONGSEONG
+            ),
+            Self::NotApplicableToJongseong(jaeum) => write!(
+                f,
+                "'{}' is not valid as a final consonant ({})",
+                char::from(*jaeum),
+                ONLY_USABLE_AS_CHOSEONG
+            ),
+            Self::NotRepresentableInEucKr(coi) => {
+                write!(f, "'{}' cannot be represented in EUC-KR", coi)
+            }
+            Self::NotRepresentableInJohab(coi) => {
+                write!(f, "'{}' cannot be represented in Johab", coi)
+            }
+            Self::UnknownJamoName(None) => write!(f, "unknown name of a consonant or vowel"),
+            Self::UnknownJamoName(Some(suggestion)) => write!(
+                f,
+                "unknown name of a consonant or vowel; did you mean {}?",
+                suggestion
+            ),
+        }
+    }
+}
+/// Reports why a consonant is not applicable to a position as the [`source`](StdError::source) of
+/// [`Error::NotApplicableToChoseong`] and [`Error::NotApplicableToJongseong`], i.e. that it is a
+/// valid consonant, but only usable in the other position.
+///
+/// ```
+/// use unikorn::Choseong;
+/// use std::{convert::TryFrom, error::Error};
+///
+/// let error = Choseong::try_from('ㄳ').unwrap_err();
+/// assert_eq!(
+///     error.source().unwrap().to_string(),
+///     "it is a valid consonant, but only usable as a final consonant"
+/// );
+/// ```
+#[cfg(feature = "std")]
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::NotApplicableToChoseong(_) => Some(&ONLY_USABLE_AS_JONGSEONG),
+            Self::NotApplicableToJongseong(_) => Some(&ONLY_USABLE_AS_CHOSEONG),
+            _ => None,
+        }
+    }
+}
+impl Error {
+    /// Returns whether the [`char`] in question is a modern Korean consonant or vowel after all,
+    /// only of the other kind than was asked for, e.g. a consonant tried converting into a
+    /// [`Jungseong`]; an IME may tell this apart from a [`char`] not Korean at all.
+    ///
+    /// Holds for the [`Error::NonJamo`] of such a [`char`], in either the compatibility or the
+    /// conjoining form, and for the [`Error::NonConjoiningJamo`] of such a conjoining one. Never
+    /// holds for [`Error::NotApplicableToChoseong`] or [`Error::NotApplicableToJongseong`], where
+    /// the consonant is of the right kind yet in the wrong position.
+    ///
+    /// As [`Error::NonConjoiningJamo`] does not tell which position was asked for, a conjoining
+    /// consonant holds there even if it was tried converting into the other consonant position,
+    /// e.g. U+1100 'ᄀ' into a [`Jongseong`].
+    ///
+    /// ```
+    /// use unikorn::{Jaeum, Jongseong, Jungseong};
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(Jungseong::try_from('ㄱ').unwrap_err().is_wrong_category());
+    /// assert!(Jungseong::try_from('\u{1100}').unwrap_err().is_wrong_category());
+    /// assert!(Jaeum::try_from('ㅏ').unwrap_err().is_wrong_category());
+    /// assert!(!Jungseong::try_from('a').unwrap_err().is_wrong_category());
+    /// ```
+    pub fn is_wrong_category(&self) -> bool {
+        let is_modern_jamo = |character| {
+            matches!(
+                character as u32,
+                0x1100..=0x1112 | 0x1161..=0x1175 | 0x11A8..=0x11C2
+            )
+        };
+
+        match *self {
+            Self::NonJamo(character) => {
+                (0x3131..=0x3163).contains(&(character as u32)) || is_modern_jamo(character)
             }
-            Self::NotApplicableToJongseong(jaeum) => {
-                write!(f, "{:?} cannot be used as a final consonant", jaeum)
+            Self::NonConjoiningJamo(character) => is_modern_jamo(character),
+            _ => false,
+        }
+    }
+}
+
+// parses one of:
+// * a variant name, case-insensitively and ignoring any '-', '_', or ' ', e.g. "ssang-kiyeok",
+// * the jamo itself, e.g. "ㄲ", or
+// * the code point of the jamo, e.g. "U+3132".
+fn parse_jamo<T>(
+    s: &str,
+    names: &[&'static str],
+    from_index: impl Fn(usize) -> T,
+    from_char: impl Fn(char) -> Result<T, Error>,
+) -> Result<T, Error> {
+    if let Some(index) = names
+        .iter()
+        .position(|name| normalize_name(name).eq(normalize_name(s)))
+    {
+        return Ok(from_index(index));
+    }
+
+    if let Some(hex) = s.strip_prefix("U+").or_else(|| s.strip_prefix("u+")) {
+        if !hex.is_empty() && hex.chars().all(|digit| digit.is_ascii_hexdigit()) {
+            if let Some(character) = u32::from_str_radix(hex, 16).ok().and_then(char::from_u32) {
+                return from_char(character);
             }
         }
     }
+
+    let mut characters = s.chars();
+    match (characters.next(), characters.next()) {
+        (Some(character), None) if !character.is_ascii() => from_char(character),
+        _ => Err(Error::UnknownJamoName(closest_name(s, names))),
+    }
+}
+
+fn normalize_name(name: &str) -> impl Iterator<Item = char> + '_ {
+    name.chars()
+        .filter(|character| !matches!(character, '-' | '_' | ' '))
+        .map(|character| character.to_ascii_lowercase())
+}
+
+// finds the name within a few typos of the given one, for "did you mean ...?" suggestions.
+#[cfg(feature = "alloc")]
+fn closest_name(s: &str, names: &[&'static str]) -> Option<&'static str> {
+    let normalized = normalize_name(s).collect::<Vec<_>>();
+    names
+        .iter()
+        .map(|&name| {
+            let candidate = normalize_name(name).collect::<Vec<_>>();
+            (name, edit_distance(&normalized, &candidate))
+        })
+        // don't let a single typo turn into a suggestion for the one-letter vowel names.
+        .filter(|&(name, distance)| distance <= 2 && distance * 2 < name.len())
+        .min_by_key(|&(_, distance)| distance)
+        .map(|(name, _)| name)
+}
+
+// computing the distances takes allocations, so there are no suggestions without `alloc`.
+#[cfg(not(feature = "alloc"))]
+fn closest_name(_: &str, _: &[&'static str]) -> Option<&'static str> {
+    None
+}
+
+// Levenshtein distance.
+#[cfg(feature = "alloc")]
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, a_char) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + if a_char == b_char { 0 } else { 1 };
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        core::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
 }
-impl StdError for Error {}
 
 /// Groups all the Korean consonants (자음, Jaeum).
 ///
@@ -176,7 +898,9 @@ impl StdError for Error {}
 /// );
 /// assert_eq!(Jongseong::try_from(jaeum), Ok(Jongseong::NieunCieuc));
 /// ```
-#[derive(Clone, Copy, Debug, Eq, IntoPrimitive, Ord, PartialEq, PartialOrd, TryFromPrimitive)]
+#[derive(
+    Clone, Copy, Debug, Eq, Hash, IntoPrimitive, Ord, PartialEq, PartialOrd, TryFromPrimitive,
+)]
 #[repr(u8)]
 pub enum Jaeum {
     /// Represents 'ㄱ'.
@@ -240,69 +964,107 @@ pub enum Jaeum {
     /// Represents 'ㅎ'.
     Hieuh,
 }
+// indexed by `Choseong as usize`.
+const CHOSEONG_TO_JAEUM: [Jaeum; 19] = [
+    Jaeum::Kiyeok,
+    Jaeum::SsangKiyeok,
+    Jaeum::Nieun,
+    Jaeum::Tikeut,
+    Jaeum::SsangTikeut,
+    Jaeum::Rieul,
+    Jaeum::Mieum,
+    Jaeum::Pieup,
+    Jaeum::SsangPieup,
+    Jaeum::Sios,
+    Jaeum::SsangSios,
+    Jaeum::Ieung,
+    Jaeum::Cieuc,
+    Jaeum::SsangCieuc,
+    Jaeum::Chieuch,
+    Jaeum::Khieukh,
+    Jaeum::Thieuth,
+    Jaeum::Phieuph,
+    Jaeum::Hieuh,
+];
 impl From<Choseong> for Jaeum {
     fn from(choseong: Choseong) -> Self {
-        match choseong {
-            Choseong::Kiyeok => Self::Kiyeok,
-            Choseong::SsangKiyeok => Self::SsangKiyeok,
-            Choseong::Nieun => Self::Nieun,
-            Choseong::Tikeut => Self::Tikeut,
-            Choseong::SsangTikeut => Self::SsangTikeut,
-            Choseong::Rieul => Self::Rieul,
-            Choseong::Mieum => Self::Mieum,
-            Choseong::Pieup => Self::Pieup,
-            Choseong::SsangPieup => Self::SsangPieup,
-            Choseong::Sios => Self::Sios,
-            Choseong::SsangSios => Self::SsangSios,
-            Choseong::Ieung => Self::Ieung,
-            Choseong::Cieuc => Self::Cieuc,
-            Choseong::SsangCieuc => Self::SsangCieuc,
-            Choseong::Chieuch => Self::Chieuch,
-            Choseong::Khieukh => Self::Khieukh,
-            Choseong::Thieuth => Self::Thieuth,
-            Choseong::Phieuph => Self::Phieuph,
-            Choseong::Hieuh => Self::Hieuh,
-        }
+        CHOSEONG_TO_JAEUM[choseong as usize]
     }
 }
 impl From<Jaeum> for char {
     fn from(jaeum: Jaeum) -> Self {
-        Self::from_u32(0x3131 + jaeum as u32).unwrap()
+        jaeum.to_char()
     }
 }
+// indexed by `Jongseong as usize - 1`, as `Jongseong` starts from 1.
+const JONGSEONG_TO_JAEUM: [Jaeum; 27] = [
+    Jaeum::Kiyeok,
+    Jaeum::SsangKiyeok,
+    Jaeum::KiyeokSios,
+    Jaeum::Nieun,
+    Jaeum::NieunCieuc,
+    Jaeum::NieunHieuh,
+    Jaeum::Tikeut,
+    Jaeum::Rieul,
+    Jaeum::RieulKiyeok,
+    Jaeum::RieulMieum,
+    Jaeum::RieulPieup,
+    Jaeum::RieulSios,
+    Jaeum::RieulThieuth,
+    Jaeum::RieulPhieuph,
+    Jaeum::RieulHieuh,
+    Jaeum::Mieum,
+    Jaeum::Pieup,
+    Jaeum::PieupSios,
+    Jaeum::Sios,
+    Jaeum::SsangSios,
+    Jaeum::Ieung,
+    Jaeum::Cieuc,
+    Jaeum::Chieuch,
+    Jaeum::Khieukh,
+    Jaeum::Thieuth,
+    Jaeum::Phieuph,
+    Jaeum::Hieuh,
+];
 impl From<Jongseong> for Jaeum {
     fn from(jongseong: Jongseong) -> Self {
-        match jongseong {
-            Jongseong::Kiyeok => Self::Kiyeok,
-            Jongseong::SsangKiyeok => Self::SsangKiyeok,
-            Jongseong::KiyeokSios => Self::KiyeokSios,
-            Jongseong::Nieun => Self::Nieun,
-            Jongseong::NieunCieuc => Self::NieunCieuc,
-            Jongseong::NieunHieuh => Self::NieunHieuh,
-            Jongseong::Tikeut => Self::Tikeut,
-            Jongseong::Rieul => Self::Rieul,
-            Jongseong::RieulKiyeok => Self::RieulKiyeok,
-            Jongseong::RieulMieum => Self::RieulMieum,
-            Jongseong::RieulPieup => Self::RieulPieup,
-            Jongseong::RieulSios => Self::RieulSios,
-            Jongseong::RieulThieuth => Self::RieulThieuth,
-            Jongseong::RieulPhieuph => Self::RieulPhieuph,
-            Jongseong::RieulHieuh => Self::RieulHieuh,
-            Jongseong::Mieum => Self::Mieum,
-            Jongseong::Pieup => Self::Pieup,
-            Jongseong::PieupSios => Self::PieupSios,
-            Jongseong::Sios => Self::Sios,
-            Jongseong::SsangSios => Self::SsangSios,
-            Jongseong::Ieung => Self::Ieung,
-            Jongseong::Cieuc => Self::Cieuc,
-            Jongseong::Chieuch => Self::Chieuch,
-            Jongseong::Khieukh => Self::Khieukh,
-            Jongseong::Thieuth => Self::Thieuth,
-            Jongseong::Phieuph => Self::Phieuph,
-            Jongseong::Hieuh => Self::Hieuh,
-        }
+        JONGSEONG_TO_JAEUM[jongseong as usize - 1]
     }
 }
+// indexed by `Jaeum as usize`.
+const JAEUM_NAMES: [&str; 30] = [
+    "Kiyeok",
+    "SsangKiyeok",
+    "KiyeokSios",
+    "Nieun",
+    "NieunCieuc",
+    "NieunHieuh",
+    "Tikeut",
+    "SsangTikeut",
+    "Rieul",
+    "RieulKiyeok",
+    "RieulMieum",
+    "RieulPieup",
+    "RieulSios",
+    "RieulThieuth",
+    "RieulPhieuph",
+    "RieulHieuh",
+    "Mieum",
+    "Pieup",
+    "SsangPieup",
+    "PieupSios",
+    "Sios",
+    "SsangSios",
+    "Ieung",
+    "Cieuc",
+    "SsangCieuc",
+    "Chieuch",
+    "Khieukh",
+    "Thieuth",
+    "Phieuph",
+    "Hieuh",
+];
+impl_from_str!(Jaeum, JAEUM_NAMES, 0);
 impl TryFrom<char> for Jaeum {
     type Error = Error;
 
@@ -314,13 +1076,247 @@ impl TryFrom<char> for Jaeum {
         Ok(Self::try_from((character as u32 - 0x3131) as u8).unwrap())
     }
 }
+// indexed by `Jaeum as usize`.
+const JAEUM_TO_DUBEOLSIK: [Option<char>; 30] = [
+    Some('r'),
+    Some('R'),
+    None,
+    Some('s'),
+    None,
+    None,
+    Some('e'),
+    Some('E'),
+    Some('f'),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some('a'),
+    Some('q'),
+    Some('Q'),
+    None,
+    Some('t'),
+    Some('T'),
+    Some('d'),
+    Some('w'),
+    Some('W'),
+    Some('c'),
+    Some('z'),
+    Some('x'),
+    Some('v'),
+    Some('g'),
+];
+impl Jaeum {
+    /// Every modern consonant, in the order of Hangul Compatibility Jamo range; see
+    /// [`Jungseong::ALL`] (a.k.a. `Moeum::ALL`) for the vowels, and [`Jaeum::KEYBOARD_ORDER`] for
+    /// the keyboard order.
+    ///
+    /// ```
+    /// use unikorn::{Jaeum, Moeum};
+    ///
+    /// assert_eq!(Jaeum::ALL[0], Jaeum::Kiyeok); // ㄱ
+    /// assert_eq!(Jaeum::ALL[Jaeum::COUNT - 1], Jaeum::Hieuh); // ㅎ
+    /// assert_eq!(Moeum::ALL.len(), 21);
+    /// ```
+    pub const ALL: [Self; 30] = [
+        Self::Kiyeok,
+        Self::SsangKiyeok,
+        Self::KiyeokSios,
+        Self::Nieun,
+        Self::NieunCieuc,
+        Self::NieunHieuh,
+        Self::Tikeut,
+        Self::SsangTikeut,
+        Self::Rieul,
+        Self::RieulKiyeok,
+        Self::RieulMieum,
+        Self::RieulPieup,
+        Self::RieulSios,
+        Self::RieulThieuth,
+        Self::RieulPhieuph,
+        Self::RieulHieuh,
+        Self::Mieum,
+        Self::Pieup,
+        Self::SsangPieup,
+        Self::PieupSios,
+        Self::Sios,
+        Self::SsangSios,
+        Self::Ieung,
+        Self::Cieuc,
+        Self::SsangCieuc,
+        Self::Chieuch,
+        Self::Khieukh,
+        Self::Thieuth,
+        Self::Phieuph,
+        Self::Hieuh,
+    ];
+
+    /// The number of the modern consonants, i.e. the length of [`Jaeum::ALL`].
+    pub const COUNT: usize = 30;
+
+    /// Every modern consonant as a slice, in the dictionary order of [`Jaeum::ALL`], e.g. for
+    /// rendering a jamo palette; see [`Jungseong::MODERN`] (a.k.a. `Moeum::MODERN`) for the vowels.
+    ///
+    /// ```
+    /// use unikorn::{Jaeum, Moeum};
+    ///
+    /// assert_eq!(Jaeum::MODERN.len(), 30);
+    /// assert_eq!(Jaeum::MODERN[0], Jaeum::Kiyeok); // ㄱ
+    /// assert_eq!(Moeum::MODERN.len(), 21);
+    /// ```
+    pub const MODERN: &'static [Self] = &Self::ALL;
+
+    /// Every modern consonant, in the order of the standard 2-set (두벌식, Dubeolsik) keyboard,
+    /// i.e. as laid out on a keyboard-like palette: the keys row by row from the left, the ones
+    /// taking Shift after the rest, and then the clusters not on the keyboard, in the order of
+    /// [`Jaeum::ALL`]; see [`Jungseong::KEYBOARD_ORDER`] for the vowels.
+    ///
+    /// ```
+    /// use unikorn::Jaeum;
+    ///
+    /// assert_eq!(Jaeum::KEYBOARD_ORDER[0], Jaeum::Pieup); // q
+    /// assert_eq!(Jaeum::KEYBOARD_ORDER[3], Jaeum::Kiyeok); // r
+    /// ```
+    pub const KEYBOARD_ORDER: [Self; 30] = [
+        Self::Pieup,
+        Self::Cieuc,
+        Self::Tikeut,
+        Self::Kiyeok,
+        Self::Sios,
+        Self::Mieum,
+        Self::Nieun,
+        Self::Ieung,
+        Self::Rieul,
+        Self::Hieuh,
+        Self::Khieukh,
+        Self::Thieuth,
+        Self::Chieuch,
+        Self::Phieuph,
+        Self::SsangPieup,
+        Self::SsangCieuc,
+        Self::SsangTikeut,
+        Self::SsangKiyeok,
+        Self::SsangSios,
+        Self::KiyeokSios,
+        Self::NieunCieuc,
+        Self::NieunHieuh,
+        Self::RieulKiyeok,
+        Self::RieulMieum,
+        Self::RieulPieup,
+        Self::RieulSios,
+        Self::RieulThieuth,
+        Self::RieulPhieuph,
+        Self::RieulHieuh,
+        Self::PieupSios,
+    ];
+
+    /// Determines if this consonant can be placed in the initial consonant (초성, [`Choseong`])
+    /// position.
+    ///
+    /// ```
+    /// use unikorn::Jaeum;
+    ///
+    /// assert!(Jaeum::SsangTikeut.can_be_choseong()); // ㄸ
+    /// assert!(!Jaeum::RieulKiyeok.can_be_choseong()); // ㄺ
+    /// ```
+    pub fn can_be_choseong(self) -> bool {
+        self.to_choseong().is_some()
+    }
+
+    /// Determines if this consonant can be placed in the final consonant (종성, [`Jongseong`])
+    /// position.
+    ///
+    /// ```
+    /// use unikorn::Jaeum;
+    ///
+    /// assert!(!Jaeum::SsangTikeut.can_be_jongseong()); // ㄸ
+    /// assert!(Jaeum::RieulKiyeok.can_be_jongseong()); // ㄺ
+    /// ```
+    pub fn can_be_jongseong(self) -> bool {
+        self.to_jongseong().is_some()
+    }
+
+    /// Returns the key typing this consonant on the standard 2-set (두벌식, Dubeolsik) keyboard laid
+    /// over QWERTY, in uppercase if it takes Shift, or [`None`] if it takes more than one key,
+    /// e.g. 'ㄳ'.
+    ///
+    /// ```
+    /// use unikorn::Jaeum;
+    ///
+    /// assert_eq!(Jaeum::Kiyeok.dubeolsik_key(), Some('r'));
+    /// assert_eq!(Jaeum::SsangKiyeok.dubeolsik_key(), Some('R')); // ㄲ
+    /// assert_eq!(Jaeum::KiyeokSios.dubeolsik_key(), None); // ㄳ
+    /// ```
+    pub const fn dubeolsik_key(self) -> Option<char> {
+        JAEUM_TO_DUBEOLSIK[self as usize]
+    }
+
+    /// Iterates over every modern consonant, in the order of Hangul Compatibility Jamo range.
+    ///
+    /// ```
+    /// use unikorn::Jaeum;
+    ///
+    /// assert_eq!(Jaeum::iter().count(), 30);
+    /// assert_eq!(Jaeum::iter().next(), Some(Jaeum::Kiyeok));
+    /// ```
+    pub fn iter() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        Self::ALL.into_iter()
+    }
+
+    /// Returns the name of this consonant, as accepted by [`FromStr`].
+    ///
+    /// ```
+    /// use unikorn::Jaeum;
+    ///
+    /// assert_eq!(Jaeum::KiyeokSios.name(), "KiyeokSios");
+    /// assert_eq!("KiyeokSios".parse(), Ok(Jaeum::KiyeokSios));
+    /// ```
+    pub const fn name(self) -> &'static str {
+        JAEUM_NAMES[self as usize]
+    }
+
+    /// Converts this consonant into a [`char`] in Hangul Compatibility Jamo range (U+3131 'ㄱ' --
+    /// U+314E 'ㅎ'); the same as `char::from`, but usable in a `const` context.
+    ///
+    /// ```
+    /// use unikorn::Jaeum;
+    ///
+    /// const KIYEOK_SIOS: char = Jaeum::KiyeokSios.to_char();
+    /// assert_eq!(KIYEOK_SIOS, 'ㄳ');
+    /// ```
+    pub const fn to_char(self) -> char {
+        char_from_code_point(0x3131 + self as u32)
+    }
+
+    /// Converts this consonant into a [`Choseong`], or [`None`] if it cannot be placed in the
+    /// initial consonant position.
+    ///
+    /// This is a shorthand for [`Choseong::try_from`] discarding the error.
+    pub fn to_choseong(self) -> Option<Choseong> {
+        Choseong::try_from(self).ok()
+    }
+
+    /// Converts this consonant into a [`Jongseong`], or [`None`] if it cannot be placed in the
+    /// final consonant position.
+    ///
+    /// This is a shorthand for [`Jongseong::try_from`] discarding the error.
+    pub fn to_jongseong(self) -> Option<Jongseong> {
+        Jongseong::try_from(self).ok()
+    }
+}
 
 /// Groups all the consonants (including clustered consonants) applicable to the 'final consonant'
 /// (종성, Jongseong) position of a Korean syllable.
 ///
 /// These consonants do reside by themselves as an individual Unicode characters, but not in this
 /// particular order; for that, see [`Jaeum`].
-#[derive(Clone, Copy, Debug, Eq, IntoPrimitive, Ord, PartialEq, PartialOrd, TryFromPrimitive)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(
+    Clone, Copy, Debug, Eq, Hash, IntoPrimitive, Ord, PartialEq, PartialOrd, TryFromPrimitive,
+)]
 #[repr(u8)]
 pub enum Jongseong {
     /// Represents 'ㄱ'.
@@ -380,56 +1376,483 @@ pub enum Jongseong {
 }
 impl From<Jongseong> for char {
     fn from(jongseong: Jongseong) -> Self {
-        Jaeum::from(jongseong).into()
+        jongseong.to_char()
     }
 }
+// indexed by `Jongseong as usize - 1`, as `Jongseong` starts from 1.
+const JONGSEONG_NAMES: [&str; 27] = [
+    "Kiyeok",
+    "SsangKiyeok",
+    "KiyeokSios",
+    "Nieun",
+    "NieunCieuc",
+    "NieunHieuh",
+    "Tikeut",
+    "Rieul",
+    "RieulKiyeok",
+    "RieulMieum",
+    "RieulPieup",
+    "RieulSios",
+    "RieulThieuth",
+    "RieulPhieuph",
+    "RieulHieuh",
+    "Mieum",
+    "Pieup",
+    "PieupSios",
+    "Sios",
+    "SsangSios",
+    "Ieung",
+    "Cieuc",
+    "Chieuch",
+    "Khieukh",
+    "Thieuth",
+    "Phieuph",
+    "Hieuh",
+];
+impl_from_str!(Jongseong, JONGSEONG_NAMES, 1, conjoining);
 impl TryFrom<char> for Jongseong {
     type Error = Error;
 
     fn try_from(character: char) -> Result<Self, Self::Error> {
+        #[cfg(feature = "fast-lookup")]
+        if let Some(jongseong) = lookup::jongseong(character) {
+            return Ok(jongseong);
+        }
+
         Self::try_from(Jaeum::try_from(character)?)
     }
 }
+// indexed by `Jaeum as usize`.
+const JAEUM_TO_JONGSEONG: [Option<Jongseong>; 30] = [
+    Some(Jongseong::Kiyeok),
+    Some(Jongseong::SsangKiyeok),
+    Some(Jongseong::KiyeokSios),
+    Some(Jongseong::Nieun),
+    Some(Jongseong::NieunCieuc),
+    Some(Jongseong::NieunHieuh),
+    Some(Jongseong::Tikeut),
+    None,
+    Some(Jongseong::Rieul),
+    Some(Jongseong::RieulKiyeok),
+    Some(Jongseong::RieulMieum),
+    Some(Jongseong::RieulPieup),
+    Some(Jongseong::RieulSios),
+    Some(Jongseong::RieulThieuth),
+    Some(Jongseong::RieulPhieuph),
+    Some(Jongseong::RieulHieuh),
+    Some(Jongseong::Mieum),
+    Some(Jongseong::Pieup),
+    None,
+    Some(Jongseong::PieupSios),
+    Some(Jongseong::Sios),
+    Some(Jongseong::SsangSios),
+    Some(Jongseong::Ieung),
+    Some(Jongseong::Cieuc),
+    None,
+    Some(Jongseong::Chieuch),
+    Some(Jongseong::Khieukh),
+    Some(Jongseong::Thieuth),
+    Some(Jongseong::Phieuph),
+    Some(Jongseong::Hieuh),
+];
 impl TryFrom<Jaeum> for Jongseong {
     type Error = Error;
 
     fn try_from(jaeum: Jaeum) -> Result<Self, Self::Error> {
-        match jaeum {
-            Jaeum::Kiyeok => Ok(Self::Kiyeok),
-            Jaeum::SsangKiyeok => Ok(Self::SsangKiyeok),
-            Jaeum::KiyeokSios => Ok(Self::KiyeokSios),
-            Jaeum::Nieun => Ok(Self::Nieun),
-            Jaeum::NieunCieuc => Ok(Self::NieunCieuc),
-            Jaeum::NieunHieuh => Ok(Self::NieunHieuh),
-            Jaeum::Tikeut => Ok(Self::Tikeut),
-            Jaeum::Rieul => Ok(Self::Rieul),
-            Jaeum::RieulKiyeok => Ok(Self::RieulKiyeok),
-            Jaeum::RieulMieum => Ok(Self::RieulMieum),
-            Jaeum::RieulPieup => Ok(Self::RieulPieup),
-            Jaeum::RieulSios => Ok(Self::RieulSios),
-            Jaeum::RieulThieuth => Ok(Self::RieulThieuth),
-            Jaeum::RieulPhieuph => Ok(Self::RieulPhieuph),
-            Jaeum::RieulHieuh => Ok(Self::RieulHieuh),
-            Jaeum::Mieum => Ok(Self::Mieum),
-            Jaeum::Pieup => Ok(Self::Pieup),
-            Jaeum::PieupSios => Ok(Self::PieupSios),
-            Jaeum::Sios => Ok(Self::Sios),
-            Jaeum::SsangSios => Ok(Self::SsangSios),
-            Jaeum::Ieung => Ok(Self::Ieung),
-            Jaeum::Cieuc => Ok(Self::Cieuc),
-            Jaeum::Chieuch => Ok(Self::Chieuch),
-            Jaeum::Khieukh => Ok(Self::Khieukh),
-            Jaeum::Thieuth => Ok(Self::Thieuth),
-            Jaeum::Phieuph => Ok(Self::Phieuph),
-            Jaeum::Hieuh => Ok(Self::Hieuh),
-            anything_else => Err(Error::NotApplicableToJongseong(anything_else)),
+        JAEUM_TO_JONGSEONG[jaeum as usize].ok_or(Error::NotApplicableToJongseong(jaeum))
+    }
+}
+impl Jongseong {
+    /// Every modern final consonant, in Korean dictionary order.
+    ///
+    /// ```
+    /// use unikorn::Jongseong;
+    ///
+    /// assert_eq!(Jongseong::ALL[0], Jongseong::Kiyeok); // ㄱ
+    /// assert_eq!(Jongseong::ALL[Jongseong::COUNT - 1], Jongseong::Hieuh); // ㅎ
+    /// ```
+    pub const ALL: [Self; 27] = [
+        Self::Kiyeok,
+        Self::SsangKiyeok,
+        Self::KiyeokSios,
+        Self::Nieun,
+        Self::NieunCieuc,
+        Self::NieunHieuh,
+        Self::Tikeut,
+        Self::Rieul,
+        Self::RieulKiyeok,
+        Self::RieulMieum,
+        Self::RieulPieup,
+        Self::RieulSios,
+        Self::RieulThieuth,
+        Self::RieulPhieuph,
+        Self::RieulHieuh,
+        Self::Mieum,
+        Self::Pieup,
+        Self::PieupSios,
+        Self::Sios,
+        Self::SsangSios,
+        Self::Ieung,
+        Self::Cieuc,
+        Self::Chieuch,
+        Self::Khieukh,
+        Self::Thieuth,
+        Self::Phieuph,
+        Self::Hieuh,
+    ];
+
+    /// The number of the modern final consonants, i.e. the length of [`Jongseong::ALL`].
+    pub const COUNT: usize = 27;
+
+    /// Determines if this final consonant can be placed in the initial consonant (초성,
+    /// [`Choseong`]) position as well.
+    ///
+    /// ```
+    /// use unikorn::Jongseong;
+    ///
+    /// assert!(Jongseong::Kiyeok.can_be_choseong());
+    /// assert!(!Jongseong::KiyeokSios.can_be_choseong()); // ㄳ
+    /// ```
+    pub const fn can_be_choseong(self) -> bool {
+        JAEUM_TO_CHOSEONG[JONGSEONG_TO_JAEUM[self as usize - 1] as usize].is_some()
+    }
+
+    /// Splits this final consonant into its two components, if it is a clustered one (겹받침,
+    /// Gyeopbatchim), or returns [`None`] if it is a simple one.
+    ///
+    /// The components follow the pronunciation, e.g. 'ㄳ' is split into 'ㄱ' and 'ㅅ', while a
+    /// tense consonant such as 'ㄲ' is a simple one and thus not split at all.
+    ///
+    /// ```
+    /// use unikorn::Jongseong;
+    ///
+    /// assert_eq!(
+    ///     Jongseong::KiyeokSios.components(),
+    ///     Some((Jongseong::Kiyeok, Jongseong::Sios))
+    /// );
+    /// assert_eq!(Jongseong::SsangKiyeok.components(), None);
+    /// ```
+    pub const fn components(self) -> Option<(Jongseong, Jongseong)> {
+        match self {
+            Self::KiyeokSios => Some((Self::Kiyeok, Self::Sios)),
+            Self::NieunCieuc => Some((Self::Nieun, Self::Cieuc)),
+            Self::NieunHieuh => Some((Self::Nieun, Self::Hieuh)),
+            Self::RieulKiyeok => Some((Self::Rieul, Self::Kiyeok)),
+            Self::RieulMieum => Some((Self::Rieul, Self::Mieum)),
+            Self::RieulPieup => Some((Self::Rieul, Self::Pieup)),
+            Self::RieulSios => Some((Self::Rieul, Self::Sios)),
+            Self::RieulThieuth => Some((Self::Rieul, Self::Thieuth)),
+            Self::RieulPhieuph => Some((Self::Rieul, Self::Phieuph)),
+            Self::RieulHieuh => Some((Self::Rieul, Self::Hieuh)),
+            Self::PieupSios => Some((Self::Pieup, Self::Sios)),
+            _ => None,
+        }
+    }
+
+    /// Converts a [`char`] in Hangul Jamo range (U+11A8 'ᆨ' -- U+11C2 'ᇂ'), i.e. the conjoining
+    /// form, into a final consonant.
+    ///
+    /// ```
+    /// use unikorn::{Error, Jongseong};
+    ///
+    /// assert_eq!(Jongseong::from_conjoining('\u{11A8}'), Ok(Jongseong::Kiyeok));
+    /// assert_eq!(
+    ///     Jongseong::from_conjoining('\u{1100}'),
+    ///     Err(Error::NonConjoiningJamo('\u{1100}'))
+    /// );
+    /// ```
+    pub fn from_conjoining(character: char) -> Result<Self, Error> {
+        if !(0x11A8..=0x11C2).contains(&(character as u32)) {
+            return Err(Error::NonConjoiningJamo(character));
+        }
+
+        Ok(Self::try_from((character as u32 - 0x11A7) as u8).unwrap())
+    }
+
+    /// Converts a 0-based index among the modern final consonants, i.e. the position in
+    /// [`Jongseong::ALL`], into one.
+    ///
+    /// This numbering stays the same regardless of how the variants are represented, and thus is
+    /// the one to rely on when migrating from the other crates, or doing arithmetic on the
+    /// Precomposed Korean [`Syllable`]s.
+    ///
+    /// ```
+    /// use unikorn::{Error, Jongseong};
+    ///
+    /// assert_eq!(Jongseong::from_modern_index(0), Ok(Jongseong::Kiyeok));
+    /// assert_eq!(Jongseong::from_modern_index(26), Ok(Jongseong::Hieuh));
+    /// assert_eq!(Jongseong::from_modern_index(27), Err(Error::NonModernIndex(27)));
+    /// ```
+    pub fn from_modern_index(index: u8) -> Result<Self, Error> {
+        index
+            .checked_add(1)
+            .and_then(|index| Self::try_from(index).ok())
+            .ok_or(Error::NonModernIndex(index))
+    }
+
+    /// Checks if this final consonant is a nasal (비음, Bieum), i.e. one of 'ㄴ', 'ㅁ', and 'ㅇ'.
+    ///
+    /// ```
+    /// use unikorn::Jongseong;
+    ///
+    /// assert!(Jongseong::Ieung.is_nasal());
+    /// assert!(!Jongseong::Kiyeok.is_nasal());
+    /// ```
+    pub const fn is_nasal(self) -> bool {
+        matches!(self, Self::Nieun | Self::Mieum | Self::Ieung)
+    }
+
+    /// Iterates over every modern final consonant, in Korean dictionary order.
+    ///
+    /// ```
+    /// use unikorn::Jongseong;
+    ///
+    /// assert_eq!(Jongseong::iter().count(), 27);
+    /// assert_eq!(Jongseong::iter().next(), Some(Jongseong::Kiyeok));
+    /// ```
+    pub fn iter() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        Self::ALL.into_iter()
+    }
+
+    /// Returns the name of this final consonant, as accepted by [`FromStr`].
+    ///
+    /// ```
+    /// use unikorn::Jongseong;
+    ///
+    /// assert_eq!(Jongseong::RieulKiyeok.name(), "RieulKiyeok");
+    /// assert_eq!("RieulKiyeok".parse(), Ok(Jongseong::RieulKiyeok));
+    /// ```
+    pub const fn name(self) -> &'static str {
+        JONGSEONG_NAMES[self as usize - 1]
+    }
+
+    /// Returns the initial consonant this final consonant is realized as, when it is linked (연음,
+    /// Yeoneum) into the next syllable beginning with a silent 'ㅇ'.
+    ///
+    /// For a clustered consonant, only its second component moves forward; see
+    /// [`Jongseong::split_for_syllable_boundary`]. Note that 'ㅎ' is returned as is, even though it
+    /// is usually dropped in actual pronunciation, e.g. '좋아' is pronounced as '조아'.
+    ///
+    /// ```
+    /// use unikorn::{Choseong, Jongseong};
+    ///
+    /// assert_eq!(Jongseong::Kiyeok.phonetically_equivalent_choseong(), Choseong::Kiyeok);
+    /// assert_eq!(Jongseong::RieulKiyeok.phonetically_equivalent_choseong(), Choseong::Kiyeok);
+    /// ```
+    pub fn phonetically_equivalent_choseong(self) -> Choseong {
+        self.split_for_syllable_boundary().1
+    }
+
+    /// Returns which of the seven representative sounds ('ㄱ', 'ㄴ', 'ㄷ', 'ㄹ', 'ㅁ', 'ㅂ', and 'ㅇ')
+    /// this final consonant is pronounced as at the end of a syllable (음절의 끝소리 규칙, Eumjeorui
+    /// Kkeutsori Gyuchik), e.g. 'ㄷ' for 'ㅅ', 'ㅆ', 'ㅈ', 'ㅊ', 'ㅌ', and 'ㅎ'.
+    ///
+    /// A clustered consonant is pronounced as one of its components, e.g. '닭' as '닥' and '삶' as
+    /// '삼'; the exceptions by the word, e.g. '밟다' pronounced as '밥따', are not covered.
+    ///
+    /// ```
+    /// use unikorn::Jongseong;
+    ///
+    /// assert_eq!(Jongseong::Khieukh.representative_sound(), Jongseong::Kiyeok);
+    /// assert_eq!(Jongseong::Cieuc.representative_sound(), Jongseong::Tikeut);
+    /// assert_eq!(Jongseong::RieulKiyeok.representative_sound(), Jongseong::Kiyeok);
+    /// assert_eq!(Jongseong::Ieung.representative_sound(), Jongseong::Ieung);
+    /// ```
+    pub const fn representative_sound(self) -> Self {
+        match self {
+            Self::Kiyeok
+            | Self::SsangKiyeok
+            | Self::KiyeokSios
+            | Self::RieulKiyeok
+            | Self::Khieukh => Self::Kiyeok,
+            Self::Nieun | Self::NieunCieuc | Self::NieunHieuh => Self::Nieun,
+            Self::Tikeut
+            | Self::Sios
+            | Self::SsangSios
+            | Self::Cieuc
+            | Self::Chieuch
+            | Self::Thieuth
+            | Self::Hieuh => Self::Tikeut,
+            Self::Rieul
+            | Self::RieulPieup
+            | Self::RieulSios
+            | Self::RieulThieuth
+            | Self::RieulHieuh => Self::Rieul,
+            Self::Mieum | Self::RieulMieum => Self::Mieum,
+            Self::Pieup | Self::PieupSios | Self::RieulPhieuph | Self::Phieuph => Self::Pieup,
+            Self::Ieung => Self::Ieung,
+        }
+    }
+
+    /// Determines what happens to this final consonant when it is linked (연음, Yeoneum) into a
+    /// following syllable beginning with a silent 'ㅇ', i.e. what stays as the final consonant, if
+    /// any, and what moves forward to become the initial consonant of the next syllable.
+    ///
+    /// A clustered consonant leaves its first component behind, e.g. '닭이' is pronounced as
+    /// '달기', while a simple one moves as a whole, e.g. '밖에' is pronounced as '바께'. The only
+    /// exception is 'ㅇ', which stays put, leaving the next syllable's silent 'ㅇ' as is.
+    ///
+    /// Note that the other rules, e.g. dropping 'ㅎ' in '좋아' (pronounced as '조아'), are not
+    /// applied.
+    ///
+    /// ```
+    /// use unikorn::{Choseong, Jongseong};
+    ///
+    /// assert_eq!(
+    ///     Jongseong::RieulKiyeok.resyllabify(),
+    ///     (Some(Jongseong::Rieul), Choseong::Kiyeok)
+    /// );
+    /// assert_eq!(Jongseong::SsangKiyeok.resyllabify(), (None, Choseong::SsangKiyeok));
+    /// assert_eq!(
+    ///     Jongseong::Ieung.resyllabify(),
+    ///     (Some(Jongseong::Ieung), Choseong::Ieung)
+    /// );
+    /// ```
+    pub fn resyllabify(self) -> (Option<Jongseong>, Choseong) {
+        match self {
+            Self::KiyeokSios => (Some(Self::Kiyeok), Choseong::Sios),
+            Self::NieunCieuc => (Some(Self::Nieun), Choseong::Cieuc),
+            Self::NieunHieuh => (Some(Self::Nieun), Choseong::Hieuh),
+            Self::RieulKiyeok => (Some(Self::Rieul), Choseong::Kiyeok),
+            Self::RieulMieum => (Some(Self::Rieul), Choseong::Mieum),
+            Self::RieulPieup => (Some(Self::Rieul), Choseong::Pieup),
+            Self::RieulSios => (Some(Self::Rieul), Choseong::Sios),
+            Self::RieulThieuth => (Some(Self::Rieul), Choseong::Thieuth),
+            Self::RieulPhieuph => (Some(Self::Rieul), Choseong::Phieuph),
+            Self::RieulHieuh => (Some(Self::Rieul), Choseong::Hieuh),
+            Self::PieupSios => (Some(Self::Pieup), Choseong::Sios),
+            Self::Ieung => (Some(Self::Ieung), Choseong::Ieung),
+            // every other simple final consonant has its initial counterpart.
+            simple => (None, Choseong::try_from(Jaeum::from(simple)).unwrap()),
+        }
+    }
+
+    /// Splits this final consonant at a syllable boundary, i.e. when it is followed by a syllable
+    /// beginning with a silent 'ㅇ' and thus linked (연음, Yeoneum) into the next syllable.
+    ///
+    /// For a clustered consonant, the first component stays as the final consonant, and the second
+    /// component moves forward to become the initial consonant of the next syllable, e.g. '닭이' is
+    /// pronounced as '달기'. For a simple consonant, it is returned as is, alongside the initial
+    /// consonant of the same sound.
+    ///
+    /// ```
+    /// use unikorn::{Choseong, Jongseong};
+    ///
+    /// assert_eq!(
+    ///     Jongseong::RieulKiyeok.split_for_syllable_boundary(),
+    ///     (Jongseong::Rieul, Choseong::Kiyeok)
+    /// );
+    /// assert_eq!(
+    ///     Jongseong::Mieum.split_for_syllable_boundary(),
+    ///     (Jongseong::Mieum, Choseong::Mieum)
+    /// );
+    /// ```
+    pub fn split_for_syllable_boundary(self) -> (Jongseong, Choseong) {
+        let (remaining, moved) = self.resyllabify();
+        (remaining.unwrap_or(self), moved)
+    }
+
+    /// Converts this final consonant into its counterpart in Hangul Compatibility Jamo range
+    /// (U+3131 'ㄱ' -- U+314E 'ㅎ'); the same as `char::from`, but usable in a `const` context.
+    ///
+    /// ```
+    /// use unikorn::Jongseong;
+    ///
+    /// const RIEUL_KIYEOK: char = Jongseong::RieulKiyeok.to_char();
+    /// assert_eq!(RIEUL_KIYEOK, 'ㄺ');
+    /// ```
+    pub const fn to_char(self) -> char {
+        JONGSEONG_TO_JAEUM[self as usize - 1].to_char()
+    }
+
+    /// Converts this final consonant into its counterpart in Hangul Jamo range (U+11A8 'ᆨ' --
+    /// U+11C2 'ᇂ'), i.e. the conjoining form.
+    ///
+    /// ```
+    /// use unikorn::Jongseong;
+    ///
+    /// assert_eq!(Jongseong::Kiyeok.to_conjoining(), '\u{11A8}');
+    /// ```
+    pub const fn to_conjoining(self) -> char {
+        // `Jongseong` starts from 1, so this is in fact 0x11A8 + (self as u32 - 1).
+        char_from_code_point(0x11A7 + self as u32)
+    }
+
+    /// Returns the compatibility consonant (자음, [`Jaeum`]) of this final consonant, the same as
+    /// [`Jaeum::from`] does, e.g. 'ㄺ' for U+11B0 'ᆰ'.
+    ///
+    /// Always returns [`Some`] for now, as with [`Choseong::to_jaeum_lossy`].
+    ///
+    /// ```
+    /// use unikorn::{Jaeum, Jongseong};
+    ///
+    /// assert_eq!(Jongseong::RieulKiyeok.to_jaeum_lossy(), Some(Jaeum::RieulKiyeok));
+    /// ```
+    pub const fn to_jaeum_lossy(self) -> Option<Jaeum> {
+        Some(JONGSEONG_TO_JAEUM[self as usize - 1])
+    }
+
+    /// Returns the 0-based index of this final consonant among the modern ones; the reverse of
+    /// [`Jongseong::from_modern_index`].
+    ///
+    /// ```
+    /// use unikorn::Jongseong;
+    ///
+    /// assert_eq!(Jongseong::Kiyeok.to_modern_index(), Some(0));
+    /// assert_eq!(Jongseong::Hieuh.to_modern_index(), Some(26));
+    /// ```
+    pub const fn to_modern_index(self) -> Option<u8> {
+        Some(self as u8 - 1)
+    }
+
+    /// Returns the plain (평음, Pyeongeum) counterpart of this final consonant if it is an
+    /// aspirated one (격음, Gyeogeum), e.g. 'ㄷ' for 'ㅌ', or itself otherwise.
+    ///
+    /// A clustered consonant is folded only if the result is a clustered consonant as well, i.e.
+    /// 'ㄿ' becomes 'ㄼ', while 'ㄾ' stays as it is, since there is no such thing as 'ㄹㄷ'.
+    ///
+    /// ```
+    /// use unikorn::Jongseong;
+    ///
+    /// assert_eq!(Jongseong::Khieukh.without_aspiration(), Jongseong::Kiyeok);
+    /// assert_eq!(Jongseong::RieulPhieuph.without_aspiration(), Jongseong::RieulPieup);
+    /// assert_eq!(Jongseong::RieulThieuth.without_aspiration(), Jongseong::RieulThieuth);
+    /// ```
+    pub const fn without_aspiration(self) -> Self {
+        match self {
+            Self::Chieuch => Self::Cieuc,
+            Self::Khieukh => Self::Kiyeok,
+            Self::Thieuth => Self::Tikeut,
+            Self::Phieuph => Self::Pieup,
+            Self::RieulPhieuph => Self::RieulPieup,
+            other => other,
+        }
+    }
+
+    /// Returns the plain (평음, Pyeongeum) counterpart of this final consonant if it is a tense one
+    /// (경음, Gyeongeum), i.e. 'ㄱ' for 'ㄲ' and 'ㅅ' for 'ㅆ', or itself otherwise.
+    ///
+    /// ```
+    /// use unikorn::Jongseong;
+    ///
+    /// assert_eq!(Jongseong::SsangSios.without_tension(), Jongseong::Sios);
+    /// assert_eq!(Jongseong::KiyeokSios.without_tension(), Jongseong::KiyeokSios);
+    /// ```
+    pub const fn without_tension(self) -> Self {
+        match self {
+            Self::SsangKiyeok => Self::Kiyeok,
+            Self::SsangSios => Self::Sios,
+            other => other,
         }
     }
 }
 
 /// Groups all the vowels applicable to the 'medial vowel' (중성, Jungseong) position of a Korean
 /// syllable.
-#[derive(Clone, Copy, Debug, Eq, IntoPrimitive, Ord, PartialEq, PartialOrd, TryFromPrimitive)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(
+    Clone, Copy, Debug, Eq, Hash, IntoPrimitive, Ord, PartialEq, PartialOrd, TryFromPrimitive,
+)]
 #[repr(u8)]
 pub enum Jungseong {
     /// Represents 'ㅏ'.
@@ -477,13 +1900,24 @@ pub enum Jungseong {
 }
 impl From<Jungseong> for char {
     fn from(jungseong: Jungseong) -> Self {
-        Self::from_u32(0x314F + jungseong as u32).unwrap()
+        jungseong.to_char()
     }
 }
+// indexed by `Jungseong as usize`.
+const JUNGSEONG_NAMES: [&str; 21] = [
+    "A", "Ae", "Ya", "Yae", "Eo", "E", "Yeo", "Ye", "O", "Wa", "Wae", "Oe", "Yo", "U", "Weo", "We",
+    "Wi", "Yu", "Eu", "Yi", "I",
+];
+impl_from_str!(Jungseong, JUNGSEONG_NAMES, 0, conjoining);
 impl TryFrom<char> for Jungseong {
     type Error = Error;
 
     fn try_from(character: char) -> Result<Self, Self::Error> {
+        #[cfg(feature = "fast-lookup")]
+        if let Some(jungseong) = lookup::jungseong(character) {
+            return Ok(jungseong);
+        }
+
         if !(0x314F..=0x3163).contains(&(character as u32)) {
             return Err(Error::NonJamo(character));
         }
@@ -491,6 +1925,428 @@ impl TryFrom<char> for Jungseong {
         Ok(Self::try_from((character as u32 - 0x314F) as u8).unwrap())
     }
 }
+// indexed by `Jungseong as usize`.
+const JUNGSEONG_TO_DUBEOLSIK: [Option<char>; 21] = [
+    Some('k'),
+    Some('o'),
+    Some('i'),
+    Some('O'),
+    Some('j'),
+    Some('p'),
+    Some('u'),
+    Some('P'),
+    Some('h'),
+    None,
+    None,
+    None,
+    Some('y'),
+    Some('n'),
+    None,
+    None,
+    None,
+    Some('b'),
+    Some('m'),
+    None,
+    Some('l'),
+];
+impl Jungseong {
+    /// Every modern medial vowel, in Korean dictionary order.
+    ///
+    /// ```
+    /// use unikorn::Jungseong;
+    ///
+    /// assert_eq!(Jungseong::ALL[0], Jungseong::A); // ㅏ
+    /// assert_eq!(Jungseong::ALL[Jungseong::COUNT - 1], Jungseong::I); // ㅣ
+    /// ```
+    pub const ALL: [Self; 21] = [
+        Self::A,
+        Self::Ae,
+        Self::Ya,
+        Self::Yae,
+        Self::Eo,
+        Self::E,
+        Self::Yeo,
+        Self::Ye,
+        Self::O,
+        Self::Wa,
+        Self::Wae,
+        Self::Oe,
+        Self::Yo,
+        Self::U,
+        Self::Weo,
+        Self::We,
+        Self::Wi,
+        Self::Yu,
+        Self::Eu,
+        Self::Yi,
+        Self::I,
+    ];
+
+    /// The number of the modern medial vowels, i.e. the length of [`Jungseong::ALL`].
+    pub const COUNT: usize = 21;
+
+    /// Every modern medial vowel as a slice, in the dictionary order of [`Jungseong::ALL`], e.g.
+    /// for rendering a jamo palette; see [`Jaeum::MODERN`] for the consonants.
+    ///
+    /// ```
+    /// use unikorn::Moeum;
+    ///
+    /// assert_eq!(Moeum::MODERN.len(), 21);
+    /// assert_eq!(Moeum::MODERN[0], Moeum::A); // ㅏ
+    /// ```
+    pub const MODERN: &'static [Self] = &Self::ALL;
+
+    /// Every modern medial vowel, in the order of the standard 2-set (두벌식, Dubeolsik) keyboard,
+    /// i.e. as laid out on a keyboard-like palette: the keys row by row from the left, the ones
+    /// taking Shift after the rest, and then the compound vowels not on the keyboard, in the order
+    /// of [`Jungseong::ALL`]; see [`Jaeum::KEYBOARD_ORDER`] for the consonants.
+    ///
+    /// ```
+    /// use unikorn::Moeum;
+    ///
+    /// assert_eq!(Moeum::KEYBOARD_ORDER[0], Moeum::Yo); // y
+    /// ```
+    pub const KEYBOARD_ORDER: [Self; 21] = [
+        Self::Yo,
+        Self::Yeo,
+        Self::Ya,
+        Self::Ae,
+        Self::E,
+        Self::O,
+        Self::Eo,
+        Self::A,
+        Self::I,
+        Self::Yu,
+        Self::U,
+        Self::Eu,
+        Self::Yae,
+        Self::Ye,
+        Self::Wa,
+        Self::Wae,
+        Self::Oe,
+        Self::Weo,
+        Self::We,
+        Self::Wi,
+        Self::Yi,
+    ];
+
+    /// Classifies this medial vowel by [`VowelBackness`].
+    ///
+    /// A diphthong (see [`Jungseong::is_compound`]) is classified by its primary component, i.e.
+    /// the vowel without the 'y' glide for 'ㅑ', 'ㅒ', 'ㅕ', 'ㅖ', 'ㅛ', and 'ㅠ', and the leftmost
+    /// component for the rest.
+    ///
+    /// ```
+    /// use unikorn::{Jungseong, VowelBackness};
+    ///
+    /// assert_eq!(Jungseong::I.backness(), VowelBackness::Front);
+    /// assert_eq!(Jungseong::Eu.backness(), VowelBackness::Central);
+    /// assert_eq!(Jungseong::Wa.backness(), VowelBackness::Back); // as in 'ㅗ'
+    /// ```
+    pub const fn backness(self) -> VowelBackness {
+        match self.primary_component() {
+            Self::Ae | Self::E | Self::Oe | Self::Wi | Self::I => VowelBackness::Front,
+            Self::Eu => VowelBackness::Central,
+            _ => VowelBackness::Back,
+        }
+    }
+
+    /// Returns the key typing this vowel on the standard 2-set (두벌식, Dubeolsik) keyboard laid
+    /// over QWERTY, in uppercase if it takes Shift, or [`None`] if it takes more than one key,
+    /// e.g. 'ㅘ'.
+    ///
+    /// ```
+    /// use unikorn::Jungseong;
+    ///
+    /// assert_eq!(Jungseong::A.dubeolsik_key(), Some('k'));
+    /// assert_eq!(Jungseong::Yae.dubeolsik_key(), Some('O')); // ㅒ
+    /// assert_eq!(Jungseong::Wa.dubeolsik_key(), None); // ㅘ
+    /// ```
+    pub const fn dubeolsik_key(self) -> Option<char> {
+        JUNGSEONG_TO_DUBEOLSIK[self as usize]
+    }
+
+    /// Converts a [`char`] in Hangul Jamo range (U+1161 'ᅡ' -- U+1175 'ᅵ'), i.e. the conjoining
+    /// form, into a medial vowel.
+    ///
+    /// ```
+    /// use unikorn::{Error, Jungseong};
+    ///
+    /// assert_eq!(Jungseong::from_conjoining('\u{1161}'), Ok(Jungseong::A));
+    /// assert_eq!(
+    ///     Jungseong::from_conjoining('ㅏ'),
+    ///     Err(Error::NonConjoiningJamo('ㅏ'))
+    /// );
+    /// ```
+    pub fn from_conjoining(character: char) -> Result<Self, Error> {
+        if !(0x1161..=0x1175).contains(&(character as u32)) {
+            return Err(Error::NonConjoiningJamo(character));
+        }
+
+        Ok(Self::try_from((character as u32 - 0x1161) as u8).unwrap())
+    }
+
+    /// Converts a 0-based index among the modern medial vowels, i.e. the position in
+    /// [`Jungseong::ALL`], into one.
+    ///
+    /// This numbering stays the same regardless of how the variants are represented, and thus is
+    /// the one to rely on when migrating from the other crates, or doing arithmetic on the
+    /// Precomposed Korean [`Syllable`]s.
+    ///
+    /// ```
+    /// use unikorn::{Error, Jungseong};
+    ///
+    /// assert_eq!(Jungseong::from_modern_index(0), Ok(Jungseong::A));
+    /// assert_eq!(Jungseong::from_modern_index(20), Ok(Jungseong::I));
+    /// assert_eq!(Jungseong::from_modern_index(21), Err(Error::NonModernIndex(21)));
+    /// ```
+    pub fn from_modern_index(index: u8) -> Result<Self, Error> {
+        Self::try_from(index).map_err(|_| Error::NonModernIndex(index))
+    }
+
+    /// Fuses this medial vowel, the last one of a verb or an adjective stem, with the first vowel
+    /// of an ending, following the regular contraction rules of Korean conjugation, e.g. 'ㅗ' and
+    /// 'ㅏ' into 'ㅘ' as in '와'; see [`contract_vowels`] for the whole syllables.
+    ///
+    /// The rules covered are:
+    /// * the same vowels merging, i.e. 'ㅏ' and 'ㅏ', or 'ㅓ' and 'ㅓ' into itself, as in '가', '서'
+    /// * 'ㅐ' or 'ㅔ', or 'ㅕ' absorbing 'ㅓ', as in '내', '세', '켜'
+    /// * 'ㅗ' and 'ㅏ' into 'ㅘ', 'ㅜ' and 'ㅓ' into 'ㅝ', 'ㅚ' and 'ㅓ' into 'ㅙ', as in '와', '배워',
+    ///   '돼'
+    /// * 'ㅣ' and 'ㅓ' into 'ㅕ', as in '마셔'
+    /// * 'ㅡ' dropping before 'ㅏ' or 'ㅓ', as in '바빠', '써'
+    /// * 'ㅏ' and 'ㅕ' into 'ㅐ', which only occurs with the stem '하', as in '해'
+    ///
+    /// Returns [`None`] for any other pair, e.g. 'ㅟ' and 'ㅓ', which are not contracted in writing.
+    ///
+    /// ```
+    /// use unikorn::Jungseong;
+    ///
+    /// assert_eq!(Jungseong::O.fuse(Jungseong::A), Some(Jungseong::Wa));
+    /// assert_eq!(Jungseong::Eu.fuse(Jungseong::Eo), Some(Jungseong::Eo));
+    /// assert_eq!(Jungseong::Wi.fuse(Jungseong::Eo), None);
+    /// ```
+    pub const fn fuse(self, other: Self) -> Option<Self> {
+        Some(match (self, other) {
+            (Self::A, Self::A) | (Self::Eu, Self::A) => Self::A,
+            (Self::Eo, Self::Eo) | (Self::Eu, Self::Eo) => Self::Eo,
+            (Self::Ae, Self::Eo) | (Self::A, Self::Yeo) => Self::Ae,
+            (Self::E, Self::Eo) => Self::E,
+            (Self::Yeo, Self::Eo) | (Self::I, Self::Eo) => Self::Yeo,
+            (Self::O, Self::A) => Self::Wa,
+            (Self::Oe, Self::Eo) => Self::Wae,
+            (Self::U, Self::Eo) => Self::Weo,
+            _ => return None,
+        })
+    }
+
+    /// Classifies this medial vowel by [`VowelHeight`].
+    ///
+    /// A diphthong is classified by its primary component, just like [`Jungseong::backness`].
+    ///
+    /// ```
+    /// use unikorn::{Jungseong, VowelHeight};
+    ///
+    /// assert_eq!(Jungseong::U.height(), VowelHeight::High);
+    /// assert_eq!(Jungseong::E.height(), VowelHeight::Mid);
+    /// assert_eq!(Jungseong::Ya.height(), VowelHeight::Low); // as in 'ㅏ'
+    /// ```
+    pub const fn height(self) -> VowelHeight {
+        match self.primary_component() {
+            Self::Wi | Self::U | Self::Eu | Self::I => VowelHeight::High,
+            Self::A => VowelHeight::Low,
+            _ => VowelHeight::Mid,
+        }
+    }
+
+    /// Checks if this medial vowel is a diphthong (이중모음, Ijungmoeum) as per the Standard
+    /// Pronunciation of Korean, i.e. one of 'ㅑ', 'ㅒ', 'ㅕ', 'ㅖ', 'ㅘ', 'ㅙ', 'ㅛ', 'ㅝ', 'ㅞ',
+    /// 'ㅠ', and 'ㅢ'.
+    ///
+    /// Note that 'ㅚ' and 'ㅟ' are classified as monophthongs (단모음, Danmoeum) by the standard,
+    /// even though they are commonly pronounced as diphthongs.
+    ///
+    /// ```
+    /// use unikorn::Jungseong;
+    ///
+    /// assert!(Jungseong::Wa.is_compound());
+    /// assert!(!Jungseong::A.is_compound());
+    /// assert!(!Jungseong::Oe.is_compound());
+    /// ```
+    pub const fn is_compound(self) -> bool {
+        matches!(
+            self,
+            Self::Ya
+                | Self::Yae
+                | Self::Yeo
+                | Self::Ye
+                | Self::Wa
+                | Self::Wae
+                | Self::Yo
+                | Self::Weo
+                | Self::We
+                | Self::Yu
+                | Self::Yi
+        )
+    }
+
+    /// Checks if this medial vowel is pronounced with rounded lips (원순모음, Wonsunmoeum).
+    ///
+    /// A diphthong is classified by its primary component, just like [`Jungseong::backness`].
+    ///
+    /// ```
+    /// use unikorn::Jungseong;
+    ///
+    /// assert!(Jungseong::O.is_rounded());
+    /// assert!(Jungseong::Weo.is_rounded()); // as in 'ㅜ'
+    /// assert!(!Jungseong::A.is_rounded());
+    /// ```
+    pub const fn is_rounded(self) -> bool {
+        matches!(
+            self.primary_component(),
+            Self::O | Self::Oe | Self::U | Self::Wi
+        )
+    }
+
+    /// Checks if this medial vowel is mediated by a 'w' glide, i.e. one of 'ㅘ', 'ㅙ', 'ㅚ', 'ㅝ',
+    /// 'ㅞ', and 'ㅟ'.
+    ///
+    /// ```
+    /// use unikorn::Jungseong;
+    ///
+    /// assert!(Jungseong::Wa.is_w_mediated());
+    /// assert!(!Jungseong::O.is_w_mediated());
+    /// ```
+    pub const fn is_w_mediated(self) -> bool {
+        matches!(
+            self,
+            Self::Wa | Self::Wae | Self::Oe | Self::Weo | Self::We | Self::Wi
+        )
+    }
+
+    /// Checks if this medial vowel is mediated by a 'y' glide, i.e. one of 'ㅑ', 'ㅒ', 'ㅕ', 'ㅖ',
+    /// 'ㅛ', 'ㅠ', and 'ㅢ'.
+    ///
+    /// Unlike [`Jungseong::is_yotized`], this includes 'ㅢ' as well, whose glide comes after the
+    /// vowel.
+    ///
+    /// ```
+    /// use unikorn::Jungseong;
+    ///
+    /// assert!(Jungseong::Ya.is_y_mediated());
+    /// assert!(Jungseong::Yi.is_y_mediated());
+    /// assert!(!Jungseong::A.is_y_mediated());
+    /// ```
+    pub const fn is_y_mediated(self) -> bool {
+        self.is_yotized() || matches!(self, Self::Yi)
+    }
+
+    /// Checks if this medial vowel starts with a 'y' glide, i.e. one of 'ㅑ', 'ㅒ', 'ㅕ', 'ㅖ',
+    /// 'ㅛ', and 'ㅠ'.
+    ///
+    /// ```
+    /// use unikorn::Jungseong;
+    ///
+    /// assert!(Jungseong::Ya.is_yotized());
+    /// assert!(!Jungseong::A.is_yotized());
+    /// ```
+    pub const fn is_yotized(self) -> bool {
+        matches!(
+            self,
+            Self::Ya | Self::Yae | Self::Yeo | Self::Ye | Self::Yo | Self::Yu
+        )
+    }
+
+    /// Iterates over every modern medial vowel, in Korean dictionary order.
+    ///
+    /// ```
+    /// use unikorn::Jungseong;
+    ///
+    /// assert_eq!(Jungseong::iter().count(), 21);
+    /// assert_eq!(Jungseong::iter().next(), Some(Jungseong::A));
+    /// ```
+    pub fn iter() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        Self::ALL.into_iter()
+    }
+
+    /// Returns the name of this medial vowel, as accepted by [`FromStr`].
+    ///
+    /// ```
+    /// use unikorn::Jungseong;
+    ///
+    /// assert_eq!(Jungseong::Weo.name(), "Weo");
+    /// assert_eq!("Weo".parse(), Ok(Jungseong::Weo));
+    /// ```
+    pub const fn name(self) -> &'static str {
+        JUNGSEONG_NAMES[self as usize]
+    }
+
+    // reduces a diphthong into the monophthong its phonetic properties are taken from.
+    const fn primary_component(self) -> Self {
+        match self {
+            Self::Ya => Self::A,
+            Self::Yae => Self::Ae,
+            Self::Yeo => Self::Eo,
+            Self::Ye => Self::E,
+            Self::Wa | Self::Wae | Self::Yo => Self::O,
+            Self::Weo | Self::We | Self::Yu => Self::U,
+            Self::Yi => Self::Eu,
+            monophthong => monophthong,
+        }
+    }
+
+    /// Converts this medial vowel into its counterpart in Hangul Compatibility Jamo range (U+314F
+    /// 'ㅏ' -- U+3163 'ㅣ'); the same as `char::from`, but usable in a `const` context.
+    ///
+    /// ```
+    /// use unikorn::Jungseong;
+    ///
+    /// const WA: char = Jungseong::Wa.to_char();
+    /// assert_eq!(WA, 'ㅘ');
+    /// ```
+    pub const fn to_char(self) -> char {
+        char_from_code_point(0x314F + self as u32)
+    }
+
+    /// Converts this medial vowel into its counterpart in Hangul Jamo range (U+1161 'ᅡ' -- U+1175
+    /// 'ᅵ'), i.e. the conjoining form.
+    ///
+    /// ```
+    /// use unikorn::Jungseong;
+    ///
+    /// assert_eq!(Jungseong::A.to_conjoining(), '\u{1161}');
+    /// ```
+    pub const fn to_conjoining(self) -> char {
+        char_from_code_point(0x1161 + self as u32)
+    }
+
+    /// Returns the 0-based index of this medial vowel among the modern ones; the reverse of
+    /// [`Jungseong::from_modern_index`].
+    ///
+    /// ```
+    /// use unikorn::Jungseong;
+    ///
+    /// assert_eq!(Jungseong::A.to_modern_index(), Some(0));
+    /// assert_eq!(Jungseong::I.to_modern_index(), Some(20));
+    /// ```
+    pub const fn to_modern_index(self) -> Option<u8> {
+        Some(self as u8)
+    }
+
+    /// Returns the compatibility vowel (모음, [`Moeum`]) of this medial vowel, which is the very
+    /// same value, as [`Moeum`] is an alias of [`Jungseong`].
+    ///
+    /// Always returns [`Some`] for now, as with [`Choseong::to_jaeum_lossy`].
+    ///
+    /// ```
+    /// use unikorn::{Jungseong, Moeum};
+    ///
+    /// assert_eq!(Jungseong::Wa.to_moeum_lossy(), Some(Moeum::Wa));
+    /// ```
+    pub const fn to_moeum_lossy(self) -> Option<Moeum> {
+        Some(self)
+    }
+}
 
 /// Groups all the Korean vowels (모음, Moeum).
 ///
@@ -519,12 +2375,39 @@ pub type Moeum = Jungseong;
 /// assert_eq!(syllable.jungseong, Jungseong::Weo);
 /// assert_eq!(syllable.jongseong, None);
 /// ```
-#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Syllable {
     pub choseong: Choseong,
     pub jungseong: Jungseong,
     pub jongseong: Option<Jongseong>,
 }
+/// Writes the syllable as its precomposed [`char`], or, with the alternate flag (`{:#}`), as the
+/// sequence of conjoining jamo (Hangul Jamo range, U+1100 -- U+11FF) accepted by
+/// [`Syllable::try_from_jamo_sequence`].
+///
+/// ```
+/// use unikorn::Syllable;
+/// use std::convert::TryFrom;
+///
+/// let syllable = Syllable::try_from('한').unwrap();
+/// assert_eq!(syllable.to_string(), "한");
+/// assert_eq!(format!("{:#}", syllable), "\u{1112}\u{1161}\u{11AB}");
+/// ```
+impl Display for Syllable {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        if !f.alternate() {
+            return Display::fmt(&char::from(*self), f);
+        }
+
+        // up to three jamo, 3 bytes each; written in one go, so that the width applies to all.
+        let mut buffer = [0; 9];
+        let mut length = 0;
+        for character in JamoForm::Conjoining.decompose(*self) {
+            length += character.encode_utf8(&mut buffer[length..]).len();
+        }
+        f.pad(core::str::from_utf8(&buffer[..length]).unwrap())
+    }
+}
 impl From<(Choseong, Jungseong)> for Syllable {
     fn from((choseong, jungseong): (Choseong, Jungseong)) -> Self {
         Self {
@@ -568,30 +2451,35 @@ impl From<Syllable> for (Choseong, Jungseong, Option<Jongseong>) {
 }
 impl From<Syllable> for char {
     fn from(syllable: Syllable) -> Self {
-        // all precomposed korean syllables are within BMP, so in this context, it is safe to
-        // assume:
-        //     Unicode Scalar Value == Unicode Code Point
-        // and thus, `char::from_u32()` never fails.
-        Self::from_u32(
-            0xAC00
-                + (syllable.choseong as u32 * 21 * 28)
-                + (syllable.jungseong as u32 * 28)
-                + if let Some(jongseong) = syllable.jongseong {
-                    jongseong as u32
-                } else {
-                    0
-                },
-        )
-        .unwrap()
+        syllable.to_char()
     }
 }
-impl TryFrom<char> for Syllable {
-    type Error = Error;
-
-    fn try_from(character: char) -> Result<Self, Self::Error> {
-        if !Self::is_one_of_us(character) {
-            return Err(Error::NonKorean(character));
-        }
+impl From<Syllable> for [u8; 3] {
+    fn from(syllable: Syllable) -> Self {
+        // all precomposed korean syllables lie within U+0800 -- U+FFFF, which is exactly the range
+        // encoded in 3 bytes in UTF-8.
+        let mut bytes = [0; 3];
+        char::from(syllable).encode_utf8(&mut bytes);
+        bytes
+    }
+}
+impl PartialEq<char> for Syllable {
+    fn eq(&self, other: &char) -> bool {
+        char::from(*self) == *other
+    }
+}
+impl PartialEq<Syllable> for char {
+    fn eq(&self, other: &Syllable) -> bool {
+        *self == char::from(*other)
+    }
+}
+impl TryFrom<char> for Syllable {
+    type Error = Error;
+
+    fn try_from(character: char) -> Result<Self, Self::Error> {
+        if !Self::is_one_of_us(character) {
+            return Err(Error::NonKorean(character, None));
+        }
 
         // all precomposed korean syllables are within BMP, so in this context, it is safe to
         // assume:
@@ -611,7 +2499,277 @@ impl TryFrom<char> for Syllable {
         })
     }
 }
+impl TryFrom<[u8; 3]> for Syllable {
+    type Error = Error;
+
+    fn try_from(bytes: [u8; 3]) -> Result<Self, Self::Error> {
+        let mut characters = core::str::from_utf8(&bytes)
+            .map_err(|_| Error::NonKoreanUtf8(bytes))?
+            .chars();
+
+        match (characters.next(), characters.next()) {
+            (Some(character), None) => {
+                Self::try_from(character).map_err(|_| Error::NonKoreanUtf8(bytes))
+            }
+            _ => Err(Error::NonKoreanUtf8(bytes)),
+        }
+    }
+}
+// indexed by `Choseong as usize`; the values in the initial position of the Revised Romanization.
+#[cfg(feature = "alloc")]
+const CHOSEONG_TO_RR: [&str; 19] = [
+    "g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s", "ss", "", "j", "jj", "ch", "k", "t", "p",
+    "h",
+];
+// indexed by `Jungseong as usize`.
+#[cfg(feature = "alloc")]
+const JUNGSEONG_TO_RR: [&str; 21] = [
+    "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa", "wae", "oe", "yo", "u", "wo", "we",
+    "wi", "yu", "eu", "ui", "i",
+];
+// indexed by `Jongseong as usize - 1`, as `Jongseong` starts from 1; the values in the final
+// position of the Revised Romanization, a cluster spelled out as both of its components in the
+// final position as well, e.g. 'ㄵ' as 'ㄴ' 'n' and 'ㅈ' 't'.
+#[cfg(feature = "alloc")]
+const JONGSEONG_TO_RR: [&str; 27] = [
+    "k", "k", "kt", "n", "nt", "nt", "t", "l", "lk", "lm", "lp", "lt", "lt", "lp", "lt", "m", "p",
+    "pt", "t", "t", "ng", "t", "t", "k", "t", "p", "t",
+];
 impl Syllable {
+    /// Applies the initial sound law (두음법칙, Dueumbeopchik) to this syllable, as if it began a
+    /// word, e.g. '리' of '李' into '이' in '이씨' (李氏).
+    ///
+    /// An initial 'ㄹ' turns into 'ㅇ' before 'ㅣ' or a vowel beginning with a 'y' sound ('ㅑ', 'ㅕ',
+    /// 'ㅖ', 'ㅛ', and 'ㅠ'), and into 'ㄴ' before any other vowel; an initial 'ㄴ' turns into
+    /// 'ㅇ' before the same vowels. Any other syllable is returned as is.
+    ///
+    /// ```
+    /// use unikorn::Syllable;
+    /// use std::convert::TryFrom;
+    ///
+    /// let law = |reading| {
+    ///     char::from(Syllable::try_from(reading).unwrap().apply_initial_sound_law())
+    /// };
+    /// assert_eq!(law('력'), '역'); // 歷史, 역사
+    /// assert_eq!(law('로'), '노'); // 老人, 노인
+    /// assert_eq!(law('녀'), '여'); // 女子, 여자
+    /// assert_eq!(law('남'), '남'); // 男子, 남자
+    /// ```
+    pub const fn apply_initial_sound_law(self) -> Self {
+        let before_i_or_y = matches!(
+            self.jungseong,
+            Jungseong::Ya
+                | Jungseong::Yeo
+                | Jungseong::Ye
+                | Jungseong::Yo
+                | Jungseong::Yu
+                | Jungseong::I
+        );
+        let choseong = match (self.choseong, before_i_or_y) {
+            (Choseong::Rieul, true) | (Choseong::Nieun, true) => Choseong::Ieung,
+            (Choseong::Rieul, false) => Choseong::Nieun,
+            (choseong, _) => choseong,
+        };
+
+        Self { choseong, ..self }
+    }
+
+    /// Composes an initial consonant, a medial vowel, and optionally a final consonant into a
+    /// Precomposed Korean syllable; the same as `char::from` on a [`Syllable`] made of them, but
+    /// usable in a `const` context.
+    ///
+    /// ```
+    /// use unikorn::{Choseong, Jongseong, Jungseong, Syllable};
+    ///
+    /// // the first syllable beginning with each initial consonant, as in a dictionary's index.
+    /// static SECTIONS: [char; 19] = {
+    ///     let mut sections = ['\0'; 19];
+    ///     let mut index = 0;
+    ///     while index < 19 {
+    ///         sections[index] = Syllable::compose(Choseong::ALL[index], Jungseong::A, None);
+    ///         index += 1;
+    ///     }
+    ///     sections
+    /// };
+    ///
+    /// assert_eq!(SECTIONS[0], '가');
+    /// assert_eq!(SECTIONS[18], '하');
+    /// assert_eq!(
+    ///     Syllable::compose(Choseong::Hieuh, Jungseong::A, Some(Jongseong::Nieun)),
+    ///     '한'
+    /// );
+    /// ```
+    pub const fn compose(
+        choseong: Choseong,
+        jungseong: Jungseong,
+        jongseong: Option<Jongseong>,
+    ) -> char {
+        Syllable {
+            choseong,
+            jungseong,
+            jongseong,
+        }
+        .to_char()
+    }
+
+    /// Returns the position of this syllable among all the 11,172 modern Korean syllables in
+    /// dictionary order, i.e. a value in `0..=11171`.
+    ///
+    /// This is a dense index, also the offset of the syllable from U+AC00 '가', which agrees with
+    /// the `Ord` impl of [`Syllable`]; a table of something for every syllable can be indexed by it
+    /// right away:
+    ///
+    /// ```
+    /// use unikorn::Syllable;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert_eq!(Syllable::try_from('가').unwrap().dictionary_index(), 0);
+    /// assert_eq!(Syllable::try_from('각').unwrap().dictionary_index(), 1);
+    /// assert_eq!(Syllable::try_from('힣').unwrap().dictionary_index(), 11171);
+    ///
+    /// let mut frequencies = [0; 11172];
+    /// for character in "가나다가".chars() {
+    ///     let syllable = Syllable::try_from(character).unwrap();
+    ///     frequencies[syllable.dictionary_index() as usize] += 1;
+    /// }
+    /// assert_eq!(frequencies[0], 2);
+    /// ```
+    #[doc(alias = "index")]
+    pub const fn dictionary_index(self) -> u32 {
+        (self.choseong as u32 * 21 + self.jungseong as u32) * 28
+            + match self.jongseong {
+                Some(jongseong) => jongseong as u32,
+                None => 0,
+            }
+    }
+
+    /// Checks if this syllable and `other` differ in the initial consonant (초성, [`Choseong`]) and
+    /// nothing else, e.g. '가' and '나'. Two identical syllables do not differ at all.
+    ///
+    /// ```
+    /// use unikorn::Syllable;
+    /// use std::convert::TryFrom;
+    ///
+    /// let syllable = |character| Syllable::try_from(character).unwrap();
+    /// assert!(syllable('가').differs_only_in_choseong(syllable('나')));
+    /// assert!(!syllable('가').differs_only_in_choseong(syllable('낙')));
+    /// ```
+    pub fn differs_only_in_choseong(self, other: Self) -> bool {
+        self.choseong != other.choseong
+            && self.jungseong == other.jungseong
+            && self.jongseong == other.jongseong
+    }
+
+    /// Checks if this syllable and `other` differ in the final consonant (종성, [`Jongseong`]), or
+    /// whether there is one, and nothing else, e.g. '가' and '각'. Two identical syllables do not
+    /// differ at all.
+    ///
+    /// ```
+    /// use unikorn::Syllable;
+    /// use std::convert::TryFrom;
+    ///
+    /// let syllable = |character| Syllable::try_from(character).unwrap();
+    /// assert!(syllable('가').differs_only_in_jongseong(syllable('각')));
+    /// assert!(syllable('닭').differs_only_in_jongseong(syllable('달')));
+    /// assert!(!syllable('가').differs_only_in_jongseong(syllable('가')));
+    /// ```
+    pub fn differs_only_in_jongseong(self, other: Self) -> bool {
+        self.choseong == other.choseong
+            && self.jungseong == other.jungseong
+            && self.jongseong != other.jongseong
+    }
+
+    /// Checks if this syllable and `other` differ in the medial vowel (중성, [`Jungseong`]) and
+    /// nothing else, e.g. '가' and '거'. Two identical syllables do not differ at all.
+    ///
+    /// ```
+    /// use unikorn::Syllable;
+    /// use std::convert::TryFrom;
+    ///
+    /// let syllable = |character| Syllable::try_from(character).unwrap();
+    /// assert!(syllable('각').differs_only_in_jungseong(syllable('걱')));
+    /// assert!(!syllable('각').differs_only_in_jungseong(syllable('거')));
+    /// ```
+    pub fn differs_only_in_jungseong(self, other: Self) -> bool {
+        self.choseong == other.choseong
+            && self.jungseong != other.jungseong
+            && self.jongseong == other.jongseong
+    }
+
+    /// Does the reverse of [`Syllable::dictionary_index`], or returns [`None`] if `index` is beyond
+    /// 11171.
+    ///
+    /// ```
+    /// use unikorn::Syllable;
+    ///
+    /// assert_eq!(Syllable::from_index(0).unwrap(), '가');
+    /// assert_eq!(Syllable::from_index(11171).unwrap(), '힣');
+    /// assert_eq!(Syllable::from_index(11172), None);
+    /// ```
+    ///
+    /// Being a `const fn`, this can fill up a table at compile time:
+    ///
+    /// ```
+    /// use unikorn::Syllable;
+    ///
+    /// static SYLLABLES: [char; 11172] = {
+    ///     let mut syllables = ['\0'; 11172];
+    ///     let mut index = 0;
+    ///     while index < 11172 {
+    ///         syllables[index] = match Syllable::from_index(index as u32) {
+    ///             Some(syllable) => syllable.to_char(),
+    ///             None => '\0',
+    ///         };
+    ///         index += 1;
+    ///     }
+    ///     syllables
+    /// };
+    ///
+    /// assert_eq!(SYLLABLES[0], '가');
+    /// assert_eq!(SYLLABLES[11171], '힣');
+    /// ```
+    pub const fn from_index(index: u32) -> Option<Self> {
+        if index > 11171 {
+            return None;
+        }
+
+        let jongseong = (index % 28) as usize;
+        Some(Self {
+            choseong: Choseong::ALL[(index / 28 / 21) as usize],
+            jungseong: Jungseong::ALL[(index / 28 % 21) as usize],
+            jongseong: if jongseong == 0 {
+                None
+            } else {
+                Some(Jongseong::ALL[jongseong - 1])
+            },
+        })
+    }
+
+    /// Does the same as [`Syllable::try_from_u16`], but without checking if `index` is in range.
+    ///
+    /// # Safety
+    /// `index` must be within `0..=11171`; anything beyond results in undefined behaviour.
+    ///
+    /// ```
+    /// use unikorn::Syllable;
+    ///
+    /// assert_eq!(unsafe { Syllable::from_u16_unchecked(11171) }, '힣');
+    /// ```
+    pub unsafe fn from_u16_unchecked(index: u16) -> Self {
+        debug_assert!(index <= 11171);
+
+        let jongseong = (index % 28) as u8;
+        Self {
+            choseong: core::mem::transmute::<u8, Choseong>((index / 28 / 21) as u8),
+            jungseong: core::mem::transmute::<u8, Jungseong>((index / 28 % 21) as u8),
+            jongseong: if jongseong == 0 {
+                None
+            } else {
+                Some(core::mem::transmute::<u8, Jongseong>(jongseong))
+            },
+        }
+    }
+
     /// Determines if a given [`char`] is one of the 11,172 valid modern Korean syllables.
     pub fn is_one_of_us(character: char) -> bool {
         // all precomposed korean syllables are within BMP, so in this context, it is safe to
@@ -621,135 +2779,1850 @@ impl Syllable {
 
         (0xAC00..=0xD7A3).contains(&character)
     }
+
+    /// Determines if this syllable renders as one block, either as its precomposed [`char`] or as
+    /// its sequence of conjoining jamo (`{:#}`).
+    ///
+    /// The two are not the same question: a syllable of archaic jamo, e.g. 'ᅌᆞᆷ', has no code point
+    /// of its own, but its conjoining sequence still renders as one block in a proper font. A
+    /// `Syllable` is made up of the modern jamo only, though, and every one of them is precomposed,
+    /// so this always returns `true` for now.
+    ///
+    /// ```
+    /// use unikorn::Syllable;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(Syllable::try_from('한').unwrap().is_renderable_block());
+    /// ```
+    pub const fn is_renderable_block(self) -> bool {
+        true
+    }
+
+    /// Iterates over every Precomposed Korean syllable, i.e. all 11172 of them, in the order of
+    /// their code points (U+AC00 '가' -- U+D7A3 '힣'), which is also Korean dictionary order.
+    ///
+    /// ```
+    /// use unikorn::Syllable;
+    ///
+    /// assert_eq!(Syllable::iter_all().len(), 11172);
+    /// assert_eq!(Syllable::iter_all().next().unwrap(), '가');
+    /// assert_eq!(Syllable::iter_all().next_back().unwrap(), '힣');
+    ///
+    /// // every syllable beginning with 'ㅎ' and ending with 'ㄴ', as in '한'.
+    /// let han = Syllable::iter_all().filter(|syllable| {
+    ///     syllable.choseong == unikorn::Choseong::Hieuh
+    ///         && syllable.jongseong == Some(unikorn::Jongseong::Nieun)
+    /// });
+    /// assert_eq!(han.count(), 21);
+    /// ```
+    pub fn iter_all() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        (0..11172).map(|index| Self::from_index(index).unwrap())
+    }
+
+    /// Returns a copy of this syllable with the initial consonant transformed by `f`.
+    ///
+    /// ```
+    /// use unikorn::{Choseong, Syllable};
+    /// use std::convert::TryFrom;
+    ///
+    /// let syllable = Syllable::try_from('각').unwrap();
+    /// assert_eq!(syllable.map_choseong(|_| Choseong::SsangKiyeok), '깍');
+    /// ```
+    pub fn map_choseong(self, f: impl FnOnce(Choseong) -> Choseong) -> Self {
+        self.with_choseong(f(self.choseong))
+    }
+
+    /// Returns a copy of this syllable with the final consonant transformed by `f`, which is given
+    /// [`None`] if there is none.
+    ///
+    /// ```
+    /// use unikorn::{Jongseong, Syllable};
+    /// use std::convert::TryFrom;
+    ///
+    /// let syllable = Syllable::try_from('닭').unwrap();
+    /// assert_eq!(
+    ///     syllable.map_jongseong(|jongseong| jongseong
+    ///         .and_then(Jongseong::components)
+    ///         .map(|(first, _)| first)),
+    ///     '달'
+    /// );
+    /// ```
+    pub fn map_jongseong(self, f: impl FnOnce(Option<Jongseong>) -> Option<Jongseong>) -> Self {
+        self.with_jongseong(f(self.jongseong))
+    }
+
+    /// Returns a copy of this syllable with the medial vowel transformed by `f`.
+    ///
+    /// ```
+    /// use unikorn::{Jungseong, Syllable};
+    /// use std::convert::TryFrom;
+    ///
+    /// let syllable = Syllable::try_from('곰').unwrap();
+    /// assert_eq!(
+    ///     syllable.map_jungseong(|jungseong| if jungseong == Jungseong::O {
+    ///         Jungseong::U
+    ///     } else {
+    ///         jungseong
+    ///     }),
+    ///     '굼'
+    /// );
+    /// ```
+    pub fn map_jungseong(self, f: impl FnOnce(Jungseong) -> Jungseong) -> Self {
+        self.with_jungseong(f(self.jungseong))
+    }
+
+    /// Returns the syllable right after this syllable in dictionary order, or [`None`] if this
+    /// syllable is the last one, '힣'.
+    ///
+    /// The dictionary order is the code point order as well, so this is the next [`char`] as long
+    /// as it stays within the Precomposed Korean Syllables range.
+    ///
+    /// ```
+    /// use unikorn::Syllable;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert_eq!(Syllable::try_from('가').unwrap().next_in_order().unwrap(), '각');
+    /// assert_eq!(Syllable::try_from('힣').unwrap().next_in_order(), None);
+    /// ```
+    #[doc(alias = "succ")]
+    pub const fn next_in_order(self) -> Option<Self> {
+        Self::from_index(self.dictionary_index() + 1)
+    }
+
+    /// Returns the syllable right before this syllable in dictionary order, or [`None`] if this
+    /// syllable is the first one, '가'; see [`Syllable::next_in_order`].
+    ///
+    /// ```
+    /// use unikorn::Syllable;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert_eq!(Syllable::try_from('나').unwrap().prev_in_order().unwrap(), '낗');
+    /// assert_eq!(Syllable::try_from('가').unwrap().prev_in_order(), None);
+    /// ```
+    #[doc(alias = "pred")]
+    pub const fn prev_in_order(self) -> Option<Self> {
+        match self.dictionary_index() {
+            0 => None,
+            index => Self::from_index(index - 1),
+        }
+    }
+
+    /// Romanizes this syllable by itself, following the Revised Romanization of Korean (국어의
+    /// 로마자 표기법).
+    ///
+    /// The initial consonant takes its value in the initial position, and the final consonant takes
+    /// its value in the final position, e.g. 'ㄱ' is 'g' in '가' but 'k' in '각'. A clustered final
+    /// consonant is spelled out as both of its components, each taking its value in the final
+    /// position as well, e.g. '밝' is 'balk' and '앉' is 'ant'.
+    ///
+    /// As this does not look at the neighbouring syllables, the assimilations across a syllable
+    /// boundary are not applied, and thus romanizing a word syllable by syllable does not always
+    /// match the romanization of the word as a whole, e.g. '신라' is 'Silla', not 'sinra'.
+    ///
+    /// ```
+    /// use unikorn::Syllable;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert_eq!(Syllable::try_from('한').unwrap().romanize_rr(), "han");
+    /// assert_eq!(Syllable::try_from('밝').unwrap().romanize_rr(), "balk");
+    /// assert_eq!(Syllable::try_from('앉').unwrap().romanize_rr(), "ant");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn romanize_rr(self) -> String {
+        let mut romanized = String::from(CHOSEONG_TO_RR[self.choseong as usize]);
+        romanized.push_str(JUNGSEONG_TO_RR[self.jungseong as usize]);
+        if let Some(jongseong) = self.jongseong {
+            romanized.push_str(JONGSEONG_TO_RR[jongseong as usize - 1]);
+        }
+
+        romanized
+    }
+
+    /// Returns a copy of this syllable without the final consonant.
+    ///
+    /// This is a shorthand for [`Syllable::with_jongseong`] with [`None`].
+    pub fn strip_jongseong(self) -> Self {
+        self.with_jongseong(None)
+    }
+
+    /// Converts this syllable into a [`char`]; the same as `char::from`, but usable in a `const`
+    /// context.
+    ///
+    /// ```
+    /// use unikorn::{Choseong, Jungseong, Syllable};
+    ///
+    /// const GA: char = Syllable {
+    ///     choseong: Choseong::Kiyeok,
+    ///     jungseong: Jungseong::A,
+    ///     jongseong: None,
+    /// }
+    /// .to_char();
+    /// assert_eq!(GA, '가');
+    /// ```
+    pub const fn to_char(self) -> char {
+        // all precomposed korean syllables are within BMP, so in this context, it is safe to
+        // assume:
+        //     Unicode Scalar Value == Unicode Code Point
+        char_from_code_point(0xAC00 + self.dictionary_index())
+    }
+
+    /// Encodes this syllable into 2 bytes, i.e. its [dictionary index](Syllable::dictionary_index).
+    ///
+    /// Useful for storing a lot of syllables in a compact manner, taking half the space of a
+    /// [`char`].
+    ///
+    /// ```
+    /// use unikorn::Syllable;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert_eq!(Syllable::try_from('가').unwrap().to_u16(), 0);
+    /// assert_eq!(Syllable::try_from('힣').unwrap().to_u16(), 11171);
+    /// ```
+    pub const fn to_u16(self) -> u16 {
+        self.dictionary_index() as u16
+    }
+
+    /// Composes a run of conjoining jamo (Hangul Jamo range, U+1100 -- U+11FF), i.e. an initial
+    /// consonant, a medial vowel, and optionally a final consonant, into a [`Syllable`], or
+    /// returns [`None`] if the string is anything else, including a longer run.
+    ///
+    /// ```
+    /// use unikorn::Syllable;
+    ///
+    /// assert_eq!(
+    ///     Syllable::try_from_jamo_sequence("\u{1112}\u{1161}\u{11AB}").unwrap(),
+    ///     '한'
+    /// );
+    /// assert_eq!(Syllable::try_from_jamo_sequence("\u{1112}"), None);
+    /// assert_eq!(Syllable::try_from_jamo_sequence("한"), None);
+    /// ```
+    pub fn try_from_jamo_sequence(s: &str) -> Option<Self> {
+        let mut characters = s.chars();
+        let choseong = Choseong::from_conjoining(characters.next()?).ok()?;
+        let jungseong = Jungseong::from_conjoining(characters.next()?).ok()?;
+        let jongseong = match characters.next() {
+            Some(character) => Some(Jongseong::from_conjoining(character).ok()?),
+            None => None,
+        };
+        if characters.next().is_some() {
+            return None;
+        }
+
+        Some(Self::from((choseong, jungseong, jongseong)))
+    }
+
+    /// Does the reverse of [`Syllable::to_u16`], or returns [`None`] if `index` is beyond 11171.
+    ///
+    /// ```
+    /// use unikorn::Syllable;
+    ///
+    /// assert_eq!(Syllable::try_from_u16(1).unwrap(), '각');
+    /// assert_eq!(Syllable::try_from_u16(11172), None);
+    /// ```
+    pub const fn try_from_u16(index: u16) -> Option<Self> {
+        Self::from_index(index as u32)
+    }
+
+    /// Converts a raw Unicode scalar value, e.g. a deserialized [`char`], into a [`Syllable`] the
+    /// same way `TryFrom<char>` does.
+    ///
+    /// Fails with [`Error::NonUnicode`] if `value` is not a valid [`char`] in the first place, and
+    /// with [`Error::NonKorean`] if it is, but not a Precomposed Korean [`Syllable`].
+    ///
+    /// ```
+    /// use unikorn::{Error, Syllable};
+    ///
+    /// assert_eq!(Syllable::try_from_u32(0xAC00).unwrap(), '가');
+    /// assert_eq!(Syllable::try_from_u32(0x41), Err(Error::NonKorean('A', None)));
+    /// assert_eq!(Syllable::try_from_u32(0xD800), Err(Error::NonUnicode(0xD800)));
+    /// ```
+    pub fn try_from_u32(value: u32) -> Result<Self, Error> {
+        char::from_u32(value)
+            .ok_or(Error::NonUnicode(value))
+            .and_then(Self::try_from)
+    }
+
+    /// Returns a copy of this syllable with the initial consonant replaced.
+    ///
+    /// ```
+    /// use unikorn::{Choseong, Syllable};
+    /// use std::convert::TryFrom;
+    ///
+    /// let syllable = Syllable::try_from('밥').unwrap();
+    /// assert_eq!(syllable.with_choseong(Choseong::Pieup), '밥');
+    /// assert_eq!(syllable.with_choseong(Choseong::Ieung), '압');
+    /// ```
+    pub fn with_choseong(self, choseong: Choseong) -> Self {
+        Self { choseong, ..self }
+    }
+
+    /// Returns a copy of this syllable with the final consonant replaced.
+    ///
+    /// ```
+    /// use unikorn::{Jongseong, Syllable};
+    /// use std::convert::TryFrom;
+    ///
+    /// let syllable = Syllable::try_from('닭').unwrap();
+    /// assert_eq!(syllable.with_jongseong(Some(Jongseong::Rieul)), '달');
+    /// assert_eq!(syllable.with_jongseong(None), '다');
+    /// ```
+    pub fn with_jongseong(self, jongseong: Option<Jongseong>) -> Self {
+        Self { jongseong, ..self }
+    }
+
+    /// Returns a copy of this syllable with the medial vowel replaced.
+    ///
+    /// ```
+    /// use unikorn::{Jungseong, Syllable};
+    /// use std::convert::TryFrom;
+    ///
+    /// let syllable = Syllable::try_from('곰').unwrap();
+    /// assert_eq!(syllable.with_jungseong(Jungseong::A), '감');
+    /// ```
+    pub fn with_jungseong(self, jungseong: Jungseong) -> Self {
+        Self { jungseong, ..self }
+    }
+}
+
+/// Decides how a [`Syllable`] is spelled out in the Latin alphabet; see [`io::romanize_reader`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum RomanizationScheme {
+    /// The Revised Romanization of Korean (국어의 로마자 표기법), syllable by syllable; see
+    /// [`Syllable::romanize_rr`].
+    RevisedRomanization,
+}
+impl Default for RomanizationScheme {
+    fn default() -> Self {
+        Self::RevisedRomanization
+    }
+}
+impl RomanizationScheme {
+    /// Romanizes a syllable by itself, following this scheme.
+    ///
+    /// ```
+    /// use unikorn::{RomanizationScheme, Syllable};
+    /// use std::convert::TryFrom;
+    ///
+    /// let syllable = Syllable::try_from('밝').unwrap();
+    /// assert_eq!(RomanizationScheme::RevisedRomanization.romanize(syllable), "balk");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn romanize(self, syllable: Syllable) -> String {
+        match self {
+            Self::RevisedRomanization => syllable.romanize_rr(),
+        }
+    }
+}
+
+/// Classifies the vowels by how far front the tongue is (혀의 앞뒤 위치, Hyeoui Apdwi Wichi); see
+/// [`Jungseong::backness`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum VowelBackness {
+    /// Front vowels (전설모음, Jeonseolmoeum), e.g. 'ㅣ'.
+    Front,
+    /// Central vowels (중설모음, Jungseolmoeum), e.g. 'ㅡ'.
+    Central,
+    /// Back vowels (후설모음, Huseolmoeum), e.g. 'ㅏ'.
+    Back,
+}
+
+/// Classifies the vowels by how high the tongue is (혀의 높낮이, Hyeoui Nopnaji); see
+/// [`Jungseong::height`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum VowelHeight {
+    /// High vowels (고모음, Gomoeum), e.g. 'ㅣ'.
+    High,
+    /// Mid vowels (중모음, Jungmoeum), e.g. 'ㅔ'.
+    Mid,
+    /// Low vowels (저모음, Jeomoeum), e.g. 'ㅏ'.
+    Low,
+}
+
+// converts a code point known to be a Unicode scalar value into a `char`, in a `const` context
+// where `char::from_u32()` is not available until Rust 1.67.
+#[allow(unknown_lints, unnecessary_transmutes)]
+const fn char_from_code_point(code_point: u32) -> char {
+    // SAFETY: every caller passes a code point within the Hangul Jamo, the Hangul Compatibility
+    // Jamo, or the Precomposed Korean Syllables range, none of which overlaps the surrogates.
+    unsafe { core::mem::transmute::<u32, char>(code_point) }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Choseong, Error, Jaeum, Jongseong, Jungseong, Syllable};
-    use std::convert::TryFrom;
+    use super::{
+        Choseong, Error, Jaeum, Jongseong, Jungseong, Moeum, Syllable, VowelBackness, VowelHeight,
+    };
+    use core::convert::TryFrom;
+
+    #[test]
+    fn test_from_choseong_for_char() {
+        assert_eq!(char::from(Choseong::Ieung), 'ㅇ');
+        assert_eq!(char::from(Choseong::Cieuc), 'ㅈ');
+    }
+
+    #[test]
+    fn test_fromstr_for_choseong() {
+        assert_eq!("Kiyeok".parse::<Choseong>(), Ok(Choseong::Kiyeok));
+        assert_eq!("ㄱ".parse::<Choseong>(), Ok(Choseong::Kiyeok));
+        assert_eq!("SsangKiyeok".parse::<Choseong>(), Ok(Choseong::SsangKiyeok));
+        assert_eq!("ㄲ".parse::<Choseong>(), Ok(Choseong::SsangKiyeok));
+
+        // case, separators, code points, and the conjoining form
+        assert_eq!(
+            "ssang-kiyeok".parse::<Choseong>(),
+            Ok(Choseong::SsangKiyeok)
+        );
+        assert_eq!(
+            "SSANG_KIYEOK".parse::<Choseong>(),
+            Ok(Choseong::SsangKiyeok)
+        );
+        assert_eq!("U+1100".parse::<Choseong>(), Ok(Choseong::Kiyeok));
+        assert_eq!("u+3131".parse::<Choseong>(), Ok(Choseong::Kiyeok));
+        assert_eq!("\u{1100}".parse::<Choseong>(), Ok(Choseong::Kiyeok));
+
+        // near misses, suggested only with `alloc`
+        #[cfg(feature = "alloc")]
+        {
+            assert_eq!(
+                "sang-kiyeok".parse::<Choseong>(),
+                Err(Error::UnknownJamoName(Some("SsangKiyeok")))
+            );
+            assert_eq!(
+                "Kieuk".parse::<Choseong>(),
+                Err(Error::UnknownJamoName(Some("Kiyeok")))
+            );
+            assert_eq!(
+                "Kieuk".parse::<Choseong>().unwrap_err().to_string(),
+                "unknown name of a consonant or vowel; did you mean Kiyeok?"
+            );
+        }
+        #[cfg(not(feature = "alloc"))]
+        assert_eq!(
+            "Kieuk".parse::<Choseong>(),
+            Err(Error::UnknownJamoName(None))
+        );
+
+        assert_eq!(
+            "U+3133".parse::<Choseong>(),
+            Err(Error::NotApplicableToChoseong(Jaeum::KiyeokSios))
+        );
+        assert_eq!("U+0041".parse::<Choseong>(), Err(Error::NonJamo('A')));
+        assert_eq!("U+".parse::<Choseong>(), Err(Error::UnknownJamoName(None)));
+        assert_eq!(
+            "U++1100".parse::<Choseong>(),
+            Err(Error::UnknownJamoName(None))
+        );
+        assert_eq!(
+            "garbage".parse::<Choseong>(),
+            Err(Error::UnknownJamoName(None))
+        );
+        assert_eq!("".parse::<Choseong>(), Err(Error::UnknownJamoName(None)));
+        assert_eq!(
+            "ㄳ".parse::<Choseong>(),
+            Err(Error::NotApplicableToChoseong(Jaeum::KiyeokSios))
+        );
+        assert_eq!(
+            "KiyeokSios".parse::<Choseong>(),
+            Err(Error::UnknownJamoName(None))
+        );
+    }
+
+    #[test]
+    fn test_tryfrom_char_for_choseong() {
+        assert_eq!(
+            Choseong::try_from('ㄵ'),
+            Err(Error::NotApplicableToChoseong(Jaeum::NieunCieuc))
+        );
+        assert_eq!(
+            Choseong::try_from('ㄺ'),
+            Err(Error::NotApplicableToChoseong(Jaeum::RieulKiyeok))
+        );
+
+        assert_eq!(Choseong::try_from('ㅈ'), Ok(Choseong::Cieuc));
+        assert_eq!(Choseong::try_from('ㅅ'), Ok(Choseong::Sios));
+    }
+
+    #[test]
+    fn test_tryfrom_jaeum_for_choseong() {
+        assert_eq!(
+            Choseong::try_from(Jaeum::KiyeokSios),
+            Err(Error::NotApplicableToChoseong(Jaeum::KiyeokSios))
+        );
+        assert_eq!(
+            Choseong::try_from(Jaeum::RieulPhieuph),
+            Err(Error::NotApplicableToChoseong(Jaeum::RieulPhieuph))
+        );
+
+        assert_eq!(
+            Choseong::try_from(Jaeum::SsangPieup),
+            Ok(Choseong::SsangPieup)
+        );
+        assert_eq!(
+            Choseong::try_from(Jaeum::SsangTikeut),
+            Ok(Choseong::SsangTikeut)
+        );
+    }
+
+    #[test]
+    fn test_choseong_all() {
+        assert_eq!(Choseong::ALL.len(), Choseong::COUNT);
+        assert!(Choseong::ALL
+            .iter()
+            .copied()
+            .eq((0..19u8).map(|index| Choseong::try_from(index).unwrap())));
+        assert!(Choseong::iter().eq(Choseong::ALL.iter().copied()));
+        assert_eq!(Choseong::iter().len(), Choseong::COUNT);
+        assert_eq!(Choseong::iter().next_back(), Choseong::ALL.last().copied());
+    }
+
+    #[test]
+    fn test_choseong_can_be_jongseong() {
+        assert!(Choseong::Kiyeok.can_be_jongseong());
+        assert!(Choseong::SsangKiyeok.can_be_jongseong());
+        assert!(Choseong::SsangSios.can_be_jongseong());
+        assert!(Choseong::Hieuh.can_be_jongseong());
+
+        assert!(!Choseong::SsangTikeut.can_be_jongseong());
+        assert!(!Choseong::SsangPieup.can_be_jongseong());
+        assert!(!Choseong::SsangCieuc.can_be_jongseong());
+
+        for index in 0..19 {
+            let choseong = Choseong::try_from(index).unwrap();
+            assert_eq!(
+                choseong.can_be_jongseong(),
+                Jongseong::try_from(Jaeum::from(choseong)).is_ok()
+            );
+        }
+    }
+
+    #[test]
+    fn test_choseong_from_conjoining() {
+        assert_eq!(Choseong::from_conjoining('ᄀ'), Ok(Choseong::Kiyeok));
+        assert_eq!(Choseong::from_conjoining('ᄒ'), Ok(Choseong::Hieuh));
+
+        assert_eq!(
+            Choseong::from_conjoining('ㄱ'),
+            Err(Error::NonConjoiningJamo('ㄱ'))
+        );
+        assert_eq!(
+            Choseong::from_conjoining('ᄓ'),
+            Err(Error::NonConjoiningJamo('ᄓ'))
+        );
+    }
+
+    #[test]
+    fn test_choseong_from_modern_index() {
+        assert_eq!(Choseong::from_modern_index(0), Ok(Choseong::Kiyeok));
+        for (index, choseong) in Choseong::iter().enumerate() {
+            assert_eq!(Choseong::from_modern_index(index as u8), Ok(choseong));
+        }
+        for index in 19..=u8::MAX {
+            assert_eq!(
+                Choseong::from_modern_index(index),
+                Err(Error::NonModernIndex(index))
+            );
+        }
+    }
+
+    #[test]
+    fn test_choseong_is_aspirated() {
+        assert!(Choseong::Chieuch.is_aspirated());
+        assert!(Choseong::Khieukh.is_aspirated());
+        assert!(Choseong::Thieuth.is_aspirated());
+        assert!(Choseong::Phieuph.is_aspirated());
+
+        assert!(!Choseong::Kiyeok.is_aspirated());
+        assert!(!Choseong::SsangTikeut.is_aspirated());
+        assert!(!Choseong::Hieuh.is_aspirated());
+
+        const ASPIRATED: bool = Choseong::Phieuph.is_aspirated();
+        assert!(ASPIRATED);
+    }
+
+    #[test]
+    fn test_choseong_is_tense() {
+        assert!(Choseong::SsangKiyeok.is_tense());
+        assert!(Choseong::SsangTikeut.is_tense());
+        assert!(Choseong::SsangPieup.is_tense());
+        assert!(Choseong::SsangSios.is_tense());
+        assert!(Choseong::SsangCieuc.is_tense());
+
+        assert!(!Choseong::Kiyeok.is_tense());
+        assert!(!Choseong::Khieukh.is_tense());
+        assert!(!Choseong::Ieung.is_tense());
+    }
+
+    #[test]
+    fn test_choseong_composition_order() {
+        assert_eq!(Choseong::COUNT, 19);
+        assert!(Choseong::try_from(Choseong::COUNT as u8).is_err());
+
+        // the variants must follow the order of Hangul Compatibility Jamo, and composing each of
+        // them with a fixed medial vowel must step through the precomposed syllables in order,
+        // 21 * 28 code points at a time.
+        for index in 0..Choseong::COUNT {
+            let choseong = Choseong::try_from(index as u8).unwrap();
+            if index > 0 {
+                let previous = Choseong::try_from(index as u8 - 1).unwrap();
+                assert!(Jaeum::from(previous) < Jaeum::from(choseong));
+            }
+            assert_eq!(
+                char::from(Syllable::from((choseong, Jungseong::A))) as usize,
+                0xAC00 + index * 21 * 28,
+                "{:?}",
+                choseong
+            );
+        }
+    }
+
+    #[test]
+    fn test_choseong_name() {
+        assert_eq!(Choseong::Kiyeok.name(), "Kiyeok");
+        assert_eq!(Choseong::Hieuh.name(), "Hieuh");
+
+        for index in 0..19 {
+            let choseong = Choseong::try_from(index).unwrap();
+            assert_eq!(choseong.name().parse(), Ok(choseong));
+            assert_eq!(format!("{:?}", choseong), choseong.name());
+        }
+    }
+
+    #[test]
+    fn test_choseong_to_conjoining() {
+        assert_eq!(Choseong::Kiyeok.to_conjoining(), 'ᄀ');
+        assert_eq!(Choseong::Hieuh.to_conjoining(), 'ᄒ');
+    }
+
+    #[test]
+    fn test_choseong_to_jaeum_lossy() {
+        for choseong in Choseong::iter() {
+            assert_eq!(choseong.to_jaeum_lossy(), Some(Jaeum::from(choseong)));
+        }
+    }
+
+    #[test]
+    fn test_choseong_to_modern_index() {
+        assert_eq!(Choseong::Kiyeok.to_modern_index(), Some(0));
+        for choseong in Choseong::iter() {
+            let index = choseong.to_modern_index().unwrap();
+            assert_eq!(Choseong::ALL[index as usize], choseong);
+            assert_eq!(Choseong::from_modern_index(index), Ok(choseong));
+        }
+    }
+
+    #[test]
+    fn test_choseong_without_aspiration() {
+        for choseong in Choseong::iter() {
+            let plain = choseong.without_aspiration();
+            assert!(!plain.is_aspirated());
+            assert_eq!(
+                plain == choseong,
+                !choseong.is_aspirated(),
+                "{:?}",
+                choseong
+            );
+        }
+        assert_eq!(Choseong::Thieuth.without_aspiration(), Choseong::Tikeut);
+        assert_eq!(
+            Choseong::SsangCieuc.without_aspiration(),
+            Choseong::SsangCieuc
+        );
+    }
+
+    #[test]
+    fn test_choseong_without_tension() {
+        for choseong in Choseong::iter() {
+            let plain = choseong.without_tension();
+            assert!(!plain.is_tense());
+            assert_eq!(plain == choseong, !choseong.is_tense(), "{:?}", choseong);
+        }
+        assert_eq!(Choseong::SsangPieup.without_tension(), Choseong::Pieup);
+        assert_eq!(Choseong::Phieuph.without_tension(), Choseong::Phieuph);
+    }
+
+    #[test]
+    fn test_display_for_error() {
+        assert_eq!(
+            Choseong::try_from('ㄳ').unwrap_err().to_string(),
+            "'ㄳ' is not valid as an initial consonant (it is a valid consonant, but only usable \
+             as a final consonant)"
+        );
+        assert_eq!(
+            Jongseong::try_from('ㄸ').unwrap_err().to_string(),
+            "'ㄸ' is not valid as a final consonant (it is a valid consonant, but only usable as \
+             an initial consonant)"
+        );
+        assert_eq!(
+            Choseong::from_modern_index(19).unwrap_err().to_string(),
+            "19 is not an index of a modern consonant or vowel"
+        );
+    }
+
+    #[test]
+    fn test_display_for_error_every_variant() {
+        // pins down the message of every variant, so that it stays the same however `Display`
+        // is implemented.
+        for (error, message) in [
+            (
+                Error::NonConjoiningJamo('ㄱ'),
+                "'ㄱ' is not a modern conjoining Hangul Jamo",
+            ),
+            (
+                Error::NonEucKr(0xA1A1),
+                "0xA1A1 is not a Korean EUC-KR code",
+            ),
+            (
+                Error::NonJamo('a'),
+                "'a' is not a Hangul Compatibility Jamo",
+            ),
+            (Error::NonJohab(0x8441), "0x8441 is not a Korean Johab code"),
+            (
+                Error::NonKorean('a', None),
+                "'a' is not a Precomposed Korean Sylable",
+            ),
+            (
+                Error::NonKorean('a', Some(3)),
+                "'a' at byte 3 is not a Precomposed Korean Sylable",
+            ),
+            (
+                Error::NonKoreanUtf8([0xE3, 0x84, 0xB1]),
+                "[E3, 84, B1] is not a Precomposed Korean Sylable in UTF-8",
+            ),
+            (
+                Error::NonModernIndex(27),
+                "27 is not an index of a modern consonant or vowel",
+            ),
+            (
+                Error::NonUnicode(0xD800),
+                "0xD800 is not a Unicode scalar value",
+            ),
+            (
+                Error::NotApplicableToChoseong(Jaeum::KiyeokSios),
+                "'ㄳ' is not valid as an initial consonant (it is a valid consonant, but only \
+                 usable as a final consonant)",
+            ),
+            (
+                Error::NotApplicableToJongseong(Jaeum::SsangTikeut),
+                "'ㄸ' is not valid as a final consonant (it is a valid consonant, but only usable \
+                 as an initial consonant)",
+            ),
+            (
+                Error::NotRepresentableInEucKr('é'),
+                "'é' cannot be represented in EUC-KR",
+            ),
+            (
+                Error::NotRepresentableInJohab('é'),
+                "'é' cannot be represented in Johab",
+            ),
+            (
+                Error::UnknownJamoName(None),
+                "unknown name of a consonant or vowel",
+            ),
+            (
+                Error::UnknownJamoName(Some("SsangKiyeok")),
+                "unknown name of a consonant or vowel; did you mean SsangKiyeok?",
+            ),
+        ] {
+            assert_eq!(error.to_string(), message);
+        }
+    }
+
+    #[test]
+    fn test_error_is_wrong_category() {
+        // a consonant in a vowel slot, and the other way around.
+        assert!(Jungseong::try_from('ㄱ').unwrap_err().is_wrong_category());
+        assert!(Jungseong::try_from('ㅎ').unwrap_err().is_wrong_category());
+        assert!(Jaeum::try_from('ㅏ').unwrap_err().is_wrong_category());
+        assert!(Choseong::try_from('ㅣ').unwrap_err().is_wrong_category());
+        assert!(Jongseong::try_from('ㅘ').unwrap_err().is_wrong_category());
+        assert!(Jungseong::from_conjoining('\u{1100}')
+            .unwrap_err()
+            .is_wrong_category());
+        assert!(Choseong::from_conjoining('\u{1161}')
+            .unwrap_err()
+            .is_wrong_category());
+
+        // not Korean at all.
+        for character in ['a', '1', ' ', '가', '\u{3164}', '\u{1113}'] {
+            assert!(!Jungseong::try_from(character)
+                .unwrap_err()
+                .is_wrong_category());
+            assert!(!Jaeum::try_from(character).unwrap_err().is_wrong_category());
+        }
+        assert!(!Jungseong::from_conjoining('\u{1176}')
+            .unwrap_err()
+            .is_wrong_category());
+
+        // a conjoining consonant in a vowel slot, through either conversion.
+        assert!(Jungseong::try_from('\u{1100}')
+            .unwrap_err()
+            .is_wrong_category());
+        assert!(Jungseong::try_from('\u{11A8}')
+            .unwrap_err()
+            .is_wrong_category());
+        assert!(Jaeum::try_from('\u{1161}').unwrap_err().is_wrong_category());
+
+        // the right kind in the wrong position.
+        assert!(!Choseong::try_from('ㄳ').unwrap_err().is_wrong_category());
+        assert!(!Jongseong::try_from('ㄸ').unwrap_err().is_wrong_category());
+        assert!(!Error::NonKorean('ㄱ', None).is_wrong_category());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_error_source() {
+        let error = Choseong::try_from('ㄳ').unwrap_err();
+        assert_eq!(
+            std::error::Error::source(&error).unwrap().to_string(),
+            "it is a valid consonant, but only usable as a final consonant"
+        );
+        let error = Jongseong::try_from('ㄸ').unwrap_err();
+        assert_eq!(
+            std::error::Error::source(&error).unwrap().to_string(),
+            "it is a valid consonant, but only usable as an initial consonant"
+        );
+
+        assert!(std::error::Error::source(&Error::NonJamo('a')).is_none());
+        assert!(std::error::Error::source(&Error::NonKorean('a', None)).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_error_for_io_error() {
+        let error = std::io::Error::from(Syllable::try_from('a').unwrap_err());
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains('a'));
+        assert_eq!(error.to_string(), Error::NonKorean('a', None).to_string());
+    }
+
+    #[test]
+    fn test_from_choseong_for_jaeum() {
+        assert_eq!(Jaeum::from(Choseong::Thieuth), Jaeum::Thieuth);
+        assert_eq!(Jaeum::from(Choseong::Kiyeok), Jaeum::Kiyeok);
+    }
+
+    #[test]
+    fn test_from_jaeum_for_char() {
+        assert_eq!(char::from(Jaeum::KiyeokSios), 'ㄳ');
+        assert_eq!(char::from(Jaeum::Hieuh), 'ㅎ');
+    }
+
+    #[test]
+    fn test_from_jongseong_for_jaeum() {
+        assert_eq!(Jaeum::from(Jongseong::PieupSios), Jaeum::PieupSios);
+        assert_eq!(Jaeum::from(Jongseong::Hieuh), Jaeum::Hieuh);
+    }
+
+    #[test]
+    fn test_fromstr_for_jaeum() {
+        assert_eq!("Kiyeok".parse::<Jaeum>(), Ok(Jaeum::Kiyeok));
+        assert_eq!("ㄱ".parse::<Jaeum>(), Ok(Jaeum::Kiyeok));
+        assert_eq!("KiyeokSios".parse::<Jaeum>(), Ok(Jaeum::KiyeokSios));
+        assert_eq!("ㄳ".parse::<Jaeum>(), Ok(Jaeum::KiyeokSios));
+
+        assert_eq!(
+            "garbage".parse::<Jaeum>(),
+            Err(Error::UnknownJamoName(None))
+        );
+        assert_eq!("".parse::<Jaeum>(), Err(Error::UnknownJamoName(None)));
+        assert_eq!("ㅏ".parse::<Jaeum>(), Err(Error::NonJamo('ㅏ')));
+        assert_eq!("U+1100".parse::<Jaeum>(), Err(Error::NonJamo('\u{1100}')));
+        assert_eq!("pieup-sios".parse::<Jaeum>(), Ok(Jaeum::PieupSios));
+    }
+
+    #[test]
+    fn test_tryfrom_char_for_jaeum() {
+        assert_eq!(Jaeum::try_from('^'), Err(Error::NonJamo('^')));
+        assert_eq!(Jaeum::try_from('v'), Err(Error::NonJamo('v')));
+        assert_eq!(Jaeum::try_from('ㅗ'), Err(Error::NonJamo('ㅗ')));
+
+        assert_eq!(Jaeum::try_from('ㄱ'), Ok(Jaeum::Kiyeok));
+        assert_eq!(Jaeum::try_from('ㅄ'), Ok(Jaeum::PieupSios));
+        assert_eq!(Jaeum::try_from('ㄸ'), Ok(Jaeum::SsangTikeut));
+    }
+
+    #[test]
+    fn test_jaeum_all() {
+        assert_eq!(Jaeum::ALL.len(), Jaeum::COUNT);
+        assert_eq!(Jaeum::ALL[0], Jaeum::Kiyeok);
+        assert!(Jaeum::ALL
+            .iter()
+            .copied()
+            .eq((0..30u8).map(|index| Jaeum::try_from(index).unwrap())));
+        assert!(Jaeum::iter().eq(Jaeum::ALL.iter().copied()));
+        assert_eq!(Jaeum::iter().len(), Jaeum::COUNT);
+        assert_eq!(Jaeum::iter().next_back(), Some(Jaeum::Hieuh));
+
+        // the vowels, for the rest of a jamo palette.
+        assert_eq!(Moeum::ALL.len(), 21);
+        assert_eq!(Moeum::ALL[0], Moeum::A);
+    }
+
+    #[test]
+    fn test_jaeum_modern() {
+        assert_eq!(Jaeum::MODERN.len(), Jaeum::COUNT);
+        assert_eq!(Jaeum::MODERN[0], Jaeum::Kiyeok);
+        assert!(Jaeum::iter().eq(Jaeum::MODERN.iter().copied()));
+    }
+
+    #[test]
+    fn test_jaeum_keyboard_order() {
+        assert_eq!(Jaeum::KEYBOARD_ORDER[0], Jaeum::Pieup);
+        assert!(Jaeum::iter().all(|jaeum| Jaeum::KEYBOARD_ORDER.contains(&jaeum)));
+
+        // the keys first, in the keyboard order, then the clusters typed with two keys.
+        let keys: String = Jaeum::KEYBOARD_ORDER
+            .iter()
+            .map(|jaeum| jaeum.dubeolsik_key())
+            .take_while(Option::is_some)
+            .flatten()
+            .collect();
+        assert_eq!(keys, "qwertasdfgzxcvQWERT");
+        assert!(Jaeum::KEYBOARD_ORDER[keys.len()..]
+            .iter()
+            .all(|jaeum| jaeum.dubeolsik_key().is_none()));
+    }
+
+    #[test]
+    fn test_jaeum_can_be_choseong() {
+        assert!(Jaeum::Kiyeok.can_be_choseong());
+        assert!(Jaeum::SsangCieuc.can_be_choseong());
+
+        assert!(!Jaeum::KiyeokSios.can_be_choseong());
+        assert!(!Jaeum::RieulHieuh.can_be_choseong());
+    }
+
+    #[test]
+    fn test_jaeum_can_be_jongseong() {
+        assert!(Jaeum::Kiyeok.can_be_jongseong());
+        assert!(Jaeum::KiyeokSios.can_be_jongseong());
+
+        assert!(!Jaeum::SsangTikeut.can_be_jongseong());
+        assert!(!Jaeum::SsangPieup.can_be_jongseong());
+        assert!(!Jaeum::SsangCieuc.can_be_jongseong());
+    }
+
+    #[test]
+    fn test_jaeum_dubeolsik_key() {
+        assert_eq!(Jaeum::Kiyeok.dubeolsik_key(), Some('r'));
+        assert_eq!(Jaeum::SsangKiyeok.dubeolsik_key(), Some('R'));
+        assert_eq!(Jaeum::Hieuh.dubeolsik_key(), Some('g'));
+        assert_eq!(Jaeum::PieupSios.dubeolsik_key(), None);
+
+        // every key is taken by at most one consonant, and only the tense ones take Shift.
+        let keys = (0..30)
+            .filter_map(|index| Jaeum::try_from(index).unwrap().dubeolsik_key())
+            .collect::<Vec<_>>();
+        assert_eq!(keys.len(), 19);
+        assert_eq!(
+            keys.iter().filter(|key| key.is_ascii_uppercase()).count(),
+            5
+        );
+        assert!(keys
+            .iter()
+            .all(|key| keys.iter().filter(|&other| other == key).count() == 1));
+    }
+
+    #[test]
+    fn test_jaeum_composition_order() {
+        assert_eq!(Jaeum::COUNT, 30);
+        assert!(Jaeum::try_from(Jaeum::COUNT as u8).is_err());
+
+        for index in 0..Jaeum::COUNT {
+            let jaeum = Jaeum::try_from(index as u8).unwrap();
+            assert_eq!(char::from(jaeum) as usize, 0x3131 + index, "{:?}", jaeum);
+        }
+    }
+
+    #[test]
+    fn test_jaeum_name() {
+        assert_eq!(Jaeum::Kiyeok.name(), "Kiyeok");
+        assert_eq!(Jaeum::PieupSios.name(), "PieupSios");
+
+        for index in 0..30 {
+            let jaeum = Jaeum::try_from(index).unwrap();
+            assert_eq!(jaeum.name().parse(), Ok(jaeum));
+            assert_eq!(format!("{:?}", jaeum), jaeum.name());
+        }
+    }
+
+    #[test]
+    fn test_jaeum_to_choseong() {
+        assert_eq!(
+            Jaeum::SsangTikeut.to_choseong(),
+            Some(Choseong::SsangTikeut)
+        );
+        assert_eq!(Jaeum::PieupSios.to_choseong(), None);
+    }
+
+    #[test]
+    fn test_jaeum_to_jongseong() {
+        assert_eq!(Jaeum::SsangTikeut.to_jongseong(), None);
+        assert_eq!(Jaeum::PieupSios.to_jongseong(), Some(Jongseong::PieupSios));
+    }
+
+    #[test]
+    fn test_from_jongseong_for_char() {
+        assert_eq!(char::from(Jongseong::KiyeokSios), 'ㄳ');
+        assert_eq!(char::from(Jongseong::RieulHieuh), 'ㅀ');
+        assert_eq!(char::from(Jongseong::Sios), 'ㅅ');
+    }
+
+    #[test]
+    fn test_fromstr_for_jongseong() {
+        assert_eq!("Kiyeok".parse::<Jongseong>(), Ok(Jongseong::Kiyeok));
+        assert_eq!("ㄱ".parse::<Jongseong>(), Ok(Jongseong::Kiyeok));
+        assert_eq!("Hieuh".parse::<Jongseong>(), Ok(Jongseong::Hieuh));
+        assert_eq!("ㅎ".parse::<Jongseong>(), Ok(Jongseong::Hieuh));
+
+        assert_eq!(
+            "garbage".parse::<Jongseong>(),
+            Err(Error::UnknownJamoName(None))
+        );
+        assert_eq!("".parse::<Jongseong>(), Err(Error::UnknownJamoName(None)));
+        assert_eq!(
+            "ㄸ".parse::<Jongseong>(),
+            Err(Error::NotApplicableToJongseong(Jaeum::SsangTikeut))
+        );
+        assert_eq!(
+            "SsangTikeut".parse::<Jongseong>(),
+            Err(Error::UnknownJamoName(None))
+        );
+
+        assert_eq!("U+11A8".parse::<Jongseong>(), Ok(Jongseong::Kiyeok));
+        assert_eq!(
+            "rieul kiyeok".parse::<Jongseong>(),
+            Ok(Jongseong::RieulKiyeok)
+        );
+    }
+
+    #[test]
+    fn test_tryfrom_char_for_jongseong() {
+        assert_eq!(Jongseong::try_from('ㅗ'), Err(Error::NonJamo('ㅗ')));
+        assert_eq!(Jongseong::try_from('v'), Err(Error::NonJamo('v')));
+
+        assert_eq!(Jongseong::try_from('ㅄ'), Ok(Jongseong::PieupSios));
+        assert_eq!(Jongseong::try_from('ㅎ'), Ok(Jongseong::Hieuh));
+    }
+
+    #[test]
+    fn test_tryfrom_jaeum_for_jongseong() {
+        // These three are the only possible instances of error in this context.
+        assert_eq!(
+            Jongseong::try_from(Jaeum::SsangTikeut),
+            Err(Error::NotApplicableToJongseong(Jaeum::SsangTikeut))
+        );
+        assert_eq!(
+            Jongseong::try_from(Jaeum::SsangPieup),
+            Err(Error::NotApplicableToJongseong(Jaeum::SsangPieup))
+        );
+        assert_eq!(
+            Jongseong::try_from(Jaeum::SsangCieuc),
+            Err(Error::NotApplicableToJongseong(Jaeum::SsangCieuc))
+        );
+
+        assert_eq!(
+            Jongseong::try_from(Jaeum::PieupSios),
+            Ok(Jongseong::PieupSios)
+        );
+        assert_eq!(Jongseong::try_from(Jaeum::Hieuh), Ok(Jongseong::Hieuh));
+    }
+
+    #[test]
+    fn test_tryfrom_char_error_matrix() {
+        // every consonant in Hangul Compatibility Jamo range, along with what to expect when
+        // converted into an initial consonant and a final consonant respectively. a consonant
+        // not applicable to a position must say so, rather than claiming it is not a jamo at all.
+        let matrix = [
+            ('ㄱ', Ok(Choseong::Kiyeok), Ok(Jongseong::Kiyeok)),
+            ('ㄲ', Ok(Choseong::SsangKiyeok), Ok(Jongseong::SsangKiyeok)),
+            (
+                'ㄳ',
+                Err(Error::NotApplicableToChoseong(Jaeum::KiyeokSios)),
+                Ok(Jongseong::KiyeokSios),
+            ),
+            ('ㄴ', Ok(Choseong::Nieun), Ok(Jongseong::Nieun)),
+            (
+                'ㄵ',
+                Err(Error::NotApplicableToChoseong(Jaeum::NieunCieuc)),
+                Ok(Jongseong::NieunCieuc),
+            ),
+            (
+                'ㄶ',
+                Err(Error::NotApplicableToChoseong(Jaeum::NieunHieuh)),
+                Ok(Jongseong::NieunHieuh),
+            ),
+            ('ㄷ', Ok(Choseong::Tikeut), Ok(Jongseong::Tikeut)),
+            (
+                'ㄸ',
+                Ok(Choseong::SsangTikeut),
+                Err(Error::NotApplicableToJongseong(Jaeum::SsangTikeut)),
+            ),
+            ('ㄹ', Ok(Choseong::Rieul), Ok(Jongseong::Rieul)),
+            (
+                'ㄺ',
+                Err(Error::NotApplicableToChoseong(Jaeum::RieulKiyeok)),
+                Ok(Jongseong::RieulKiyeok),
+            ),
+            (
+                'ㄻ',
+                Err(Error::NotApplicableToChoseong(Jaeum::RieulMieum)),
+                Ok(Jongseong::RieulMieum),
+            ),
+            (
+                'ㄼ',
+                Err(Error::NotApplicableToChoseong(Jaeum::RieulPieup)),
+                Ok(Jongseong::RieulPieup),
+            ),
+            (
+                'ㄽ',
+                Err(Error::NotApplicableToChoseong(Jaeum::RieulSios)),
+                Ok(Jongseong::RieulSios),
+            ),
+            (
+                'ㄾ',
+                Err(Error::NotApplicableToChoseong(Jaeum::RieulThieuth)),
+                Ok(Jongseong::RieulThieuth),
+            ),
+            (
+                'ㄿ',
+                Err(Error::NotApplicableToChoseong(Jaeum::RieulPhieuph)),
+                Ok(Jongseong::RieulPhieuph),
+            ),
+            (
+                'ㅀ',
+                Err(Error::NotApplicableToChoseong(Jaeum::RieulHieuh)),
+                Ok(Jongseong::RieulHieuh),
+            ),
+            ('ㅁ', Ok(Choseong::Mieum), Ok(Jongseong::Mieum)),
+            ('ㅂ', Ok(Choseong::Pieup), Ok(Jongseong::Pieup)),
+            (
+                'ㅃ',
+                Ok(Choseong::SsangPieup),
+                Err(Error::NotApplicableToJongseong(Jaeum::SsangPieup)),
+            ),
+            (
+                'ㅄ',
+                Err(Error::NotApplicableToChoseong(Jaeum::PieupSios)),
+                Ok(Jongseong::PieupSios),
+            ),
+            ('ㅅ', Ok(Choseong::Sios), Ok(Jongseong::Sios)),
+            ('ㅆ', Ok(Choseong::SsangSios), Ok(Jongseong::SsangSios)),
+            ('ㅇ', Ok(Choseong::Ieung), Ok(Jongseong::Ieung)),
+            ('ㅈ', Ok(Choseong::Cieuc), Ok(Jongseong::Cieuc)),
+            (
+                'ㅉ',
+                Ok(Choseong::SsangCieuc),
+                Err(Error::NotApplicableToJongseong(Jaeum::SsangCieuc)),
+            ),
+            ('ㅊ', Ok(Choseong::Chieuch), Ok(Jongseong::Chieuch)),
+            ('ㅋ', Ok(Choseong::Khieukh), Ok(Jongseong::Khieukh)),
+            ('ㅌ', Ok(Choseong::Thieuth), Ok(Jongseong::Thieuth)),
+            ('ㅍ', Ok(Choseong::Phieuph), Ok(Jongseong::Phieuph)),
+            ('ㅎ', Ok(Choseong::Hieuh), Ok(Jongseong::Hieuh)),
+        ];
+        for (character, choseong, jongseong) in matrix.iter() {
+            assert_eq!(Choseong::try_from(*character), *choseong, "{}", character);
+            assert_eq!(Jongseong::try_from(*character), *jongseong, "{}", character);
+        }
+
+        for character in ['ㅏ', 'ᄀ', 'ᆨ', '가', 'a'].iter() {
+            assert_eq!(
+                Choseong::try_from(*character),
+                Err(Error::NonJamo(*character))
+            );
+            assert_eq!(
+                Jongseong::try_from(*character),
+                Err(Error::NonJamo(*character))
+            );
+        }
+    }
+
+    #[test]
+    fn test_jongseong_all() {
+        assert_eq!(Jongseong::ALL.len(), Jongseong::COUNT);
+        assert!(Jongseong::ALL
+            .iter()
+            .copied()
+            .eq((1..=27u8).map(|index| Jongseong::try_from(index).unwrap())));
+        assert!(Jongseong::iter().eq(Jongseong::ALL.iter().copied()));
+        assert_eq!(Jongseong::iter().len(), Jongseong::COUNT);
+        assert_eq!(
+            Jongseong::iter().next_back(),
+            Jongseong::ALL.last().copied()
+        );
+    }
+
+    #[test]
+    fn test_jongseong_can_be_choseong() {
+        assert!(Jongseong::Kiyeok.can_be_choseong());
+        assert!(Jongseong::SsangSios.can_be_choseong());
+        assert!(Jongseong::Hieuh.can_be_choseong());
+
+        assert!(!Jongseong::KiyeokSios.can_be_choseong());
+        assert!(!Jongseong::RieulHieuh.can_be_choseong());
+
+        for index in 1..28 {
+            let jongseong = Jongseong::try_from(index).unwrap();
+            assert_eq!(
+                jongseong.can_be_choseong(),
+                Choseong::try_from(Jaeum::from(jongseong)).is_ok()
+            );
+        }
+    }
+
+    #[test]
+    fn test_jongseong_components() {
+        assert_eq!(
+            Jongseong::RieulPhieuph.components(),
+            Some((Jongseong::Rieul, Jongseong::Phieuph))
+        );
+        assert_eq!(Jongseong::SsangSios.components(), None);
+        assert_eq!(Jongseong::Hieuh.components(), None);
+
+        for jongseong in Jongseong::iter() {
+            // exactly the clusters are unable to be placed in the initial consonant position.
+            assert_eq!(
+                jongseong.components().is_some(),
+                !jongseong.can_be_choseong(),
+                "{:?}",
+                jongseong
+            );
+            if let Some((first, second)) = jongseong.components() {
+                assert_eq!(first.components(), None);
+                assert_eq!(second.components(), None);
+                assert_eq!(
+                    jongseong.resyllabify(),
+                    (Some(first), second.phonetically_equivalent_choseong())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_jongseong_from_conjoining() {
+        assert_eq!(Jongseong::from_conjoining('ᆨ'), Ok(Jongseong::Kiyeok));
+        assert_eq!(Jongseong::from_conjoining('ᇂ'), Ok(Jongseong::Hieuh));
+
+        assert_eq!(
+            Jongseong::from_conjoining('ᄀ'),
+            Err(Error::NonConjoiningJamo('ᄀ'))
+        );
+        assert_eq!(
+            Jongseong::from_conjoining('ᇃ'),
+            Err(Error::NonConjoiningJamo('ᇃ'))
+        );
+    }
+
+    #[test]
+    fn test_jongseong_from_modern_index() {
+        assert_eq!(Jongseong::from_modern_index(0), Ok(Jongseong::Kiyeok));
+        for (index, jongseong) in Jongseong::iter().enumerate() {
+            assert_eq!(Jongseong::from_modern_index(index as u8), Ok(jongseong));
+        }
+        for index in 27..=u8::MAX {
+            assert_eq!(
+                Jongseong::from_modern_index(index),
+                Err(Error::NonModernIndex(index))
+            );
+        }
+    }
+
+    #[test]
+    fn test_jongseong_is_nasal() {
+        assert!(Jongseong::Nieun.is_nasal());
+        assert!(Jongseong::Mieum.is_nasal());
+        assert!(Jongseong::Ieung.is_nasal());
+
+        assert!(!Jongseong::Kiyeok.is_nasal());
+        assert!(!Jongseong::Rieul.is_nasal());
+        assert!(!Jongseong::RieulMieum.is_nasal());
+        assert!(!Jongseong::NieunCieuc.is_nasal());
+    }
+
+    #[test]
+    fn test_jongseong_composition_order() {
+        assert_eq!(Jongseong::COUNT, 27);
+        assert!(Jongseong::try_from(Jongseong::COUNT as u8 + 1).is_err());
+
+        for index in 1..=Jongseong::COUNT {
+            let jongseong = Jongseong::try_from(index as u8).unwrap();
+            if index > 1 {
+                let previous = Jongseong::try_from(index as u8 - 1).unwrap();
+                assert!(Jaeum::from(previous) < Jaeum::from(jongseong));
+            }
+            assert_eq!(
+                char::from(Syllable::from((
+                    Choseong::Kiyeok,
+                    Jungseong::A,
+                    Some(jongseong)
+                ))) as usize,
+                0xAC00 + index,
+                "{:?}",
+                jongseong
+            );
+        }
+    }
+
+    #[test]
+    fn test_jongseong_name() {
+        assert_eq!(Jongseong::Kiyeok.name(), "Kiyeok");
+        assert_eq!(Jongseong::Hieuh.name(), "Hieuh");
+
+        for index in 1..28 {
+            let jongseong = Jongseong::try_from(index).unwrap();
+            assert_eq!(jongseong.name().parse(), Ok(jongseong));
+            assert_eq!(format!("{:?}", jongseong), jongseong.name());
+        }
+    }
+
+    #[test]
+    fn test_jongseong_phonetically_equivalent_choseong() {
+        assert_eq!(
+            Jongseong::Kiyeok.phonetically_equivalent_choseong(),
+            Choseong::Kiyeok
+        );
+        assert_eq!(
+            Jongseong::SsangSios.phonetically_equivalent_choseong(),
+            Choseong::SsangSios
+        );
+        assert_eq!(
+            Jongseong::Hieuh.phonetically_equivalent_choseong(),
+            Choseong::Hieuh
+        );
+        assert_eq!(
+            Jongseong::RieulPieup.phonetically_equivalent_choseong(),
+            Choseong::Pieup
+        );
+        assert_eq!(
+            Jongseong::PieupSios.phonetically_equivalent_choseong(),
+            Choseong::Sios
+        );
+    }
+
+    #[test]
+    fn test_jongseong_resyllabify() {
+        assert_eq!(
+            Jongseong::KiyeokSios.resyllabify(),
+            (Some(Jongseong::Kiyeok), Choseong::Sios)
+        );
+        assert_eq!(
+            Jongseong::RieulHieuh.resyllabify(),
+            (Some(Jongseong::Rieul), Choseong::Hieuh)
+        );
+        assert_eq!(Jongseong::Kiyeok.resyllabify(), (None, Choseong::Kiyeok));
+        assert_eq!(
+            Jongseong::SsangSios.resyllabify(),
+            (None, Choseong::SsangSios)
+        );
+        assert_eq!(
+            Jongseong::Ieung.resyllabify(),
+            (Some(Jongseong::Ieung), Choseong::Ieung)
+        );
+
+        for jongseong in Jongseong::iter() {
+            let (remaining, moved) = jongseong.resyllabify();
+            let (first, second) = jongseong.split_for_syllable_boundary();
+            assert_eq!(moved, second, "{:?}", jongseong);
+
+            // exactly the clustered ones leave something behind, and it is their first component.
+            let clustered = Choseong::try_from(Jaeum::from(jongseong)).is_err();
+            match remaining {
+                Some(remaining) if jongseong != Jongseong::Ieung => {
+                    assert!(clustered, "{:?}", jongseong);
+                    assert_eq!(remaining, first);
+                }
+                _ => assert!(!clustered, "{:?}", jongseong),
+            }
+        }
+    }
+
+    #[test]
+    fn test_jongseong_split_for_syllable_boundary() {
+        assert_eq!(
+            Jongseong::KiyeokSios.split_for_syllable_boundary(),
+            (Jongseong::Kiyeok, Choseong::Sios)
+        );
+        assert_eq!(
+            Jongseong::RieulKiyeok.split_for_syllable_boundary(),
+            (Jongseong::Rieul, Choseong::Kiyeok)
+        );
+        assert_eq!(
+            Jongseong::PieupSios.split_for_syllable_boundary(),
+            (Jongseong::Pieup, Choseong::Sios)
+        );
+
+        assert_eq!(
+            Jongseong::SsangKiyeok.split_for_syllable_boundary(),
+            (Jongseong::SsangKiyeok, Choseong::SsangKiyeok)
+        );
+        assert_eq!(
+            Jongseong::Hieuh.split_for_syllable_boundary(),
+            (Jongseong::Hieuh, Choseong::Hieuh)
+        );
+    }
+
+    #[test]
+    fn test_jongseong_to_conjoining() {
+        assert_eq!(Jongseong::Kiyeok.to_conjoining(), 'ᆨ');
+        assert_eq!(Jongseong::Hieuh.to_conjoining(), 'ᇂ');
+    }
+
+    #[test]
+    fn test_jongseong_to_jaeum_lossy() {
+        for jongseong in Jongseong::iter() {
+            assert_eq!(jongseong.to_jaeum_lossy(), Some(Jaeum::from(jongseong)));
+        }
+    }
+
+    #[test]
+    fn test_jongseong_to_modern_index() {
+        assert_eq!(Jongseong::Kiyeok.to_modern_index(), Some(0));
+        for jongseong in Jongseong::iter() {
+            let index = jongseong.to_modern_index().unwrap();
+            assert_eq!(Jongseong::ALL[index as usize], jongseong);
+            assert_eq!(Jongseong::from_modern_index(index), Ok(jongseong));
+        }
+    }
+
+    #[test]
+    fn test_jongseong_representative_sound() {
+        let representatives = [
+            Jongseong::Kiyeok,
+            Jongseong::Nieun,
+            Jongseong::Tikeut,
+            Jongseong::Rieul,
+            Jongseong::Mieum,
+            Jongseong::Pieup,
+            Jongseong::Ieung,
+        ];
+        for jongseong in Jongseong::iter() {
+            let representative = jongseong.representative_sound();
+            assert!(representatives.contains(&representative), "{:?}", jongseong);
+            assert_eq!(representative.representative_sound(), representative);
+
+            // a clustered consonant is pronounced as one of its components.
+            if let Some((first, second)) = jongseong.components() {
+                assert!(
+                    representative == first.representative_sound()
+                        || representative == second.representative_sound(),
+                    "{:?}",
+                    jongseong
+                );
+            }
+        }
+        for representative in representatives {
+            assert_eq!(representative.representative_sound(), representative);
+        }
+
+        assert_eq!(
+            Jongseong::SsangKiyeok.representative_sound(),
+            Jongseong::Kiyeok
+        );
+        assert_eq!(
+            Jongseong::SsangSios.representative_sound(),
+            Jongseong::Tikeut
+        );
+        assert_eq!(Jongseong::Hieuh.representative_sound(), Jongseong::Tikeut);
+        assert_eq!(Jongseong::Phieuph.representative_sound(), Jongseong::Pieup);
+        assert_eq!(
+            Jongseong::NieunHieuh.representative_sound(),
+            Jongseong::Nieun
+        );
+        assert_eq!(
+            Jongseong::RieulPieup.representative_sound(),
+            Jongseong::Rieul
+        );
+        assert_eq!(
+            Jongseong::RieulMieum.representative_sound(),
+            Jongseong::Mieum
+        );
+        assert_eq!(
+            Jongseong::RieulPhieuph.representative_sound(),
+            Jongseong::Pieup
+        );
+        assert_eq!(
+            Jongseong::PieupSios.representative_sound(),
+            Jongseong::Pieup
+        );
+    }
+
+    #[test]
+    fn test_jongseong_without_aspiration() {
+        for jongseong in Jongseong::iter() {
+            let plain = jongseong.without_aspiration();
+            // the simple ones fold the same way as their initial counterparts.
+            if let (Ok(choseong), Ok(plain_choseong)) = (
+                Choseong::try_from(Jaeum::from(jongseong)),
+                Choseong::try_from(Jaeum::from(plain)),
+            ) {
+                assert_eq!(choseong.without_aspiration(), plain_choseong);
+            }
+        }
+        assert_eq!(Jongseong::Chieuch.without_aspiration(), Jongseong::Cieuc);
+        assert_eq!(
+            Jongseong::NieunCieuc.without_aspiration(),
+            Jongseong::NieunCieuc
+        );
+        assert_eq!(
+            Jongseong::RieulHieuh.without_aspiration(),
+            Jongseong::RieulHieuh
+        );
+    }
+
+    #[test]
+    fn test_jongseong_without_tension() {
+        for jongseong in Jongseong::iter() {
+            let plain = jongseong.without_tension();
+            if let (Ok(choseong), Ok(plain_choseong)) = (
+                Choseong::try_from(Jaeum::from(jongseong)),
+                Choseong::try_from(Jaeum::from(plain)),
+            ) {
+                assert_eq!(choseong.without_tension(), plain_choseong);
+            }
+        }
+        assert_eq!(Jongseong::SsangKiyeok.without_tension(), Jongseong::Kiyeok);
+        assert_eq!(Jongseong::PieupSios.without_tension(), Jongseong::PieupSios);
+    }
+
+    #[test]
+    fn test_from_jungseong_for_char() {
+        assert_eq!(char::from(Jungseong::Yu), 'ㅠ');
+        assert_eq!(char::from(Jungseong::O), 'ㅗ');
+    }
+
+    #[test]
+    fn test_fromstr_for_jungseong() {
+        assert_eq!("A".parse::<Jungseong>(), Ok(Jungseong::A));
+        assert_eq!("ㅏ".parse::<Jungseong>(), Ok(Jungseong::A));
+        assert_eq!("Weo".parse::<Jungseong>(), Ok(Jungseong::Weo));
+        assert_eq!("ㅝ".parse::<Jungseong>(), Ok(Jungseong::Weo));
+
+        assert_eq!(
+            "garbage".parse::<Jungseong>(),
+            Err(Error::UnknownJamoName(None))
+        );
+        assert_eq!("".parse::<Jungseong>(), Err(Error::UnknownJamoName(None)));
+        assert_eq!("ㄱ".parse::<Moeum>(), Err(Error::NonJamo('ㄱ')));
+        assert_eq!("Kiyeok".parse::<Moeum>(), Err(Error::UnknownJamoName(None)));
+
+        assert_eq!("wa".parse::<Jungseong>(), Ok(Jungseong::Wa));
+        assert_eq!("U+1161".parse::<Jungseong>(), Ok(Jungseong::A));
+        assert_eq!("x".parse::<Jungseong>(), Err(Error::UnknownJamoName(None)));
+        assert_eq!("Wae_".parse::<Jungseong>(), Ok(Jungseong::Wae));
+        #[cfg(feature = "alloc")]
+        assert_eq!(
+            "Waee".parse::<Jungseong>(),
+            Err(Error::UnknownJamoName(Some("Wae")))
+        );
+    }
+
+    #[test]
+    fn test_tryfrom_char_for_jungseong() {
+        assert_eq!(Jungseong::try_from('1'), Err(Error::NonJamo('1')));
+        assert_eq!(Jungseong::try_from('ㅎ'), Err(Error::NonJamo('ㅎ')));
+
+        assert_eq!(Jungseong::try_from('ㅠ'), Ok(Jungseong::Yu));
+    }
+
+    #[test]
+    fn test_jungseong_all() {
+        assert_eq!(Jungseong::ALL.len(), Jungseong::COUNT);
+        assert!(Jungseong::ALL
+            .iter()
+            .copied()
+            .eq((0..21u8).map(|index| Jungseong::try_from(index).unwrap())));
+        assert!(Jungseong::iter().eq(Jungseong::ALL.iter().copied()));
+        assert_eq!(Jungseong::iter().len(), Jungseong::COUNT);
+        assert_eq!(
+            Jungseong::iter().next_back(),
+            Jungseong::ALL.last().copied()
+        );
+    }
+
+    #[test]
+    fn test_jungseong_modern() {
+        assert_eq!(Moeum::MODERN.len(), Jungseong::COUNT);
+        assert_eq!(Moeum::MODERN[0], Moeum::A);
+        assert!(Jungseong::iter().eq(Moeum::MODERN.iter().copied()));
+    }
+
+    #[test]
+    fn test_jungseong_keyboard_order() {
+        assert_eq!(Moeum::KEYBOARD_ORDER[0], Moeum::Yo);
+        assert!(Jungseong::iter().all(|jungseong| Jungseong::KEYBOARD_ORDER.contains(&jungseong)));
+
+        // the keys first, in the keyboard order, then the compound vowels typed with two keys.
+        let keys: String = Jungseong::KEYBOARD_ORDER
+            .iter()
+            .map(|jungseong| jungseong.dubeolsik_key())
+            .take_while(Option::is_some)
+            .flatten()
+            .collect();
+        assert_eq!(keys, "yuiophjklbnmOP");
+        assert!(Jungseong::KEYBOARD_ORDER[keys.len()..]
+            .iter()
+            .all(|jungseong| jungseong.dubeolsik_key().is_none()));
+    }
+
+    #[test]
+    fn test_jungseong_backness() {
+        assert_eq!(Jungseong::I.backness(), VowelBackness::Front);
+        assert_eq!(Jungseong::E.backness(), VowelBackness::Front);
+        assert_eq!(Jungseong::Ae.backness(), VowelBackness::Front);
+        assert_eq!(Jungseong::Oe.backness(), VowelBackness::Front);
+        assert_eq!(Jungseong::Wi.backness(), VowelBackness::Front);
+        assert_eq!(Jungseong::Eu.backness(), VowelBackness::Central);
+        assert_eq!(Jungseong::Eo.backness(), VowelBackness::Back);
+        assert_eq!(Jungseong::O.backness(), VowelBackness::Back);
+        assert_eq!(Jungseong::A.backness(), VowelBackness::Back);
+        assert_eq!(Jungseong::U.backness(), VowelBackness::Back);
+
+        // diphthongs
+        assert_eq!(Jungseong::Yae.backness(), VowelBackness::Front);
+        assert_eq!(Jungseong::Wae.backness(), VowelBackness::Back);
+        assert_eq!(Jungseong::Yi.backness(), VowelBackness::Central);
+    }
+
+    #[test]
+    fn test_jungseong_dubeolsik_key() {
+        assert_eq!(Moeum::A.dubeolsik_key(), Some('k'));
+        assert_eq!(Moeum::Ye.dubeolsik_key(), Some('P'));
+        assert_eq!(Moeum::I.dubeolsik_key(), Some('l'));
+        assert_eq!(Moeum::Weo.dubeolsik_key(), None);
+        assert_eq!(Moeum::Yi.dubeolsik_key(), None);
+
+        // the ones typed with two keys, i.e. ㅗ or ㅜ (or ㅡ) followed by another vowel.
+        let keyless = Jungseong::iter()
+            .filter(|jungseong| jungseong.dubeolsik_key().is_none())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            keyless,
+            [
+                Jungseong::Wa,
+                Jungseong::Wae,
+                Jungseong::Oe,
+                Jungseong::Weo,
+                Jungseong::We,
+                Jungseong::Wi,
+                Jungseong::Yi,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_jungseong_from_conjoining() {
+        assert_eq!(Jungseong::from_conjoining('ᅡ'), Ok(Jungseong::A));
+        assert_eq!(Jungseong::from_conjoining('ᅵ'), Ok(Jungseong::I));
+
+        assert_eq!(
+            Jungseong::from_conjoining('ㅏ'),
+            Err(Error::NonConjoiningJamo('ㅏ'))
+        );
+        assert_eq!(
+            Jungseong::from_conjoining('ᅶ'),
+            Err(Error::NonConjoiningJamo('ᅶ'))
+        );
+    }
+
+    #[test]
+    fn test_jungseong_from_modern_index() {
+        assert_eq!(Jungseong::from_modern_index(0), Ok(Jungseong::A));
+        for (index, jungseong) in Jungseong::iter().enumerate() {
+            assert_eq!(Jungseong::from_modern_index(index as u8), Ok(jungseong));
+        }
+        for index in 21..=u8::MAX {
+            assert_eq!(
+                Jungseong::from_modern_index(index),
+                Err(Error::NonModernIndex(index))
+            );
+        }
+    }
 
     #[test]
-    fn test_from_choseong_for_char() {
-        assert_eq!(char::from(Choseong::Ieung), 'ㅇ');
-        assert_eq!(char::from(Choseong::Cieuc), 'ㅈ');
+    fn test_jungseong_fuse() {
+        assert_eq!(Jungseong::A.fuse(Jungseong::A), Some(Jungseong::A));
+        assert_eq!(Jungseong::Eo.fuse(Jungseong::Eo), Some(Jungseong::Eo));
+        assert_eq!(Jungseong::O.fuse(Jungseong::A), Some(Jungseong::Wa));
+        assert_eq!(Jungseong::U.fuse(Jungseong::Eo), Some(Jungseong::Weo));
+        assert_eq!(Jungseong::Oe.fuse(Jungseong::Eo), Some(Jungseong::Wae));
+        assert_eq!(Jungseong::I.fuse(Jungseong::Eo), Some(Jungseong::Yeo));
+        assert_eq!(Jungseong::Eu.fuse(Jungseong::A), Some(Jungseong::A));
+        assert_eq!(Jungseong::Eu.fuse(Jungseong::Eo), Some(Jungseong::Eo));
+        assert_eq!(Jungseong::A.fuse(Jungseong::Yeo), Some(Jungseong::Ae));
+
+        // the vowel harmony is not crossed, and 'ㅟ' is not contracted.
+        assert_eq!(Jungseong::A.fuse(Jungseong::Eo), None);
+        assert_eq!(Jungseong::O.fuse(Jungseong::Eo), None);
+        assert_eq!(Jungseong::Wi.fuse(Jungseong::Eo), None);
+        assert_eq!(Jungseong::A.fuse(Jungseong::I), None);
     }
 
     #[test]
-    fn test_tryfrom_char_for_choseong() {
-        assert_eq!(
-            Choseong::try_from('ㄵ'),
-            Err(Error::NotApplicableToChoseong(Jaeum::NieunCieuc))
-        );
-        assert_eq!(
-            Choseong::try_from('ㄺ'),
-            Err(Error::NotApplicableToChoseong(Jaeum::RieulKiyeok))
-        );
+    fn test_jungseong_height() {
+        assert_eq!(Jungseong::I.height(), VowelHeight::High);
+        assert_eq!(Jungseong::Eu.height(), VowelHeight::High);
+        assert_eq!(Jungseong::U.height(), VowelHeight::High);
+        assert_eq!(Jungseong::Wi.height(), VowelHeight::High);
+        assert_eq!(Jungseong::Ae.height(), VowelHeight::Mid);
+        assert_eq!(Jungseong::E.height(), VowelHeight::Mid);
+        assert_eq!(Jungseong::Eo.height(), VowelHeight::Mid);
+        assert_eq!(Jungseong::O.height(), VowelHeight::Mid);
+        assert_eq!(Jungseong::Oe.height(), VowelHeight::Mid);
+        assert_eq!(Jungseong::A.height(), VowelHeight::Low);
 
-        assert_eq!(Choseong::try_from('ㅈ'), Ok(Choseong::Cieuc));
-        assert_eq!(Choseong::try_from('ㅅ'), Ok(Choseong::Sios));
+        // diphthongs
+        assert_eq!(Jungseong::Ya.height(), VowelHeight::Low);
+        assert_eq!(Jungseong::Wa.height(), VowelHeight::Mid);
+        assert_eq!(Jungseong::Yu.height(), VowelHeight::High);
     }
 
     #[test]
-    fn test_tryfrom_jaeum_for_choseong() {
-        assert_eq!(
-            Choseong::try_from(Jaeum::KiyeokSios),
-            Err(Error::NotApplicableToChoseong(Jaeum::KiyeokSios))
-        );
-        assert_eq!(
-            Choseong::try_from(Jaeum::RieulPhieuph),
-            Err(Error::NotApplicableToChoseong(Jaeum::RieulPhieuph))
-        );
+    fn test_jungseong_is_compound() {
+        assert!(Jungseong::Ya.is_compound());
+        assert!(Jungseong::Wae.is_compound());
+        assert!(Jungseong::Weo.is_compound());
+        assert!(Jungseong::Yi.is_compound());
+
+        assert!(!Jungseong::A.is_compound());
+        assert!(!Jungseong::Oe.is_compound());
+        assert!(!Jungseong::Wi.is_compound());
+        assert!(!Jungseong::I.is_compound());
 
         assert_eq!(
-            Choseong::try_from(Jaeum::SsangPieup),
-            Ok(Choseong::SsangPieup)
-        );
-        assert_eq!(
-            Choseong::try_from(Jaeum::SsangTikeut),
-            Ok(Choseong::SsangTikeut)
+            (0..21)
+                .filter(|&index| Jungseong::try_from(index).unwrap().is_compound())
+                .count(),
+            11
         );
     }
 
     #[test]
-    fn test_from_choseong_for_jaeum() {
-        assert_eq!(Jaeum::from(Choseong::Thieuth), Jaeum::Thieuth);
-        assert_eq!(Jaeum::from(Choseong::Kiyeok), Jaeum::Kiyeok);
+    fn test_jungseong_is_rounded() {
+        assert!(Jungseong::O.is_rounded());
+        assert!(Jungseong::U.is_rounded());
+        assert!(Jungseong::Oe.is_rounded());
+        assert!(Jungseong::Wi.is_rounded());
+        assert!(Jungseong::Wa.is_rounded());
+        assert!(Jungseong::Yu.is_rounded());
+
+        assert!(!Jungseong::A.is_rounded());
+        assert!(!Jungseong::Eu.is_rounded());
+        assert!(!Jungseong::Yi.is_rounded());
+        assert!(!Jungseong::I.is_rounded());
     }
 
     #[test]
-    fn test_from_jaeum_for_char() {
-        assert_eq!(char::from(Jaeum::KiyeokSios), 'ㄳ');
-        assert_eq!(char::from(Jaeum::Hieuh), 'ㅎ');
+    fn test_jungseong_is_w_mediated() {
+        assert!(Jungseong::Wa.is_w_mediated());
+        assert!(Jungseong::Wae.is_w_mediated());
+        assert!(Jungseong::Oe.is_w_mediated());
+        assert!(Jungseong::Weo.is_w_mediated());
+        assert!(Jungseong::We.is_w_mediated());
+        assert!(Jungseong::Wi.is_w_mediated());
+
+        assert!(!Jungseong::O.is_w_mediated());
+        assert!(!Jungseong::U.is_w_mediated());
+        assert!(!Jungseong::Yi.is_w_mediated());
     }
 
     #[test]
-    fn test_from_jongseong_for_jaeum() {
-        assert_eq!(Jaeum::from(Jongseong::PieupSios), Jaeum::PieupSios);
-        assert_eq!(Jaeum::from(Jongseong::Hieuh), Jaeum::Hieuh);
+    fn test_jungseong_is_y_mediated() {
+        assert!(Jungseong::Ya.is_y_mediated());
+        assert!(Jungseong::Yae.is_y_mediated());
+        assert!(Jungseong::Yeo.is_y_mediated());
+        assert!(Jungseong::Ye.is_y_mediated());
+        assert!(Jungseong::Yo.is_y_mediated());
+        assert!(Jungseong::Yu.is_y_mediated());
+        assert!(Jungseong::Yi.is_y_mediated());
+
+        assert!(!Jungseong::A.is_y_mediated());
+        assert!(!Jungseong::Eu.is_y_mediated());
+        assert!(!Jungseong::Wa.is_y_mediated());
+
+        // every vowel is either plain, y-mediated, or w-mediated.
+        for index in 0..21 {
+            let jungseong = Jungseong::try_from(index).unwrap();
+            assert!(!(jungseong.is_y_mediated() && jungseong.is_w_mediated()));
+        }
     }
 
     #[test]
-    fn test_tryfrom_char_for_jaeum() {
-        assert_eq!(Jaeum::try_from('^'), Err(Error::NonJamo('^')));
-        assert_eq!(Jaeum::try_from('v'), Err(Error::NonJamo('v')));
-        assert_eq!(Jaeum::try_from('ㅗ'), Err(Error::NonJamo('ㅗ')));
+    fn test_jungseong_is_yotized() {
+        assert!(Jungseong::Ya.is_yotized());
+        assert!(Jungseong::Ye.is_yotized());
+        assert!(Jungseong::Yu.is_yotized());
 
-        assert_eq!(Jaeum::try_from('ㄱ'), Ok(Jaeum::Kiyeok));
-        assert_eq!(Jaeum::try_from('ㅄ'), Ok(Jaeum::PieupSios));
-        assert_eq!(Jaeum::try_from('ㄸ'), Ok(Jaeum::SsangTikeut));
+        assert!(!Jungseong::A.is_yotized());
+        assert!(!Jungseong::Wa.is_yotized());
+        assert!(!Jungseong::Yi.is_yotized());
     }
 
     #[test]
-    fn test_from_jongseong_for_char() {
-        assert_eq!(char::from(Jongseong::KiyeokSios), 'ㄳ');
-        assert_eq!(char::from(Jongseong::RieulHieuh), 'ㅀ');
-        assert_eq!(char::from(Jongseong::Sios), 'ㅅ');
+    fn test_jungseong_composition_order() {
+        assert_eq!(Jungseong::COUNT, 21);
+        assert!(Jungseong::try_from(Jungseong::COUNT as u8).is_err());
+
+        for index in 0..Jungseong::COUNT {
+            let jungseong = Jungseong::try_from(index as u8).unwrap();
+            assert_eq!(
+                char::from(Syllable::from((Choseong::Kiyeok, jungseong))) as usize,
+                0xAC00 + index * 28,
+                "{:?}",
+                jungseong
+            );
+        }
     }
 
     #[test]
-    fn test_tryfrom_char_for_jongseong() {
-        assert_eq!(Jongseong::try_from('ㅗ'), Err(Error::NonJamo('ㅗ')));
-        assert_eq!(Jongseong::try_from('v'), Err(Error::NonJamo('v')));
+    fn test_jungseong_name() {
+        assert_eq!(Jungseong::A.name(), "A");
+        assert_eq!(Jungseong::Yi.name(), "Yi");
 
-        assert_eq!(Jongseong::try_from('ㅄ'), Ok(Jongseong::PieupSios));
-        assert_eq!(Jongseong::try_from('ㅎ'), Ok(Jongseong::Hieuh));
+        for index in 0..21 {
+            let jungseong = Jungseong::try_from(index).unwrap();
+            assert_eq!(jungseong.name().parse(), Ok(jungseong));
+            assert_eq!(format!("{:?}", jungseong), jungseong.name());
+        }
     }
 
     #[test]
-    fn test_tryfrom_jaeum_for_jongseong() {
-        // These three are the only possible instances of error in this context.
-        assert_eq!(
-            Jongseong::try_from(Jaeum::SsangTikeut),
-            Err(Error::NotApplicableToJongseong(Jaeum::SsangTikeut))
-        );
-        assert_eq!(
-            Jongseong::try_from(Jaeum::SsangPieup),
-            Err(Error::NotApplicableToJongseong(Jaeum::SsangPieup))
-        );
-        assert_eq!(
-            Jongseong::try_from(Jaeum::SsangCieuc),
-            Err(Error::NotApplicableToJongseong(Jaeum::SsangCieuc))
-        );
+    fn test_jungseong_to_conjoining() {
+        assert_eq!(Jungseong::A.to_conjoining(), 'ᅡ');
+        assert_eq!(Jungseong::I.to_conjoining(), 'ᅵ');
+    }
 
-        assert_eq!(
-            Jongseong::try_from(Jaeum::PieupSios),
-            Ok(Jongseong::PieupSios)
-        );
-        assert_eq!(Jongseong::try_from(Jaeum::Hieuh), Ok(Jongseong::Hieuh));
+    #[test]
+    fn test_jungseong_to_modern_index() {
+        assert_eq!(Jungseong::A.to_modern_index(), Some(0));
+        for jungseong in Jungseong::iter() {
+            let index = jungseong.to_modern_index().unwrap();
+            assert_eq!(Jungseong::ALL[index as usize], jungseong);
+            assert_eq!(Jungseong::from_modern_index(index), Ok(jungseong));
+        }
     }
 
     #[test]
-    fn test_from_jungseong_for_char() {
-        assert_eq!(char::from(Jungseong::Yu), 'ㅠ');
-        assert_eq!(char::from(Jungseong::O), 'ㅗ');
+    fn test_jungseong_to_moeum_lossy() {
+        for jungseong in Jungseong::iter() {
+            assert_eq!(jungseong.to_moeum_lossy(), Some(jungseong));
+        }
     }
 
     #[test]
-    fn test_tryfrom_char_for_jungseong() {
-        assert_eq!(Jungseong::try_from('1'), Err(Error::NonJamo('1')));
-        assert_eq!(Jungseong::try_from('ㅎ'), Err(Error::NonJamo('ㅎ')));
+    fn test_display_for_syllable() {
+        let syllable = Syllable::try_from('각').unwrap();
+        assert_eq!(syllable.to_string(), "각");
+        assert_eq!(format!("{:#}", syllable), "\u{1100}\u{1161}\u{11A8}");
+        assert_eq!(
+            format!("{:#}", Syllable::try_from('가').unwrap()),
+            "\u{1100}\u{1161}"
+        );
 
-        assert_eq!(Jungseong::try_from('ㅠ'), Ok(Jungseong::Yu));
+        // width, fill, and alignment
+        assert_eq!(format!("{:>3}", syllable), "  각");
+        assert_eq!(format!("{:*<2}", syllable), "각*");
+        assert_eq!(format!("{:>#5}", syllable), "  \u{1100}\u{1161}\u{11A8}");
     }
 
     #[test]
@@ -877,11 +4750,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_syllable_for_utf8_bytes() {
+        assert_eq!(
+            <[u8; 3]>::from(Syllable::try_from('가').unwrap()),
+            [0xEA, 0xB0, 0x80]
+        );
+        assert_eq!(
+            <[u8; 3]>::from(Syllable::try_from('힣').unwrap()),
+            [0xED, 0x9E, 0xA3]
+        );
+
+        for code_point in 0xAC00..=0xD7A3 {
+            let character = char::from_u32(code_point).unwrap();
+            let mut expected = [0; 3];
+            character.encode_utf8(&mut expected);
+
+            assert_eq!(
+                <[u8; 3]>::from(Syllable::try_from(character).unwrap()),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_partialeq_char_for_syllable() {
+        let syllable = Syllable {
+            choseong: Choseong::Mieum,
+            jungseong: Jungseong::Eo,
+            jongseong: Some(Jongseong::Ieung),
+        };
+        assert_eq!(syllable, '멍');
+        assert_eq!('멍', syllable);
+
+        assert_ne!(syllable, '멈');
+        assert_ne!('멈', syllable);
+        assert_ne!(syllable, 'm');
+    }
+
     #[test]
     fn test_tryfrom_char_for_syllable() {
-        assert_eq!(Syllable::try_from('@'), Err(Error::NonKorean('@')));
-        assert_eq!(Syllable::try_from('E'), Err(Error::NonKorean('E')));
-        assert_eq!(Syllable::try_from('𝄞'), Err(Error::NonKorean('𝄞')));
+        assert_eq!(Syllable::try_from('@'), Err(Error::NonKorean('@', None)));
+        assert_eq!(Syllable::try_from('E'), Err(Error::NonKorean('E', None)));
+        assert_eq!(Syllable::try_from('𝄞'), Err(Error::NonKorean('𝄞', None)));
 
         assert_eq!(
             Syllable::try_from('고'),
@@ -910,6 +4821,157 @@ mod tests {
     }
 
     #[test]
+    fn test_tryfrom_utf8_bytes_for_syllable() {
+        assert_eq!(Syllable::try_from([0xEA, 0xB0, 0x80]).unwrap(), '가');
+        assert_eq!(Syllable::try_from([0xED, 0x9E, 0xA3]).unwrap(), '힣');
+
+        for code_point in 0xAC00..=0xD7A3 {
+            let character = char::from_u32(code_point).unwrap();
+            let mut bytes = [0; 3];
+            character.encode_utf8(&mut bytes);
+
+            assert_eq!(Syllable::try_from(bytes).unwrap(), character);
+        }
+
+        for bytes in [
+            [0xEA, 0xAF, 0xBF], // U+ABFF, right before '가'
+            [0xED, 0x9E, 0xA4], // U+D7A4, right after '힣'
+            [0xE3, 0x84, 0xB1], // 'ㄱ'
+            *b"abc",
+            [0xEA, 0xB0, b'a'],
+            [0xFF, 0xFF, 0xFF],
+        ]
+        .iter()
+        {
+            assert_eq!(
+                Syllable::try_from(*bytes),
+                Err(Error::NonKoreanUtf8(*bytes))
+            );
+        }
+    }
+
+    #[test]
+    fn test_syllable_apply_initial_sound_law() {
+        for (reading, expected) in [
+            ('리', '이'),
+            ('량', '양'),
+            ('려', '여'),
+            ('례', '예'),
+            ('료', '요'),
+            ('륙', '육'),
+            ('라', '나'),
+            ('래', '내'),
+            ('론', '논'),
+            ('뢰', '뇌'),
+            ('루', '누'),
+            ('릉', '능'),
+            ('녀', '여'),
+            ('뇨', '요'),
+            ('뉴', '유'),
+            ('니', '이'),
+            // 'ㄴ' stays before any other vowel.
+            ('남', '남'),
+            ('내', '내'),
+            ('노', '노'),
+            // and any other initial consonant stays.
+            ('기', '기'),
+            ('이', '이'),
+        ] {
+            let syllable = Syllable::try_from(reading).unwrap();
+            assert_eq!(
+                char::from(syllable.apply_initial_sound_law()),
+                expected,
+                "{}",
+                reading
+            );
+        }
+    }
+
+    #[test]
+    fn test_syllable_compose() {
+        for index in 0..11172 {
+            let syllable = Syllable::from_index(index).unwrap();
+            assert_eq!(
+                Syllable::compose(syllable.choseong, syllable.jungseong, syllable.jongseong),
+                char::from_u32(0xAC00 + index).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_syllable_dictionary_index() {
+        assert_eq!(Syllable::try_from('가').unwrap().dictionary_index(), 0);
+        assert_eq!(Syllable::try_from('개').unwrap().dictionary_index(), 28);
+        assert_eq!(
+            Syllable::try_from('까').unwrap().dictionary_index(),
+            21 * 28
+        );
+        assert_eq!(Syllable::try_from('힣').unwrap().dictionary_index(), 11171);
+
+        for index in 0..=11171 {
+            let syllable = Syllable::from_index(index).unwrap();
+            assert_eq!(syllable.dictionary_index(), index);
+            assert_eq!(char::from(syllable) as u32, 0xAC00 + index);
+        }
+
+        // the order of the indices is that of `Ord`.
+        for index in 0..11171 {
+            let syllable = Syllable::from_index(index).unwrap();
+            let next = Syllable::from_index(index + 1).unwrap();
+            assert!(syllable < next, "{} < {}", syllable, next);
+        }
+    }
+
+    #[test]
+    fn test_syllable_differs_only_in() {
+        let syllable = |character| Syllable::try_from(character).unwrap();
+        let differs = |a, b| {
+            let (a, b) = (syllable(a), syllable(b));
+            [
+                a.differs_only_in_choseong(b),
+                a.differs_only_in_jungseong(b),
+                a.differs_only_in_jongseong(b),
+            ]
+        };
+
+        assert_eq!(differs('가', '각'), [false, false, true]);
+        assert_eq!(differs('각', '갃'), [false, false, true]);
+        assert_eq!(differs('가', '나'), [true, false, false]);
+        assert_eq!(differs('각', '낙'), [true, false, false]);
+        assert_eq!(differs('가', '과'), [false, true, false]);
+        // in none, or in more than one.
+        assert_eq!(differs('가', '가'), [false, false, false]);
+        assert_eq!(differs('가', '낙'), [false, false, false]);
+        assert_eq!(differs('가', '너'), [false, false, false]);
+        assert_eq!(differs('한', '글'), [false, false, false]);
+
+        // symmetric.
+        for (a, b) in [('가', '각'), ('가', '나'), ('가', '과'), ('한', '글')] {
+            assert_eq!(differs(a, b), differs(b, a));
+        }
+    }
+
+    #[test]
+    fn test_syllable_from_index() {
+        assert_eq!(Syllable::from_index(0), Syllable::try_from('가').ok());
+        assert_eq!(Syllable::from_index(11171), Syllable::try_from('힣').ok());
+
+        assert_eq!(Syllable::from_index(11172), None);
+        assert_eq!(Syllable::from_index(u32::MAX), None);
+    }
+
+    #[test]
+    fn test_syllable_from_u16_unchecked() {
+        for index in 0..=11171 {
+            assert_eq!(
+                Some(unsafe { Syllable::from_u16_unchecked(index) }),
+                Syllable::try_from_u16(index)
+            );
+        }
+    }
+
+    #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn test_syllable_is_one_of_us() {
         // TODO: update this test once the `archaic-korean` feature is added to the crate.
         assert_eq!(Syllable::is_one_of_us('꯹'), false); // U+ABF9
@@ -917,9 +4979,274 @@ mod tests {
         assert_eq!(Syllable::is_one_of_us('문'), true); // U+BB38
         assert_eq!(Syllable::is_one_of_us('힣'), true); // U+D7A3
         assert_eq!(Syllable::is_one_of_us('ힰ'), false); // U+D7B0 is technically a Korean alphabet,
-                                                        // but an *archaic* Korean alphabet rather
-                                                        // than a modern one. Thus it is considered
-                                                        // NOT a valid Korean alphabet in the
-                                                        // context of this library.
+                                                       // but an *archaic* Korean alphabet rather
+                                                       // than a modern one. Thus it is considered
+                                                       // NOT a valid Korean alphabet in the
+                                                       // context of this library.
+    }
+
+    #[test]
+    fn test_syllable_is_renderable_block() {
+        assert!(Syllable::iter_all().all(Syllable::is_renderable_block));
+    }
+
+    #[test]
+    fn test_syllable_iter_all() {
+        assert_eq!(Syllable::iter_all().len(), 11172);
+        assert_eq!(Syllable::iter_all().count(), 11172);
+        assert_eq!(Syllable::iter_all().rev().count(), 11172);
+
+        for (syllable, code_point) in Syllable::iter_all().zip(0xAC00..=0xD7A3) {
+            let character = char::from(syllable);
+            assert_eq!(character as u32, code_point);
+            assert_eq!(Syllable::try_from(character), Ok(syllable));
+        }
+        assert!(Syllable::iter_all().rev().eq((0..11172)
+            .rev()
+            .map(|index| Syllable::from_index(index).unwrap())));
+    }
+
+    #[test]
+    fn test_syllable_map_choseong() {
+        let syllable = Syllable::try_from('달').unwrap();
+        assert_eq!(syllable.map_choseong(|_| Choseong::Thieuth), '탈');
+        assert_eq!(syllable.map_choseong(|choseong| choseong), syllable);
+    }
+
+    #[test]
+    fn test_syllable_map_jongseong() {
+        let syllable = Syllable::try_from('가').unwrap();
+        assert_eq!(syllable.map_jongseong(|_| Some(Jongseong::Mieum)), '감');
+        assert_eq!(
+            syllable.map_jongseong(|jongseong| {
+                assert_eq!(jongseong, None);
+                jongseong
+            }),
+            '가'
+        );
+        assert_eq!(
+            Syllable::try_from('감').unwrap().map_jongseong(|_| None),
+            '가'
+        );
+    }
+
+    #[test]
+    fn test_syllable_map_jungseong() {
+        let syllable = Syllable::try_from('글').unwrap();
+        assert_eq!(syllable.map_jungseong(|_| Jungseong::I), '길');
+        assert_eq!(syllable.map_jungseong(|jungseong| jungseong), syllable);
+    }
+
+    #[test]
+    fn test_syllable_next_in_order() {
+        assert_eq!(
+            Syllable::try_from('갛').unwrap().next_in_order(),
+            Syllable::try_from('개').ok()
+        );
+        assert_eq!(Syllable::try_from('힣').unwrap().next_in_order(), None);
+
+        // the same as stepping through the code points.
+        for code_point in 0xAC00..0xD7A3 {
+            let syllable = Syllable::try_from(char::from_u32(code_point).unwrap()).unwrap();
+            assert_eq!(
+                syllable.next_in_order().map(char::from),
+                char::from_u32(code_point + 1)
+            );
+            assert_eq!(
+                syllable.next_in_order().unwrap().prev_in_order(),
+                Some(syllable)
+            );
+        }
+    }
+
+    #[test]
+    fn test_syllable_prev_in_order() {
+        assert_eq!(
+            Syllable::try_from('개').unwrap().prev_in_order(),
+            Syllable::try_from('갛').ok()
+        );
+        assert_eq!(Syllable::try_from('가').unwrap().prev_in_order(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_syllable_romanize_rr() {
+        for (syllable, romanized) in [
+            ('한', "han"),
+            ('밝', "balk"),
+            ('밟', "balp"),
+            ('앉', "ant"),
+            ('않', "ant"),
+            ('잃', "ilt"),
+            ('넋', "neokt"),
+            ('가', "ga"),
+            ('각', "gak"),
+            ('아', "a"),
+            ('앙', "ang"),
+            ('꽃', "kkot"),
+            ('의', "ui"),
+            ('뷁', "bwelk"),
+            ('값', "gapt"),
+            ('힣', "hit"),
+        ] {
+            assert_eq!(
+                Syllable::try_from(syllable).unwrap().romanize_rr(),
+                romanized,
+                "{}",
+                syllable
+            );
+        }
+
+        // every cluster is spelled out as its components in the final position.
+        let final_of = |jongseong: Jongseong| {
+            Syllable::from((Jungseong::A, Some(jongseong)))
+                .romanize_rr()
+                .split_off(1)
+        };
+        for jongseong in Jongseong::iter() {
+            if let Some((first, second)) = jongseong.components() {
+                assert_eq!(
+                    final_of(jongseong),
+                    final_of(first) + &final_of(second),
+                    "{:?}",
+                    jongseong
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_syllable_strip_jongseong() {
+        assert_eq!(Syllable::try_from('강').unwrap().strip_jongseong(), '가');
+        assert_eq!(Syllable::try_from('가').unwrap().strip_jongseong(), '가');
+    }
+
+    #[test]
+    fn test_syllable_to_char() {
+        const HAN: char = Syllable {
+            choseong: Choseong::Hieuh,
+            jungseong: Jungseong::A,
+            jongseong: Some(Jongseong::Nieun),
+        }
+        .to_char();
+        assert_eq!(HAN, '한');
+
+        for code_point in 0xAC00..=0xD7A3 {
+            let character = char::from_u32(code_point).unwrap();
+            assert_eq!(Syllable::try_from(character).unwrap().to_char(), character);
+        }
+    }
+
+    #[test]
+    fn test_syllable_to_u16() {
+        assert_eq!(Syllable::try_from('가').unwrap().to_u16(), 0);
+        assert_eq!(Syllable::try_from('각').unwrap().to_u16(), 1);
+        assert_eq!(Syllable::try_from('힣').unwrap().to_u16(), 11171);
+    }
+
+    #[test]
+    fn test_syllable_try_from_jamo_sequence() {
+        assert_eq!(
+            Syllable::try_from_jamo_sequence("\u{1100}\u{1161}"),
+            Syllable::try_from('가').ok()
+        );
+        assert_eq!(
+            Syllable::try_from_jamo_sequence("\u{1112}\u{1175}\u{11C2}"),
+            Syllable::try_from('힣').ok()
+        );
+
+        assert_eq!(Syllable::try_from_jamo_sequence(""), None);
+        assert_eq!(Syllable::try_from_jamo_sequence("\u{1161}\u{11A8}"), None);
+        assert_eq!(
+            Syllable::try_from_jamo_sequence("\u{1100}\u{1161}\u{1100}"),
+            None
+        );
+        assert_eq!(
+            Syllable::try_from_jamo_sequence("\u{1100}\u{1161}\u{11A8}\u{11A8}"),
+            None
+        );
+        assert_eq!(Syllable::try_from_jamo_sequence("ㄱㅏ"), None);
+
+        // archaic jamo have no place in a `Syllable`.
+        assert_eq!(
+            Syllable::try_from_jamo_sequence("\u{114C}\u{119E}\u{11B7}"),
+            None
+        );
+
+        for code_point in (0xAC00..=0xD7A3).step_by(7) {
+            let syllable = Syllable::try_from(char::from_u32(code_point).unwrap()).unwrap();
+            assert_eq!(
+                Syllable::try_from_jamo_sequence(&format!("{:#}", syllable)),
+                Some(syllable)
+            );
+        }
+    }
+
+    #[test]
+    fn test_syllable_try_from_u16() {
+        for index in 0..=11171 {
+            assert_eq!(Syllable::try_from_u16(index).unwrap().to_u16(), index);
+        }
+
+        assert_eq!(Syllable::try_from_u16(11172), None);
+        assert_eq!(Syllable::try_from_u16(u16::MAX), None);
+    }
+
+    #[test]
+    fn test_syllable_try_from_u32() {
+        assert_eq!(Syllable::try_from_u32(0xAC00), Syllable::try_from('가'));
+        assert_eq!(Syllable::try_from_u32(0xD7A3), Syllable::try_from('힣'));
+
+        assert_eq!(
+            Syllable::try_from_u32(0xD7A4),
+            Err(Error::NonKorean('\u{D7A4}', None))
+        );
+        assert_eq!(
+            Syllable::try_from_u32(0xABFF),
+            Err(Error::NonKorean('꯿', None))
+        );
+        assert_eq!(
+            Syllable::try_from_u32(0x110000),
+            Err(Error::NonUnicode(0x110000))
+        );
+        assert_eq!(
+            Syllable::try_from_u32(u32::MAX),
+            Err(Error::NonUnicode(u32::MAX))
+        );
+        assert_eq!(
+            Error::NonUnicode(0xD800).to_string(),
+            "0xD800 is not a Unicode scalar value"
+        );
+    }
+
+    #[test]
+    fn test_syllable_with_choseong() {
+        assert_eq!(
+            Syllable::try_from('각')
+                .unwrap()
+                .with_choseong(Choseong::Hieuh),
+            '학'
+        );
+    }
+
+    #[test]
+    fn test_syllable_with_jongseong() {
+        assert_eq!(
+            Syllable::try_from('가')
+                .unwrap()
+                .with_jongseong(Some(Jongseong::Nieun)),
+            '간'
+        );
+        assert_eq!(Syllable::try_from('간').unwrap().with_jongseong(None), '가');
+    }
+
+    #[test]
+    fn test_syllable_with_jungseong() {
+        assert_eq!(
+            Syllable::try_from('각')
+                .unwrap()
+                .with_jungseong(Jungseong::Wae),
+            '괙'
+        );
     }
 }
@@ -0,0 +1,101 @@
+//! The lookup table behind `TryFrom<char>` for [`Choseong`], [`Jongseong`], and [`Jungseong`],
+//! enabled by the `fast-lookup` feature.
+//!
+//! Without it, a consonant goes through [`Jaeum`](crate::Jaeum) first, and thus a couple of range
+//! checks and table lookups; with it, any of the three is a single bounds check and an index away.
+//! Only the hits are served from here; every miss falls back to the regular conversion so that the
+//! errors stay the same.
+use crate::{Choseong, Jongseong, Jungseong, JAEUM_TO_CHOSEONG, JAEUM_TO_JONGSEONG};
+
+#[derive(Clone, Copy)]
+struct Entry {
+    choseong: Option<Choseong>,
+    jongseong: Option<Jongseong>,
+    jungseong: Option<Jungseong>,
+}
+
+// indexed by `character as usize - 0x3131`, i.e. Hangul Compatibility Jamo range from 'ㄱ' to 'ㅣ';
+// the first 30 are the consonants, and the rest are the vowels.
+static COMPAT_JAMO: [Entry; 51] = build();
+
+const fn build() -> [Entry; 51] {
+    let mut table = [Entry {
+        choseong: None,
+        jongseong: None,
+        jungseong: None,
+    }; 51];
+
+    let mut index = 0;
+    while index < 30 {
+        table[index].choseong = JAEUM_TO_CHOSEONG[index];
+        table[index].jongseong = JAEUM_TO_JONGSEONG[index];
+        index += 1;
+    }
+    while index < 51 {
+        table[index].jungseong = Some(Jungseong::ALL[index - 30]);
+        index += 1;
+    }
+
+    table
+}
+
+fn entry(character: char) -> Option<&'static Entry> {
+    COMPAT_JAMO.get((character as u32).wrapping_sub(0x3131) as usize)
+}
+
+pub(crate) fn choseong(character: char) -> Option<Choseong> {
+    entry(character).and_then(|entry| entry.choseong)
+}
+
+pub(crate) fn jongseong(character: char) -> Option<Jongseong> {
+    entry(character).and_then(|entry| entry.jongseong)
+}
+
+pub(crate) fn jungseong(character: char) -> Option<Jungseong> {
+    entry(character).and_then(|entry| entry.jungseong)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{choseong, jongseong, jungseong};
+    use crate::{Choseong, Jaeum, Jongseong, Jungseong};
+    use core::convert::TryFrom;
+
+    #[test]
+    fn test_lookup_round_trip() {
+        for choseong_ in Choseong::iter() {
+            assert_eq!(choseong(char::from(choseong_)), Some(choseong_));
+        }
+        for jongseong_ in Jongseong::iter() {
+            assert_eq!(jongseong(char::from(jongseong_)), Some(jongseong_));
+        }
+        for jungseong_ in Jungseong::iter() {
+            assert_eq!(jungseong(char::from(jungseong_)), Some(jungseong_));
+        }
+    }
+
+    #[test]
+    fn test_lookup_against_regular_conversion() {
+        for character in ('\u{3100}'..='\u{31FF}').chain(['\0', '\u{1100}', '가', '\u{10FFFF}']) {
+            let jaeum = Jaeum::try_from(character).ok();
+            assert_eq!(
+                choseong(character),
+                jaeum.and_then(Jaeum::to_choseong),
+                "{:?}",
+                character
+            );
+            assert_eq!(
+                jongseong(character),
+                jaeum.and_then(Jaeum::to_jongseong),
+                "{:?}",
+                character
+            );
+            assert_eq!(
+                jungseong(character),
+                Jungseong::try_from(character).ok(),
+                "{:?}",
+                character
+            );
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! Sliding windows of consecutive syllables or jamo, for n-gram analysis of Korean text.
+use crate::{Jaeum, JamoForm, Moeum, Syllable};
+use alloc::vec::Vec;
+use core::{convert::TryFrom, iter};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// Iterates over every run of `n` consecutive Precomposed Korean [`Syllable`]s in a given string,
+/// from left to right, overlapping one another.
+///
+/// Anything other than a [`Syllable`] breaks the run, i.e. no window spans across it; thus a run of
+/// fewer than `n` syllables yields nothing.
+///
+/// # Panics
+/// Panics if `n` is 0.
+///
+/// ```
+/// use unikorn::syllable_ngrams;
+///
+/// let bigrams: Vec<String> = syllable_ngrams("한국어, 말", 2)
+///     .map(|bigram| bigram.into_iter().map(char::from).collect())
+///     .collect();
+/// assert_eq!(bigrams, ["한국", "국어"]);
+/// ```
+pub fn syllable_ngrams(s: &str, n: usize) -> impl Iterator<Item = Vec<Syllable>> + '_ {
+    windows(
+        s.chars()
+            .map(|character| Syllable::try_from(character).ok()),
+        n,
+    )
+}
+
+/// Iterates over every run of `n` consecutive jamo in a given string, from left to right,
+/// overlapping one another, once every Precomposed Korean [`Syllable`] is decomposed into the
+/// Hangul Compatibility Jamo (U+3131 -- U+3163).
+///
+/// A standalone compatibility jamo, e.g. the ones in 'ㅋㅋ', takes part as it is; anything else
+/// breaks the run, i.e. no window spans across it.
+///
+/// # Panics
+/// Panics if `n` is 0.
+///
+/// ```
+/// use unikorn::jamo_ngrams;
+///
+/// let trigrams: Vec<String> = jamo_ngrams("한 ㅋ", 3)
+///     .map(|trigram| trigram.into_iter().collect())
+///     .collect();
+/// assert_eq!(trigrams, ["ㅎㅏㄴ"]);
+/// ```
+pub fn jamo_ngrams(s: &str, n: usize) -> impl Iterator<Item = Vec<char>> + '_ {
+    windows(
+        s.chars().flat_map(|character| {
+            let (decomposed, standalone) = match Syllable::try_from(character) {
+                Ok(syllable) => (Some(JamoForm::Compatibility.decompose(syllable)), None),
+                Err(_) => (
+                    None,
+                    Some(Some(character).filter(|&character| {
+                        Jaeum::try_from(character).is_ok() || Moeum::try_from(character).is_ok()
+                    })),
+                ),
+            };
+
+            decomposed.into_iter().flatten().map(Some).chain(standalone)
+        }),
+        n,
+    )
+}
+
+/// Counts how many times each run of `n` consecutive Precomposed Korean [`Syllable`]s occurs in a
+/// given string, as [`syllable_ngrams`] yields them.
+///
+/// # Panics
+/// Panics if `n` is 0.
+///
+/// ```
+/// use unikorn::{syllable_ngram_counts, Syllable};
+/// use std::convert::TryFrom;
+///
+/// let counts = syllable_ngram_counts("하하하 하하", 2);
+/// let haha = vec![Syllable::try_from('하').unwrap(); 2];
+/// assert_eq!(counts[&haha], 3);
+/// assert_eq!(counts.len(), 1);
+/// ```
+#[cfg(feature = "std")]
+pub fn syllable_ngram_counts(s: &str, n: usize) -> HashMap<Vec<Syllable>, usize> {
+    let mut counts = HashMap::new();
+    for ngram in syllable_ngrams(s, n) {
+        *counts.entry(ngram).or_insert(0) += 1;
+    }
+
+    counts
+}
+
+// slides a window of `n` over `items`, where a `None` breaks the run and empties the window.
+fn windows<T: Clone>(
+    mut items: impl Iterator<Item = Option<T>>,
+    n: usize,
+) -> impl Iterator<Item = Vec<T>> {
+    assert!(n > 0, "n-gram size must be non-zero");
+    let mut window = Vec::with_capacity(n);
+
+    iter::from_fn(move || loop {
+        match items.next()? {
+            Some(item) => {
+                if window.len() == n {
+                    window.remove(0);
+                }
+                window.push(item);
+                if window.len() == n {
+                    return Some(window.clone());
+                }
+            }
+            None => window.clear(),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{jamo_ngrams, syllable_ngrams};
+    use crate::Syllable;
+    use alloc::{string::String, vec::Vec};
+    use core::convert::TryFrom;
+
+    fn syllables(s: &str) -> Vec<Syllable> {
+        s.chars()
+            .map(|character| Syllable::try_from(character).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_syllable_ngrams() {
+        assert_eq!(
+            syllable_ngrams("가나다라", 3).collect::<Vec<_>>(),
+            [syllables("가나다"), syllables("나다라")]
+        );
+        assert_eq!(
+            syllable_ngrams("가나 다라마", 2).collect::<Vec<_>>(),
+            [syllables("가나"), syllables("다라"), syllables("라마")]
+        );
+        assert_eq!(
+            syllable_ngrams("가ㄱ나", 1).collect::<Vec<_>>(),
+            [syllables("가"), syllables("나")]
+        );
+        assert_eq!(syllable_ngrams("가 나 다", 2).count(), 0);
+        assert_eq!(syllable_ngrams("", 1).count(), 0);
+    }
+
+    #[test]
+    fn test_jamo_ngrams() {
+        let ngrams = |s, n| {
+            jamo_ngrams(s, n)
+                .map(|ngram| ngram.into_iter().collect::<String>())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(ngrams("각", 2), ["ㄱㅏ", "ㅏㄱ"]);
+        assert_eq!(ngrams("가ㅋ", 2), ["ㄱㅏ", "ㅏㅋ"]);
+        assert_eq!(ngrams("가 나", 2), ["ㄱㅏ", "ㄴㅏ"]);
+        // conjoining jamo are not decomposed syllables, and break the run.
+        assert_eq!(ngrams("가\u{1100}\u{1161}", 2), ["ㄱㅏ"]);
+        assert!(ngrams("abc", 1).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "n-gram size must be non-zero")]
+    fn test_syllable_ngrams_zero() {
+        let _ = syllable_ngrams("가나", 0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_syllable_ngram_counts() {
+        let counts = super::syllable_ngram_counts("가나다 가나", 2);
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[&syllables("가나")], 2);
+        assert_eq!(counts[&syllables("나다")], 1);
+    }
+}
@@ -0,0 +1,168 @@
+//! Parallel counterparts of the syllable-by-syllable transformations, for book-length text.
+//!
+//! The string is cut into chunks of about 16 KiB, never in the middle of a [`char`], which are then
+//! processed on the [`rayon`] thread pool. Only what looks at a single syllable at a time has a
+//! counterpart here; e.g. [`pushdown_jongseong`](crate::pushdown_jongseong) looks ahead across
+//! the chunk boundaries, and thus stays sequential.
+#[cfg(feature = "alloc")]
+use crate::JamoForm;
+use crate::Syllable;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use alloc::vec::Vec;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+const CHUNK_SIZE: usize = 16 * 1024;
+
+/// Does the same as [`decompose_with_offsets`](crate::decompose_with_offsets), but in parallel.
+///
+/// ```
+/// use rayon::iter::ParallelIterator;
+/// use unikorn::par_decompose;
+///
+/// let source = "한글 ".repeat(10_000);
+/// assert_eq!(par_decompose(&source).count(), 20_000);
+/// ```
+pub fn par_decompose(s: &str) -> impl ParallelIterator<Item = (usize, Syllable)> + '_ {
+    chunks(s, CHUNK_SIZE)
+        .into_par_iter()
+        .flat_map_iter(|(start, chunk)| {
+            crate::decompose_with_offsets(chunk)
+                .map(move |(offset, syllable)| (start + offset, syllable))
+        })
+}
+
+/// Does the same as [`decompose_syllables`](crate::decompose_syllables), but in parallel.
+///
+/// ```
+/// use unikorn::{decompose_syllables, par_decompose_syllables, JamoForm};
+///
+/// let source = "한글 ".repeat(10_000);
+/// assert_eq!(
+///     par_decompose_syllables(&source, JamoForm::Conjoining),
+///     decompose_syllables(&source, JamoForm::Conjoining)
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn par_decompose_syllables(s: &str, form: JamoForm) -> String {
+    par_concat(s, |chunk| crate::decompose_syllables(chunk, form))
+}
+
+/// Does the same as [`map_syllables`](crate::map_syllables), but in parallel; hence `f` is shared
+/// among the threads, and is not to rely on the order it is called in.
+///
+/// ```
+/// use unikorn::par_map_syllables;
+///
+/// let source = "강물 ".repeat(10_000);
+/// assert_eq!(
+///     par_map_syllables(&source, |syllable| syllable.strip_jongseong()),
+///     "가무 ".repeat(10_000)
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn par_map_syllables<F>(s: &str, f: F) -> String
+where
+    F: Fn(Syllable) -> Syllable + Send + Sync,
+{
+    par_concat(s, |chunk| crate::map_syllables(chunk, &f))
+}
+
+// transforms every chunk with `f` in parallel, and puts the results back together in order.
+#[cfg(feature = "alloc")]
+fn par_concat<F>(s: &str, f: F) -> String
+where
+    F: Fn(&str) -> String + Send + Sync,
+{
+    chunks(s, CHUNK_SIZE)
+        .into_par_iter()
+        .map(|(_, chunk)| f(chunk))
+        .collect::<Vec<_>>()
+        .concat()
+}
+
+// cuts `s` into the chunks of at least `size` bytes, but the last one, each paired with where it
+// starts in `s`.
+fn chunks(s: &str, size: usize) -> Vec<(usize, &str)> {
+    let mut chunks = Vec::with_capacity(s.len() / size + 1);
+
+    let mut start = 0;
+    while start < s.len() {
+        let mut end = (start + size).min(s.len());
+        while !s.is_char_boundary(end) {
+            end += 1;
+        }
+
+        chunks.push((start, &s[start..end]));
+        start = end;
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{chunks, par_decompose};
+    #[cfg(feature = "alloc")]
+    use super::{par_decompose_syllables, par_map_syllables};
+    use crate::decompose_with_offsets;
+    #[cfg(feature = "alloc")]
+    use crate::{decompose_syllables, map_syllables, JamoForm};
+    use rayon::iter::ParallelIterator;
+
+    fn corpus() -> String {
+        "가나다라 abc 마바사, ㄱㄴㄷ \u{1100}\u{1161} 힣!\n".repeat(2_000)
+    }
+
+    #[test]
+    fn test_chunks() {
+        assert!(chunks("", 4).is_empty());
+        assert_eq!(chunks("abc", 4), [(0, "abc")]);
+        assert_eq!(chunks("가나다", 4), [(0, "가나"), (6, "다")]);
+        assert_eq!(chunks("a가나", 2), [(0, "a가"), (4, "나")]);
+
+        let source = corpus();
+        for size in [1, 2, 3, 7, 1024] {
+            let chunks = chunks(&source, size);
+            assert_eq!(
+                chunks.iter().map(|&(_, chunk)| chunk).collect::<String>(),
+                source
+            );
+            for (start, chunk) in chunks {
+                assert_eq!(&source[start..start + chunk.len()], chunk);
+            }
+        }
+    }
+
+    #[test]
+    fn test_par_decompose() {
+        let source = corpus();
+        let mut syllables: Vec<_> = par_decompose(&source).collect();
+        syllables.sort_by_key(|&(offset, _)| offset);
+        assert_eq!(
+            syllables,
+            decompose_with_offsets(&source).collect::<Vec<_>>()
+        );
+        assert_eq!(par_decompose("").count(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_par_decompose_syllables() {
+        let source = corpus();
+        for form in [JamoForm::Conjoining, JamoForm::Compatibility] {
+            assert_eq!(
+                par_decompose_syllables(&source, form),
+                decompose_syllables(&source, form)
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_par_map_syllables() {
+        let source = corpus();
+        let f = |syllable: crate::Syllable| syllable.with_jongseong(None);
+        assert_eq!(par_map_syllables(&source, f), map_syllables(&source, f));
+    }
+}
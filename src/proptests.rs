@@ -0,0 +1,166 @@
+//! Property-based tests checking that every conversion path agrees with one another.
+use crate::{Choseong, Jaeum, Jongseong, Jungseong, Syllable};
+use core::convert::TryFrom;
+use proptest::prelude::*;
+
+// covers Hangul Jamo, Hangul Compatibility Jamo, Precomposed Korean Syllables, and a bit of their
+// surroundings, plus anything else now and then.
+fn korean_ish_char() -> impl Strategy<Value = char> {
+    prop_oneof![
+        prop::char::range('\u{10F0}', '\u{1210}'),
+        prop::char::range('\u{3120}', '\u{3190}'),
+        prop::char::range('\u{ABF0}', '\u{D7B0}'),
+        any::<char>(),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn test_char_round_trip(character in korean_ish_char()) {
+        if let Ok(choseong) = Choseong::try_from(character) {
+            prop_assert_eq!(char::from(choseong), character);
+        }
+        if let Ok(jaeum) = Jaeum::try_from(character) {
+            prop_assert_eq!(char::from(jaeum), character);
+        }
+        if let Ok(jongseong) = Jongseong::try_from(character) {
+            prop_assert_eq!(char::from(jongseong), character);
+        }
+        if let Ok(jungseong) = Jungseong::try_from(character) {
+            prop_assert_eq!(char::from(jungseong), character);
+        }
+        if let Ok(syllable) = Syllable::try_from(character) {
+            prop_assert_eq!(char::from(syllable), character);
+        }
+    }
+
+    #[test]
+    fn test_conjoining_round_trip(character in korean_ish_char()) {
+        if let Ok(choseong) = Choseong::from_conjoining(character) {
+            prop_assert_eq!(choseong.to_conjoining(), character);
+        }
+        if let Ok(jongseong) = Jongseong::from_conjoining(character) {
+            prop_assert_eq!(jongseong.to_conjoining(), character);
+        }
+        if let Ok(jungseong) = Jungseong::from_conjoining(character) {
+            prop_assert_eq!(jungseong.to_conjoining(), character);
+        }
+    }
+
+    #[test]
+    fn test_conversion_paths_agree(character in korean_ish_char()) {
+        let jaeum = Jaeum::try_from(character);
+
+        // char -> Choseong vs. char -> Jaeum -> Choseong
+        match (Choseong::try_from(character), jaeum) {
+            (Ok(choseong), Ok(jaeum)) => {
+                prop_assert_eq!(Choseong::try_from(jaeum), Ok(choseong));
+                prop_assert_eq!(Jaeum::from(choseong), jaeum);
+            }
+            (Ok(_), Err(_)) => prop_assert!(false, "{:?} is a Choseong but not a Jaeum", character),
+            (Err(_), Ok(jaeum)) => prop_assert!(Choseong::try_from(jaeum).is_err()),
+            (Err(_), Err(_)) => {}
+        }
+
+        // char -> Jongseong vs. char -> Jaeum -> Jongseong
+        match (Jongseong::try_from(character), jaeum) {
+            (Ok(jongseong), Ok(jaeum)) => {
+                prop_assert_eq!(Jongseong::try_from(jaeum), Ok(jongseong));
+                prop_assert_eq!(Jaeum::from(jongseong), jaeum);
+            }
+            (Ok(_), Err(_)) => {
+                prop_assert!(false, "{:?} is a Jongseong but not a Jaeum", character)
+            }
+            (Err(_), Ok(jaeum)) => prop_assert!(Jongseong::try_from(jaeum).is_err()),
+            (Err(_), Err(_)) => {}
+        }
+
+        // Jaeum::can_be_* vs. the conversions themselves
+        if let Ok(jaeum) = jaeum {
+            prop_assert_eq!(jaeum.can_be_choseong(), Choseong::try_from(jaeum).is_ok());
+            prop_assert_eq!(jaeum.can_be_jongseong(), Jongseong::try_from(jaeum).is_ok());
+            prop_assert_eq!(jaeum.to_choseong(), Choseong::try_from(jaeum).ok());
+            prop_assert_eq!(jaeum.to_jongseong(), Jongseong::try_from(jaeum).ok());
+        }
+    }
+
+    #[test]
+    fn test_syllable_composition_round_trip(
+        choseong in 0..19u8,
+        jungseong in 0..21u8,
+        jongseong in 0..28u8,
+    ) {
+        let choseong = Choseong::try_from(choseong).unwrap();
+        let jungseong = Jungseong::try_from(jungseong).unwrap();
+        let jongseong = Jongseong::try_from(jongseong).ok();
+
+        let syllable = Syllable::from((choseong, jungseong, jongseong));
+        prop_assert_eq!(
+            <(Choseong, Jungseong, Option<Jongseong>)>::from(syllable),
+            (choseong, jungseong, jongseong)
+        );
+        prop_assert_eq!(Syllable::try_from(char::from(syllable)), Ok(syllable));
+    }
+}
+
+#[cfg(feature = "alloc")]
+proptest! {
+    #[test]
+    fn test_compatibility_jamo_round_trip(
+        syllables in prop::collection::vec(0..11172u32, 0..16),
+        separator in prop::sample::select(vec!["", " ", "a"]),
+    ) {
+        let source = syllables
+            .into_iter()
+            .map(|index| char::from(Syllable::from_index(index).unwrap()).to_string())
+            .collect::<Vec<_>>()
+            .join(separator);
+        let decomposed = crate::decompose_syllables(&source, crate::JamoForm::Compatibility);
+        prop_assert_eq!(crate::compose_orphans(&decomposed), source);
+    }
+
+    #[test]
+    fn test_pushdown_jongseong_idempotent(
+        source in prop::collection::vec(
+            prop_oneof![
+                (0..11172u32).prop_map(|index| char::from(Syllable::from_index(index).unwrap())),
+                // the syllables beginning with a silent 'ㅇ', to have something to link into.
+                (0..588u32).prop_map(|index| {
+                    let ieung = Choseong::Ieung as u32 * 588;
+                    char::from(Syllable::from_index(ieung + index).unwrap())
+                }),
+                Just(' '),
+            ],
+            0..16,
+        ),
+        palatalization in any::<bool>(),
+    ) {
+        let source = source.into_iter().collect::<String>();
+        let options = crate::PushdownOptions {
+            skip_chars: &[' '],
+            palatalization,
+        };
+        let once = crate::pushdown_jongseong_with(&source, options);
+        prop_assert_eq!(crate::pushdown_jongseong_with(&once, options), once);
+    }
+}
+
+#[test]
+fn test_every_syllable_round_trip() {
+    for code_point in 0xAC00..=0xD7A3 {
+        let character = char::from_u32(code_point).unwrap();
+        let syllable = Syllable::try_from(character).unwrap();
+
+        assert_eq!(char::from(syllable), character);
+        assert_eq!(
+            Syllable::try_from(char::from(syllable)),
+            Ok(syllable),
+            "{:?}",
+            character
+        );
+        assert_eq!(
+            Syllable::from((syllable.choseong, syllable.jungseong, syllable.jongseong)),
+            syllable
+        );
+    }
+}
@@ -0,0 +1,226 @@
+//! Telling whether Korean syllables rhyme or alliterate, for the analysis of poetry (시조, Sijo)
+//! and lyrics.
+use crate::{write::compose_conjoining, Choseong, Syllable};
+use core::convert::TryFrom;
+
+/// Checks if two syllables rhyme strictly, i.e. share both the medial vowel (중성, Jungseong) and
+/// the final consonant (종성, Jongseong), or the lack thereof; only the initial consonant may
+/// differ.
+///
+/// ```
+/// use unikorn::{syllables_rhyme, Syllable};
+/// use std::convert::TryFrom;
+///
+/// let syllable = |character| Syllable::try_from(character).unwrap();
+/// assert!(syllables_rhyme(syllable('랑'), syllable('상'))); // 사랑, 세상
+/// assert!(!syllables_rhyme(syllable('랑'), syllable('라')));
+/// ```
+pub fn syllables_rhyme(a: Syllable, b: Syllable) -> bool {
+    a.jungseong == b.jungseong && a.jongseong == b.jongseong
+}
+
+/// Does the same as [`syllables_rhyme`], but only the medial vowels have to match, i.e. checks for
+/// an assonance.
+///
+/// ```
+/// use unikorn::{syllables_rhyme_vowel_only, Syllable};
+/// use std::convert::TryFrom;
+///
+/// let syllable = |character| Syllable::try_from(character).unwrap();
+/// assert!(syllables_rhyme_vowel_only(syllable('랑'), syllable('라')));
+/// assert!(!syllables_rhyme_vowel_only(syllable('랑'), syllable('렁')));
+/// ```
+pub fn syllables_rhyme_vowel_only(a: Syllable, b: Syllable) -> bool {
+    a.jungseong == b.jungseong
+}
+
+/// Iterates over every syllable rhyming strictly with a given one, as [`syllables_rhyme`] says,
+/// the given one included, in Korean dictionary order; i.e. the given syllable with each of the
+/// 19 initial consonants in turn.
+///
+/// ```
+/// use unikorn::{find_rhyming_syllables, Syllable};
+/// use std::convert::TryFrom;
+///
+/// let rhymes: String = find_rhyming_syllables(Syllable::try_from('랑').unwrap())
+///     .take(5)
+///     .map(char::from)
+///     .collect();
+/// assert_eq!(rhymes, "강깡낭당땅");
+/// ```
+pub fn find_rhyming_syllables(
+    target: Syllable,
+) -> impl DoubleEndedIterator<Item = Syllable> + ExactSizeIterator {
+    Choseong::iter().map(move |choseong| target.with_choseong(choseong))
+}
+
+/// Checks if two syllables alliterate, i.e. share the initial consonant (초성, Choseong).
+///
+/// ```
+/// use unikorn::{syllables_alliterate, Syllable};
+/// use std::convert::TryFrom;
+///
+/// let syllable = |character| Syllable::try_from(character).unwrap();
+/// assert!(syllables_alliterate(syllable('바'), syllable('불')));
+/// assert!(!syllables_alliterate(syllable('바'), syllable('빠')));
+/// ```
+pub fn syllables_alliterate(a: Syllable, b: Syllable) -> bool {
+    a.choseong == b.choseong
+}
+
+/// Iterates over every syllable alliterating with a given one, as [`syllables_alliterate`] says,
+/// the given one included, in Korean dictionary order; i.e. all 588 syllables beginning with the
+/// same initial consonant.
+///
+/// ```
+/// use unikorn::{find_alliterating_syllables, Syllable};
+/// use std::convert::TryFrom;
+///
+/// let mut alliterations = find_alliterating_syllables(Syllable::try_from('한').unwrap());
+/// assert_eq!(alliterations.len(), 588);
+/// assert_eq!(alliterations.next().unwrap(), '하');
+/// assert_eq!(alliterations.next_back().unwrap(), '힣');
+/// ```
+pub fn find_alliterating_syllables(
+    target: Syllable,
+) -> impl DoubleEndedIterator<Item = Syllable> + ExactSizeIterator {
+    let first = target.choseong as u32 * 21 * 28;
+    (first..first + 21 * 28).map(|index| Syllable::from_index(index).unwrap())
+}
+
+/// Counts the pairs of consecutive syllables alliterating in a given string, as
+/// [`syllables_alliterate`] says, within a word; i.e. a character neither alphabetic nor numeric,
+/// e.g. a space or a punctuation mark, ends the run, while any other one in between, e.g. a
+/// standalone jamo, is looked past.
+///
+/// A run of conjoining jamo counts as the syllable it composes into, as
+/// [`write_composed`](crate::write_composed) says.
+///
+/// ```
+/// use unikorn::count_alliterating_pairs;
+///
+/// assert_eq!(count_alliterating_pairs("바보, 부부"), 2); // 바보, 부부
+/// assert_eq!(count_alliterating_pairs("가나다"), 0);
+/// assert_eq!(count_alliterating_pairs("\u{1107}\u{1161}\u{1107}\u{1169}"), 1);
+/// ```
+pub fn count_alliterating_pairs(s: &str) -> usize {
+    let mut count = 0;
+    let mut previous = None;
+    for (_, character) in compose_conjoining(s) {
+        if !character.is_alphanumeric() {
+            previous = None;
+            continue;
+        }
+        if let Ok(syllable) = Syllable::try_from(character) {
+            if previous.map_or(false, |previous| syllables_alliterate(previous, syllable)) {
+                count += 1;
+            }
+            previous = Some(syllable);
+        }
+    }
+
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        count_alliterating_pairs, find_alliterating_syllables, find_rhyming_syllables,
+        syllables_alliterate, syllables_rhyme, syllables_rhyme_vowel_only,
+    };
+    use crate::Syllable;
+    use core::convert::TryFrom;
+
+    fn syllable(character: char) -> Syllable {
+        Syllable::try_from(character).unwrap()
+    }
+
+    #[test]
+    fn test_syllables_rhyme() {
+        assert!(syllables_rhyme(syllable('랑'), syllable('상')));
+        assert!(syllables_rhyme(syllable('가'), syllable('하')));
+        assert!(syllables_rhyme(syllable('닭'), syllable('닭')));
+        // the final consonant, or the lack of it, has to match as well.
+        assert!(!syllables_rhyme(syllable('가'), syllable('각')));
+        assert!(!syllables_rhyme(syllable('닭'), syllable('달')));
+        assert!(!syllables_rhyme(syllable('랑'), syllable('렁')));
+    }
+
+    #[test]
+    fn test_syllables_rhyme_vowel_only() {
+        assert!(syllables_rhyme_vowel_only(syllable('가'), syllable('각')));
+        assert!(syllables_rhyme_vowel_only(syllable('닭'), syllable('한')));
+        assert!(!syllables_rhyme_vowel_only(syllable('과'), syllable('가')));
+        assert!(!syllables_rhyme_vowel_only(syllable('애'), syllable('에')));
+
+        // a strict rhyme is an assonance, too.
+        for a in ['가', '랑', '닭'] {
+            for b in ['하', '상', '각', '닭'] {
+                if syllables_rhyme(syllable(a), syllable(b)) {
+                    assert!(syllables_rhyme_vowel_only(syllable(a), syllable(b)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_rhyming_syllables() {
+        let target = syllable('랑');
+        assert_eq!(find_rhyming_syllables(target).len(), 19);
+        assert!(find_rhyming_syllables(target).any(|rhyme| rhyme == target));
+        assert!(find_rhyming_syllables(target).all(|rhyme| syllables_rhyme(rhyme, target)));
+
+        // every one of them, and nothing but them.
+        assert!(find_rhyming_syllables(target)
+            .eq(Syllable::iter_all().filter(|&syllable| syllables_rhyme(syllable, target))));
+        assert_eq!(
+            find_rhyming_syllables(target).next_back(),
+            Some(syllable('항'))
+        );
+    }
+
+    #[test]
+    fn test_syllables_alliterate() {
+        assert!(syllables_alliterate(syllable('가'), syllable('각')));
+        assert!(syllables_alliterate(syllable('바'), syllable('불')));
+        assert!(syllables_alliterate(syllable('아'), syllable('읽')));
+        assert!(!syllables_alliterate(syllable('가'), syllable('까')));
+        assert!(!syllables_alliterate(syllable('가'), syllable('카')));
+    }
+
+    #[test]
+    fn test_find_alliterating_syllables() {
+        for target in [syllable('가'), syllable('밥'), syllable('힣')] {
+            assert_eq!(find_alliterating_syllables(target).len(), 588);
+            assert!(find_alliterating_syllables(target).any(|other| other == target));
+            assert!(find_alliterating_syllables(target)
+                .eq(Syllable::iter_all().filter(|&other| syllables_alliterate(other, target))));
+        }
+        assert_eq!(
+            find_alliterating_syllables(syllable('밥')).next(),
+            Some(syllable('바'))
+        );
+    }
+
+    #[test]
+    fn test_count_alliterating_pairs() {
+        assert_eq!(count_alliterating_pairs(""), 0);
+        assert_eq!(count_alliterating_pairs("가"), 0);
+        assert_eq!(count_alliterating_pairs("가각"), 1);
+        assert_eq!(count_alliterating_pairs("가각간"), 2);
+        assert_eq!(count_alliterating_pairs("가나가"), 0);
+        // a space or a punctuation mark ends the run.
+        assert_eq!(count_alliterating_pairs("가, 각!"), 0);
+        assert_eq!(count_alliterating_pairs("가각 각가"), 2);
+        assert_eq!(count_alliterating_pairs("abc"), 0);
+        // any other character is looked past; standalone jamo are not syllables.
+        assert_eq!(count_alliterating_pairs("가a각"), 1);
+        assert_eq!(count_alliterating_pairs("가ㄱ가"), 1);
+        // conjoining jamo count as the syllables they compose into.
+        assert_eq!(
+            count_alliterating_pairs("\u{1100}\u{1161}\u{1100}\u{1161}\u{11A8}"),
+            1
+        );
+        assert_eq!(count_alliterating_pairs("가\u{1100}\u{1161}"), 1);
+    }
+}
@@ -0,0 +1,413 @@
+//! Scanning strings for Precomposed Korean syllables without decoding them into [`char`]s.
+//!
+//! Every syllable (U+AC00 '가' -- U+D7A3 '힣') is encoded in UTF-8 as three bytes, the first of
+//! which is one of 0xEA -- 0xED. Since the input is a valid `str`, such a byte always begins a
+//! three-byte sequence, so it is enough to look for those bytes and check the code point each one
+//! begins. With the `simd` feature on x86 and x86-64, the search for them goes 16 bytes at a time.
+use crate::Syllable;
+use core::{
+    iter,
+    ops::{Bound, Range, RangeBounds},
+};
+
+/// Checks if a given string contains at least one Precomposed Korean
+/// [`Syllable`](crate::Syllable).
+///
+/// ```
+/// use unikorn::is_any_korean;
+///
+/// assert!(is_any_korean("hello, 세계"));
+/// assert!(!is_any_korean("hello, world"));
+/// assert!(!is_any_korean("ㄱㄴㄷ")); // only the jamo
+/// ```
+pub fn is_any_korean(s: &str) -> bool {
+    let bytes = s.as_bytes();
+
+    let mut from = 0;
+    while let Some(index) = next_candidate(bytes, from) {
+        if is_syllable_at(bytes, index) {
+            return true;
+        }
+        from = index + 1;
+    }
+
+    false
+}
+
+/// Counts the Precomposed Korean [`Syllable`](crate::Syllable)s in a given string.
+///
+/// ```
+/// use unikorn::count_korean_syllables;
+///
+/// assert_eq!(count_korean_syllables("한글 ok, 漢字 no"), 2);
+/// ```
+pub fn count_korean_syllables(s: &str) -> usize {
+    let bytes = s.as_bytes();
+
+    let mut count = 0;
+    let mut from = 0;
+    while let Some(index) = next_candidate(bytes, from) {
+        if is_syllable_at(bytes, index) {
+            count += 1;
+        }
+        from = index + 1;
+    }
+
+    count
+}
+
+/// Finds the byte offset of the first Precomposed Korean [`Syllable`](crate::Syllable) in a given
+/// string, if any.
+///
+/// ```
+/// use unikorn::find_korean_syllable;
+///
+/// assert_eq!(find_korean_syllable("hello, 세계"), Some(7));
+/// assert_eq!(find_korean_syllable("ㄱㄴㄷ"), None);
+/// ```
+pub fn find_korean_syllable(s: &str) -> Option<usize> {
+    let bytes = s.as_bytes();
+
+    let mut from = 0;
+    while let Some(index) = next_candidate(bytes, from) {
+        if is_syllable_at(bytes, index) {
+            return Some(index);
+        }
+        from = index + 1;
+    }
+
+    None
+}
+
+/// Iterates over every Precomposed Korean [`Syllable`] in a given string alongside its byte
+/// offset, without allocating anything.
+///
+/// ```
+/// use unikorn::{decompose_with_offsets, Syllable};
+/// use std::convert::TryFrom;
+///
+/// let mut syllables = decompose_with_offsets("a 한글");
+/// assert_eq!(syllables.next(), Some((2, Syllable::try_from('한').unwrap())));
+/// assert_eq!(syllables.next(), Some((5, Syllable::try_from('글').unwrap())));
+/// assert_eq!(syllables.next(), None);
+/// ```
+pub fn decompose_with_offsets(s: &str) -> impl Iterator<Item = (usize, Syllable)> + '_ {
+    let bytes = s.as_bytes();
+
+    let mut from = 0;
+    core::iter::from_fn(move || {
+        while let Some(index) = next_candidate(bytes, from) {
+            from = index + 1;
+            if let Ok(syllable) = Syllable::try_from_u32(code_point_at(bytes, index)) {
+                from = index + 3;
+                return Some((index, syllable));
+            }
+        }
+
+        None
+    })
+}
+
+/// Does the same as [`decompose_with_offsets`], but with the byte range each syllable takes up in
+/// a given string, which is always 3 bytes long, so that it can be sliced or annotated right away.
+///
+/// ```
+/// use unikorn::decompose_spans;
+///
+/// let source = "a 한글";
+/// let spans: Vec<_> = decompose_spans(source).map(|(span, _)| &source[span]).collect();
+/// assert_eq!(spans, ["한", "글"]);
+/// ```
+pub fn decompose_spans(s: &str) -> impl Iterator<Item = (Range<usize>, Syllable)> + '_ {
+    decompose_with_offsets(s).map(|(offset, syllable)| (offset..offset + 3, syllable))
+}
+
+/// Slices a given string by the positions of the Precomposed Korean [`Syllable`]s in it, counting
+/// only the syllables, or returns [`None`] if `range` is out of bounds.
+///
+/// The slice begins right at the first syllable in `range` and ends right after the last one,
+/// taking whatever lies in between along; an unbounded end reaches the corresponding end of the
+/// string instead.
+///
+/// ```
+/// use unikorn::get_syllable_slice;
+///
+/// let s = "<안녕, 세상>";
+/// assert_eq!(get_syllable_slice(s, 1..3), Some("녕, 세"));
+/// assert_eq!(get_syllable_slice(s, ..2), Some("<안녕"));
+/// assert_eq!(get_syllable_slice(s, 3..), Some("상>"));
+/// assert_eq!(get_syllable_slice(s, 3..5), None);
+/// ```
+pub fn get_syllable_slice<R: RangeBounds<usize>>(s: &str, range: R) -> Option<&str> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => Some(start),
+        Bound::Excluded(&start) => Some(start.checked_add(1)?),
+        Bound::Unbounded => None,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => Some(end.checked_add(1)?),
+        Bound::Excluded(&end) => Some(end),
+        Bound::Unbounded => None,
+    };
+
+    // the offset of the syllable at a position, or the end of `s` right past the last one.
+    let from = match start {
+        Some(start) => decompose_with_offsets(s)
+            .map(|(offset, _)| offset)
+            .chain(iter::once(s.len()))
+            .nth(start)?,
+        None => 0,
+    };
+    match end {
+        Some(end) if end <= start.unwrap_or(0) => {
+            if end < start.unwrap_or(0) {
+                return None;
+            }
+            Some(&s[from..from])
+        }
+        Some(end) => {
+            let (offset, _) = decompose_with_offsets(s).nth(end - 1)?;
+            Some(&s[from..offset + 3])
+        }
+        None => Some(&s[from..]),
+    }
+}
+
+/// Does the same as [`get_syllable_slice`], but panics if `range` is out of bounds, the way
+/// indexing a [`str`] does.
+///
+/// ```
+/// use unikorn::syllable_slice;
+///
+/// assert_eq!(syllable_slice("한국어 text", 1..=2), "국어");
+/// ```
+///
+/// ```should_panic
+/// use unikorn::syllable_slice;
+///
+/// syllable_slice("한국어", 2..4);
+/// ```
+pub fn syllable_slice<R: RangeBounds<usize>>(s: &str, range: R) -> &str {
+    match get_syllable_slice(s, range) {
+        Some(slice) => slice,
+        None => panic!(
+            "syllable range out of bounds of a string with {} syllables",
+            count_korean_syllables(s)
+        ),
+    }
+}
+
+// whether the three-byte sequence beginning at `index` encodes a syllable; `bytes[index]` must be
+// one of 0xEA -- 0xED in a valid UTF-8 string.
+fn is_syllable_at(bytes: &[u8], index: usize) -> bool {
+    (0xAC00..=0xD7A3).contains(&code_point_at(bytes, index))
+}
+
+// decodes the three-byte sequence beginning at `index`, under the same condition as above.
+fn code_point_at(bytes: &[u8], index: usize) -> u32 {
+    (u32::from(bytes[index] & 0x0F) << 12)
+        | (u32::from(bytes[index + 1] & 0x3F) << 6)
+        | u32::from(bytes[index + 2] & 0x3F)
+}
+
+// the index of the first byte in 0xEA -- 0xED at or after `from`, if any.
+#[cfg(not(all(
+    feature = "simd",
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+)))]
+fn next_candidate(bytes: &[u8], from: usize) -> Option<usize> {
+    next_candidate_scalar(bytes, from)
+}
+
+#[cfg(all(
+    feature = "simd",
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+fn next_candidate(bytes: &[u8], from: usize) -> Option<usize> {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::{
+        __m128i, _mm_cmpeq_epi8, _mm_loadu_si128, _mm_min_epu8, _mm_movemask_epi8, _mm_set1_epi8,
+        _mm_sub_epi8,
+    };
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::{
+        __m128i, _mm_cmpeq_epi8, _mm_loadu_si128, _mm_min_epu8, _mm_movemask_epi8, _mm_set1_epi8,
+        _mm_sub_epi8,
+    };
+
+    let mut index = from;
+    while index + 16 <= bytes.len() {
+        // SAFETY: SSE2 is enabled at compile time, and the 16 bytes from `index` are in bounds;
+        // `_mm_loadu_si128` has no alignment requirement.
+        let mask = unsafe {
+            let chunk = _mm_loadu_si128(bytes.as_ptr().add(index) as *const __m128i);
+            // a byte is in 0xEA -- 0xED iff it is at most 3 after subtracting 0xEA.
+            let offset = _mm_sub_epi8(chunk, _mm_set1_epi8(0xEAu8 as i8));
+            let in_range = _mm_cmpeq_epi8(_mm_min_epu8(offset, _mm_set1_epi8(3)), offset);
+            _mm_movemask_epi8(in_range) as u32
+        };
+        if mask != 0 {
+            return Some(index + mask.trailing_zeros() as usize);
+        }
+
+        index += 16;
+    }
+
+    next_candidate_scalar(bytes, index)
+}
+
+fn next_candidate_scalar(bytes: &[u8], from: usize) -> Option<usize> {
+    bytes
+        .get(from..)?
+        .iter()
+        .position(|byte| (0xEA..=0xED).contains(byte))
+        .map(|position| from + position)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        count_korean_syllables, decompose_spans, decompose_with_offsets, find_korean_syllable,
+        get_syllable_slice, is_any_korean, syllable_slice,
+    };
+    use crate::Syllable;
+    use core::{convert::TryFrom, ops::Bound};
+
+    fn count_by_chars(s: &str) -> usize {
+        s.chars()
+            .filter(|&character| Syllable::is_one_of_us(character))
+            .count()
+    }
+
+    #[test]
+    fn test_is_any_korean() {
+        assert!(is_any_korean("가"));
+        assert!(is_any_korean("힣"));
+        assert!(is_any_korean(&format!("{}한", "a".repeat(40))));
+
+        assert!(!is_any_korean(""));
+        assert!(!is_any_korean(&"abc".repeat(20)));
+        // right around the syllables, sharing the lead bytes with them.
+        assert!(!is_any_korean("\u{ABFF}\u{D7A4}\u{D7B0}\u{A960}"));
+        assert!(!is_any_korean("ㄱㅏ\u{1100}\u{1161}"));
+    }
+
+    #[test]
+    fn test_count_korean_syllables() {
+        assert_eq!(count_korean_syllables(""), 0);
+        assert_eq!(count_korean_syllables("가나다"), 3);
+        assert_eq!(count_korean_syllables("\u{ABFF}가\u{D7A3}\u{D7A4}"), 2);
+
+        // every syllable at every offset around the 16-byte chunks.
+        for padding in 0..=32 {
+            let s = format!(
+                "{}각\u{D7A4}{}힣{}",
+                "a".repeat(padding),
+                "b".repeat(padding),
+                "\u{D7FB}"
+            );
+            assert_eq!(count_korean_syllables(&s), 2, "{:?}", s);
+            assert_eq!(count_korean_syllables(&s), count_by_chars(&s));
+        }
+
+        let mixed = "한국어 텍스트와 English text, そして日本語 ＡＢＣ, 그리고 ㄱㄴㄷ".repeat(7);
+        assert_eq!(count_korean_syllables(&mixed), count_by_chars(&mixed));
+    }
+
+    #[test]
+    fn test_find_korean_syllable() {
+        assert_eq!(find_korean_syllable(""), None);
+        assert_eq!(find_korean_syllable("가"), Some(0));
+        assert_eq!(find_korean_syllable("\u{ABFF}\u{D7A4}ㄱ"), None);
+
+        for padding in 0..=32 {
+            let s = format!("{}\u{D7B0}{}힣", "a".repeat(padding), "b".repeat(padding));
+            assert_eq!(find_korean_syllable(&s), Some(2 * padding + 3), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn test_decompose_with_offsets() {
+        assert_eq!(decompose_with_offsets("").next(), None);
+        assert_eq!(decompose_with_offsets("abc ㄱ\u{D7A4}").next(), None);
+
+        let source = format!("{}각 ok\u{D7FB}힣{}", "a".repeat(20), "ㅏ".repeat(10));
+        let expected: Vec<_> = source
+            .char_indices()
+            .filter_map(|(offset, character)| {
+                Syllable::try_from(character)
+                    .ok()
+                    .map(|syllable| (offset, syllable))
+            })
+            .collect();
+        assert_eq!(
+            decompose_with_offsets(&source).collect::<Vec<_>>(),
+            expected
+        );
+        assert_eq!(expected.len(), 2);
+    }
+
+    #[test]
+    fn test_decompose_spans() {
+        let source = "가, 나다";
+        let spans: Vec<_> = decompose_spans(source).collect();
+        assert_eq!(
+            spans,
+            [
+                (0..3, Syllable::try_from('가').unwrap()),
+                (5..8, Syllable::try_from('나').unwrap()),
+                (8..11, Syllable::try_from('다').unwrap()),
+            ]
+        );
+        for (span, syllable) in spans {
+            assert_eq!(source[span].chars().next(), Some(char::from(syllable)));
+        }
+    }
+
+    #[test]
+    fn test_get_syllable_slice() {
+        let s = "a가 나b다";
+        assert_eq!(get_syllable_slice(s, ..), Some(s));
+        assert_eq!(get_syllable_slice(s, 0..), Some("가 나b다"));
+        assert_eq!(get_syllable_slice(s, 0..1), Some("가"));
+        assert_eq!(get_syllable_slice(s, 0..=1), Some("가 나"));
+        assert_eq!(get_syllable_slice(s, 1..3), Some("나b다"));
+        assert_eq!(get_syllable_slice(s, ..1), Some("a가"));
+        assert_eq!(get_syllable_slice(s, 2..), Some("다"));
+        assert_eq!(
+            get_syllable_slice(s, (Bound::Excluded(0), Bound::Included(1))),
+            Some("나")
+        );
+
+        // empty, yet in bounds.
+        assert_eq!(get_syllable_slice(s, 1..1), Some(""));
+        assert_eq!(get_syllable_slice(s, 3..), Some(""));
+        assert_eq!(get_syllable_slice(s, 3..3), Some(""));
+        assert_eq!(get_syllable_slice(s, ..0), Some(""));
+
+        // out of bounds.
+        assert_eq!(get_syllable_slice(s, 0..4), None);
+        assert_eq!(get_syllable_slice(s, 4..), None);
+        assert_eq!(
+            get_syllable_slice(s, (Bound::Included(2), Bound::Excluded(1))),
+            None
+        );
+        assert_eq!(get_syllable_slice(s, ..=usize::MAX), None);
+        assert_eq!(get_syllable_slice("abc", 0..1), None);
+        assert_eq!(get_syllable_slice("abc", 0..0), Some(""));
+        assert_eq!(get_syllable_slice("abc", ..), Some("abc"));
+    }
+
+    #[test]
+    fn test_syllable_slice() {
+        assert_eq!(syllable_slice("가나다", 1..), "나다");
+    }
+
+    #[test]
+    #[should_panic(expected = "syllable range out of bounds")]
+    fn test_syllable_slice_out_of_bounds() {
+        syllable_slice("가나다", 3..4);
+    }
+}
@@ -0,0 +1,110 @@
+//! Statistics over the Korean portion of a text.
+use crate::{Choseong, Jongseong, Jungseong, Syllable};
+use std::{collections::HashMap, convert::TryFrom};
+
+/// Holds how many times each consonant and vowel occurred in a text, as counted by
+/// [`jamo_frequencies`].
+///
+/// Only the Precomposed Korean [`Syllable`]s count; a component that never occurred is absent from
+/// its map rather than mapped to 0.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct JamoStats {
+    /// Counts of each initial consonant (초성, [`Choseong`]).
+    pub choseong: HashMap<Choseong, usize>,
+    /// Counts of each medial vowel (중성, [`Jungseong`]).
+    pub jungseong: HashMap<Jungseong, usize>,
+    /// Counts of each final consonant (종성, [`Jongseong`]).
+    pub jongseong: HashMap<Jongseong, usize>,
+    /// Count of the syllables without a final consonant.
+    pub without_jongseong: usize,
+}
+impl JamoStats {
+    /// Returns the initial consonant occurred the most, or [`None`] if there was no syllable at
+    /// all.
+    ///
+    /// In case of a tie, the one coming first in Korean dictionary order wins.
+    ///
+    /// ```
+    /// use unikorn::{jamo_frequencies, Choseong};
+    ///
+    /// assert_eq!(
+    ///     jamo_frequencies("가나나다").most_common_choseong(),
+    ///     Some(Choseong::Nieun)
+    /// );
+    /// assert_eq!(jamo_frequencies("abc").most_common_choseong(), None);
+    /// ```
+    pub fn most_common_choseong(&self) -> Option<Choseong> {
+        self.choseong
+            .iter()
+            .max_by(|(a, a_count), (b, b_count)| a_count.cmp(b_count).then(b.cmp(a)))
+            .map(|(&choseong, _)| choseong)
+    }
+}
+
+/// Tallies every consonant and vowel of the Precomposed Korean [`Syllable`]s in a given string.
+///
+/// Anything other than a [`Syllable`], including a standalone consonant or vowel, is ignored.
+///
+/// ```
+/// use unikorn::{jamo_frequencies, Choseong, Jongseong, Jungseong};
+///
+/// let stats = jamo_frequencies("한글");
+/// assert_eq!(stats.choseong[&Choseong::Hieuh], 1);
+/// assert_eq!(stats.jungseong[&Jungseong::Eu], 1);
+/// assert_eq!(stats.jongseong[&Jongseong::Nieun], 1);
+/// assert_eq!(stats.without_jongseong, 0);
+/// ```
+pub fn jamo_frequencies(s: &str) -> JamoStats {
+    let mut stats = JamoStats::default();
+
+    for syllable in s
+        .chars()
+        .filter_map(|character| Syllable::try_from(character).ok())
+    {
+        *stats.choseong.entry(syllable.choseong).or_insert(0) += 1;
+        *stats.jungseong.entry(syllable.jungseong).or_insert(0) += 1;
+        match syllable.jongseong {
+            Some(jongseong) => *stats.jongseong.entry(jongseong).or_insert(0) += 1,
+            None => stats.without_jongseong += 1,
+        }
+    }
+
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{jamo_frequencies, JamoStats};
+    use crate::{Choseong, Jongseong, Jungseong};
+
+    #[test]
+    fn test_jamo_frequencies() {
+        let stats = jamo_frequencies("가나다 각 ㄱ abc 강");
+        assert_eq!(stats.choseong.len(), 3);
+        assert_eq!(stats.choseong[&Choseong::Kiyeok], 3);
+        assert_eq!(stats.choseong[&Choseong::Nieun], 1);
+        assert_eq!(stats.choseong[&Choseong::Tikeut], 1);
+        assert_eq!(stats.jungseong.len(), 1);
+        assert_eq!(stats.jungseong[&Jungseong::A], 5);
+        assert_eq!(stats.jongseong.len(), 2);
+        assert_eq!(stats.jongseong[&Jongseong::Kiyeok], 1);
+        assert_eq!(stats.jongseong[&Jongseong::Ieung], 1);
+        assert_eq!(stats.without_jongseong, 3);
+
+        assert_eq!(jamo_frequencies(""), JamoStats::default());
+        assert_eq!(jamo_frequencies("ㄱㅏ abc"), JamoStats::default());
+    }
+
+    #[test]
+    fn test_jamo_stats_most_common_choseong() {
+        assert_eq!(
+            jamo_frequencies("하하호호 가").most_common_choseong(),
+            Some(Choseong::Hieuh)
+        );
+        assert_eq!(
+            jamo_frequencies("하가나").most_common_choseong(),
+            Some(Choseong::Kiyeok)
+        );
+        assert_eq!(jamo_frequencies("").most_common_choseong(), None);
+    }
+}
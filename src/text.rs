@@ -0,0 +1,1964 @@
+//! Whole-string transformations of Korean text.
+use crate::{
+    forms::is_archaic, jamo::halfwidth_to_compat, scan::decompose_with_offsets,
+    write::compose_conjoining, Choseong, ConversionFallback, Error, Jaeum, Jamo, JamoForm,
+    JamoKind, Jongseong, Jungseong, Syllable,
+};
+use alloc::{string::String, vec::Vec};
+use core::convert::TryFrom;
+
+/// Converts every Halfwidth Hangul jamo (U+FFA1 'ﾡ' -- U+FFDC 'ￜ') in a given string into its
+/// counterpart in Hangul Compatibility Jamo range, leaving everything else untouched.
+///
+/// Korean has no letter case to fold, so this is the closest thing to a case-insensitive
+/// normalization before comparing strings from mixed sources.
+///
+/// ```
+/// use unikorn::normalize_halfwidth;
+///
+/// assert_eq!(normalize_halfwidth("\u{FFA1}\u{FFC2}"), "ㄱㅏ");
+/// assert_eq!(normalize_halfwidth("abc\u{FFBE}def"), "abcㅎdef");
+/// ```
+pub fn normalize_halfwidth(s: &str) -> String {
+    s.chars()
+        .map(|character| halfwidth_to_compat(character).unwrap_or(character))
+        .collect()
+}
+
+/// Removes the final consonant (종성, [`Jongseong`]) from every Precomposed Korean [`Syllable`] in
+/// a given string, leaving everything else untouched.
+///
+/// ```
+/// use unikorn::strip_jongseong;
+///
+/// assert_eq!(strip_jongseong("강물이 흐른다"), "가무이 흐르다");
+/// ```
+pub fn strip_jongseong(s: &str) -> String {
+    set_jongseong(s, None)
+}
+
+/// Reverses the order of the syllables in a given string, keeping each syllable intact.
+///
+/// Any run of modern conjoining jamo (Hangul Jamo range, U+1100 -- U+11FF), i.e. an initial
+/// consonant followed by a medial vowel and optionally a final consonant, is composed into a
+/// Precomposed Korean [`Syllable`] beforehand, and so is a [`Syllable`] without a final consonant
+/// followed by a conjoining final consonant. Everything else is reversed [`char`] by [`char`].
+///
+/// ```
+/// use unikorn::reverse_syllables;
+///
+/// assert_eq!(reverse_syllables("한글 ok"), "ko 글한");
+/// assert_eq!(reverse_syllables("\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}"), "글한");
+/// ```
+pub fn reverse_syllables(s: &str) -> String {
+    let mut composed: Vec<_> = compose_conjoining(s).collect();
+    composed.reverse();
+    composed
+        .into_iter()
+        .map(|(_, character)| character)
+        .collect()
+}
+
+/// Does the same as [`reverse_syllables`], but reverses the Korean syllables alone, leaving every
+/// other [`char`] where it was, i.e. the syllables trade places with one another only.
+///
+/// ```
+/// use unikorn::reverse_syllables_in_place;
+///
+/// assert_eq!(reverse_syllables_in_place("ABC 한글"), "ABC 글한");
+/// assert_eq!(reverse_syllables_in_place("가, 나, 다!"), "다, 나, 가!");
+/// ```
+pub fn reverse_syllables_in_place(s: &str) -> String {
+    let composed: Vec<_> = compose_conjoining(s)
+        .map(|(_, character)| character)
+        .collect();
+    let mut reversed = composed
+        .iter()
+        .rev()
+        .filter(|&&character| Syllable::is_one_of_us(character));
+
+    composed
+        .iter()
+        .map(|&character| {
+            if Syllable::is_one_of_us(character) {
+                *reversed.next().unwrap()
+            } else {
+                character
+            }
+        })
+        .collect()
+}
+
+/// Reverses a given string jamo by jamo rather than syllable by syllable, i.e. decomposes every
+/// syllable into the Hangul Compatibility Jamo, reverses the whole, and composes the jamo back
+/// into syllables as [`compose_orphans`] does. Everything else is reversed [`char`] by [`char`],
+/// as [`reverse_syllables`] does.
+///
+/// A final consonant thus becomes the initial consonant of the syllable before it, and whatever
+/// does not fit into a syllable, e.g. a vowel left first, stays as a standalone jamo.
+///
+/// ```
+/// use unikorn::reverse_jamo;
+///
+/// assert_eq!(reverse_jamo("한글"), "륵낳");
+/// assert_eq!(reverse_jamo("기차 ok"), "ko ㅏ칙");
+/// assert_eq!(reverse_jamo(&reverse_jamo("다리")), "다리");
+/// ```
+pub fn reverse_jamo(s: &str) -> String {
+    let mut flattened = Vec::with_capacity(s.len());
+    for (_, character) in compose_conjoining(s) {
+        match Syllable::try_from(character) {
+            Ok(syllable) => flattened.extend(JamoForm::Compatibility.decompose(syllable)),
+            Err(_) => flattened.push(character),
+        }
+    }
+    flattened.reverse();
+
+    compose_orphans(&flattened.into_iter().collect::<String>())
+}
+
+/// Checks if the Korean syllables in a given string read the same forwards and backwards,
+/// ignoring everything else, e.g. the spaces and the punctuation. A run of conjoining jamo counts
+/// as the syllable it composes into, and a string without any syllable is a palindrome.
+///
+/// ```
+/// use unikorn::is_syllable_palindrome;
+///
+/// assert!(is_syllable_palindrome("다시 합창합시다"));
+/// assert!(is_syllable_palindrome("소주 만 병만 주소!"));
+/// assert!(!is_syllable_palindrome("기러기 아빠"));
+/// ```
+pub fn is_syllable_palindrome(s: &str) -> bool {
+    let syllables: Vec<_> = compose_conjoining(s)
+        .filter_map(|(_, character)| Syllable::try_from(character).ok())
+        .collect();
+
+    syllables.iter().eq(syllables.iter().rev())
+}
+
+/// Does the same as [`is_syllable_palindrome`], but reads jamo by jamo, i.e. decomposes every
+/// syllable into the Hangul Compatibility Jamo first, as [`reverse_jamo`] does. A clustered
+/// consonant or a diphthong counts as one jamo, e.g. 'ㄺ' or 'ㅘ'.
+///
+/// ```
+/// use unikorn::is_jamo_palindrome;
+///
+/// assert!(is_jamo_palindrome("각")); // ㄱㅏㄱ
+/// assert!(is_jamo_palindrome("임밍")); // ㅇㅣㅁㅁㅣㅇ
+/// assert!(!is_jamo_palindrome("기러기")); // ㄱㅣㄹㅓㄱㅣ
+/// ```
+pub fn is_jamo_palindrome(s: &str) -> bool {
+    let mut jamo = Vec::with_capacity(s.len());
+    for (_, character) in compose_conjoining(s) {
+        if let Ok(syllable) = Syllable::try_from(character) {
+            jamo.extend(JamoForm::Compatibility.decompose(syllable));
+        }
+    }
+
+    jamo.iter().eq(jamo.iter().rev())
+}
+
+/// Replaces the final consonant (종성, [`Jongseong`]) of every Precomposed Korean [`Syllable`] in a
+/// given string with `jongseong`, leaving everything else untouched.
+///
+/// ```
+/// use unikorn::{set_jongseong, Jongseong};
+///
+/// assert_eq!(set_jongseong("가나 abc", Some(Jongseong::Ieung)), "강낭 abc");
+/// assert_eq!(set_jongseong("강물", None), "가무");
+/// ```
+pub fn set_jongseong(s: &str, jongseong: Option<Jongseong>) -> String {
+    map_syllables(s, |syllable| syllable.with_jongseong(jongseong))
+}
+
+/// Makes a given string sound cute (애교, Aegyo) the way chat messages often do, by altering the last
+/// syllable of every word, e.g. '했어' into '했엉'.
+///
+/// A word ends where a [`char`] is followed by the end of the string or by anything other than an
+/// alphanumeric one, i.e. whitespace or punctuation; a word not ending in a Precomposed Korean
+/// [`Syllable`] is left untouched. See [`CuteStyle`] for what happens to the last syllable.
+///
+/// ```
+/// use unikorn::{cuteify, CuteStyle};
+///
+/// assert_eq!(cuteify("했어? 밥", CuteStyle::AppendIeungJongseong), "했엉? 밥");
+/// assert_eq!(cuteify("좋아!", CuteStyle::EchoVowel), "좋아아!");
+/// assert_eq!(cuteify("좋아 ok", CuteStyle::Both), "좋아앙 ok");
+/// ```
+pub fn cuteify(source: &str, style: CuteStyle) -> String {
+    let mut cuteified = String::with_capacity(source.len() * 2);
+    let mut characters = source.chars().peekable();
+
+    while let Some(character) = characters.next() {
+        let syllable = match Syllable::try_from(character) {
+            Ok(syllable)
+                if !characters
+                    .peek()
+                    .map_or(false, |next| next.is_alphanumeric()) =>
+            {
+                syllable
+            }
+            _ => {
+                cuteified.push(character);
+                continue;
+            }
+        };
+
+        let add_ieung = |syllable: Syllable| match syllable.jongseong {
+            None => syllable.with_jongseong(Some(Jongseong::Ieung)),
+            Some(_) => syllable,
+        };
+        let echo = Syllable::from((syllable.jungseong, None));
+        match style {
+            CuteStyle::AppendIeungJongseong => cuteified.push(add_ieung(syllable).into()),
+            CuteStyle::EchoVowel => cuteified.extend([char::from(syllable), echo.into()]),
+            CuteStyle::Both => cuteified.extend([char::from(syllable), add_ieung(echo).into()]),
+        }
+    }
+
+    cuteified
+}
+
+/// Decides what [`cuteify`] does to the last syllable of every word.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CuteStyle {
+    /// Adds a final 'ㅇ' to the syllable, if it has no final consonant yet, e.g. '했어' into '했엉';
+    /// a closed syllable is left as it is, e.g. '밥'.
+    AppendIeungJongseong,
+    /// Appends a syllable of a silent 'ㅇ' and the same medial vowel, e.g. '좋아' into '좋아아', or
+    /// '밥' into '밥아'.
+    EchoVowel,
+    /// Does [`CuteStyle::EchoVowel`], then [`CuteStyle::AppendIeungJongseong`] on the appended
+    /// syllable, e.g. '좋아' into '좋아앙'.
+    Both,
+}
+
+/// Transforms every Precomposed Korean [`Syllable`] in a given string with `f`, leaving everything
+/// else untouched.
+///
+/// This is the loop the syllable-by-syllable transformations, e.g. [`set_jongseong`] and
+/// [`fold_korean`](crate::fold_korean), are built upon; a custom one is a closure away.
+///
+/// ```
+/// use unikorn::{map_syllables, Choseong};
+///
+/// assert_eq!(
+///     map_syllables("달 ok", |syllable| syllable.with_choseong(Choseong::Thieuth)),
+///     "탈 ok"
+/// );
+/// ```
+pub fn map_syllables(s: &str, mut f: impl FnMut(Syllable) -> Syllable) -> String {
+    let mut mapped = String::with_capacity(s.len());
+
+    let mut copied = 0;
+    for (offset, syllable) in decompose_with_offsets(s) {
+        mapped.push_str(&s[copied..offset]);
+        mapped.push(f(syllable).into());
+        copied = offset + 3;
+    }
+    mapped.push_str(&s[copied..]);
+
+    mapped
+}
+
+/// Transforms every Precomposed Korean [`Syllable`] in a given string with `f`, looking one
+/// syllable ahead, leaving everything else untouched.
+///
+/// `f` is given a syllable and the one right after it, if the very next [`char`] is a syllable at
+/// all, and returns what to replace the former with, and optionally what to replace the latter
+/// with, before it is in turn given to `f`. This is what a transformation moving a consonant across
+/// a syllable boundary looks like, e.g. linking (연음, Yeoneum) a final consonant into the next
+/// syllable:
+///
+/// ```
+/// use unikorn::{map_syllable_pairs, Choseong};
+///
+/// let linked = map_syllable_pairs("닭이 먹어", |current, next| match (current.jongseong, next) {
+///     (Some(jongseong), Some(next)) if next.choseong == Choseong::Ieung => {
+///         let (remaining, moved) = jongseong.resyllabify();
+///         (current.with_jongseong(remaining), Some(next.with_choseong(moved)))
+///     }
+///     _ => (current, None),
+/// });
+/// assert_eq!(linked, "달기 머거");
+/// ```
+pub fn map_syllable_pairs(
+    s: &str,
+    mut f: impl FnMut(Syllable, Option<Syllable>) -> (Syllable, Option<Syllable>),
+) -> String {
+    let mut mapped = String::with_capacity(s.len());
+    let mut characters = s.chars().peekable();
+    // what `f` replaced the upcoming syllable with, if anything.
+    let mut replacement = None;
+
+    while let Some(character) = characters.next() {
+        let current = match Syllable::try_from(character) {
+            Ok(syllable) => replacement.take().unwrap_or(syllable),
+            Err(_) => {
+                mapped.push(character);
+                continue;
+            }
+        };
+        let next = characters
+            .peek()
+            .and_then(|&character| Syllable::try_from(character).ok());
+
+        let (current, update) = f(current, next);
+        mapped.push(current.into());
+        replacement = next.and(update);
+    }
+
+    mapped
+}
+
+/// Links (연음, Yeoneum) the final consonant (종성, [`Jongseong`]) of every Precomposed Korean
+/// [`Syllable`] in a given string into the next syllable, if it begins with a silent 'ㅇ', the way
+/// it is pronounced, e.g. '먹어요' into '머거요'.
+///
+/// This is the same as [`pushdown_jongseong_with`] with the default [`PushdownOptions`], i.e. only
+/// the syllables right next to each other are linked.
+///
+/// ```
+/// use unikorn::pushdown_jongseong;
+///
+/// assert_eq!(pushdown_jongseong("닭이 먹어요"), "달기 머거요");
+/// assert_eq!(pushdown_jongseong("밥 을"), "밥 을");
+/// ```
+pub fn pushdown_jongseong(source: &str) -> String {
+    pushdown_jongseong_with(source, PushdownOptions::default())
+}
+
+/// Does the same as [`pushdown_jongseong`], but as [`PushdownOptions`] says, i.e. looks past the
+/// characters in [`PushdownOptions::skip_chars`] when finding the next syllable (those are kept as
+/// they are), and palatalizes if [`PushdownOptions::palatalization`] is set.
+///
+/// The final consonant moves as [`Jongseong::resyllabify`] says, i.e. a clustered one leaves its
+/// first component behind, and 'ㅇ' stays put.
+///
+/// A single pass reaches the fixed point, so applying this twice does the same as once: a final
+/// consonant moved into the next syllable takes the place of its silent 'ㅇ', and links further
+/// within the same pass if that syllable has a final consonant of its own, e.g. '닭알이' into
+/// '달가리'; what is left behind, if anything, is followed by a syllable no longer beginning with
+/// a silent 'ㅇ', unless it is 'ㅇ' itself, which stays put anyway.
+///
+/// ```
+/// use unikorn::{pushdown_jongseong_with, PushdownOptions};
+///
+/// let options = PushdownOptions {
+///     skip_chars: &[' '],
+///     ..PushdownOptions::default()
+/// };
+/// assert_eq!(pushdown_jongseong_with("밥 을 먹다", options), "바 블 먹다");
+///
+/// let options = PushdownOptions {
+///     palatalization: true,
+///     ..PushdownOptions::default()
+/// };
+/// assert_eq!(pushdown_jongseong_with("같이 밭을", options), "가치 바틀");
+/// ```
+pub fn pushdown_jongseong_with(source: &str, options: PushdownOptions) -> String {
+    let mut characters: Vec<char> = source.chars().collect();
+
+    for index in 0..characters.len() {
+        let current = match Syllable::try_from(characters[index]) {
+            Ok(syllable) => syllable,
+            Err(_) => continue,
+        };
+        let jongseong = match current.jongseong {
+            Some(jongseong) => jongseong,
+            None => continue,
+        };
+        let next = characters[index + 1..]
+            .iter()
+            .position(|character| !options.skip_chars.contains(character))
+            .map(|offset| index + 1 + offset)
+            .and_then(|next_index| {
+                Syllable::try_from(characters[next_index])
+                    .ok()
+                    .map(|next| (next_index, next))
+            });
+
+        if let Some((next_index, next)) = next {
+            if next.choseong == Choseong::Ieung {
+                let (remaining, mut moved) = jongseong.resyllabify();
+                // palatalization happens within a word only, i.e. not across a skipped character.
+                if options.palatalization
+                    && next_index == index + 1
+                    && next.jungseong == Jungseong::I
+                {
+                    moved = match moved {
+                        Choseong::Tikeut => Choseong::Cieuc,
+                        Choseong::Thieuth => Choseong::Chieuch,
+                        moved => moved,
+                    };
+                }
+                characters[index] = current.with_jongseong(remaining).into();
+                characters[next_index] = next.with_choseong(moved).into();
+            }
+        }
+    }
+
+    characters.into_iter().collect()
+}
+
+/// Decides how [`pushdown_jongseong_with`] finds the syllable to link a final consonant into.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PushdownOptions<'a> {
+    /// The characters to look past, e.g. `&[' ']` to link across a space as if it were not there.
+    /// Empty by default, i.e. only the syllables right next to each other are linked.
+    pub skip_chars: &'a [char],
+    /// Whether a final 'ㄷ' or 'ㅌ' (including the one in 'ㄾ') turns into 'ㅈ' or 'ㅊ' as it links
+    /// into a syllable of the vowel 'ㅣ', i.e. palatalization (구개음화, Gugaeeumhwa), as in the
+    /// standard pronunciation, e.g. '같이' into '가치' rather than '가티'. Only the syllables right
+    /// next to each other are palatalized, i.e. '밭 이' becomes '바 티' even with `' '` skipped. Off
+    /// by default, i.e. the final consonant moves as it is.
+    pub palatalization: bool,
+}
+
+/// Moves the initial consonant (초성, [`Choseong`]) of every Precomposed Korean [`Syllable`] in a
+/// given string into the previous syllable, if that has no final consonant of its own, leaving a
+/// silent 'ㅇ' behind; the reverse of [`pushdown_jongseong`], e.g. '머거요' into '먹어요'.
+///
+/// This is the same as [`pullup_choseong_with`] with the default [`PullupOptions`], i.e. only the
+/// syllables right next to each other are considered.
+///
+/// ```
+/// use unikorn::pullup_choseong;
+///
+/// assert_eq!(pullup_choseong("바블 머거요"), "밥을 먹어요");
+/// assert_eq!(pullup_choseong("머 거"), "머 거");
+/// ```
+pub fn pullup_choseong(source: &str) -> String {
+    pullup_choseong_with(source, PullupOptions::default())
+}
+
+/// Does the same as [`pullup_choseong`], but looks past the characters in
+/// [`PullupOptions::skip_chars`] when finding the next syllable; those are kept as they are.
+///
+/// 'ㅇ' stays put, and so do 'ㄸ', 'ㅃ', and 'ㅉ', which cannot be a final consonant. A final
+/// consonant is never made into a cluster, so this does not undo everything [`pushdown_jongseong`]
+/// does, e.g. '달가리' becomes '달갈이' rather than '닭알이'. A single pass reaches the fixed point,
+/// as with [`pushdown_jongseong_with`].
+///
+/// ```
+/// use unikorn::{pullup_choseong_with, PullupOptions};
+///
+/// let options = PullupOptions { skip_chars: &[' '] };
+/// assert_eq!(pullup_choseong_with("머 거 요", options), "먹 어 요");
+/// assert_eq!(pullup_choseong_with("아빠", options), "아빠");
+/// ```
+pub fn pullup_choseong_with(source: &str, options: PullupOptions) -> String {
+    let mut characters: Vec<char> = source.chars().collect();
+
+    for index in 0..characters.len() {
+        let current = match Syllable::try_from(characters[index]) {
+            Ok(syllable) if syllable.jongseong.is_none() => syllable,
+            _ => continue,
+        };
+        let next = characters[index + 1..]
+            .iter()
+            .position(|character| !options.skip_chars.contains(character))
+            .map(|offset| index + 1 + offset)
+            .and_then(|next_index| {
+                Syllable::try_from(characters[next_index])
+                    .ok()
+                    .map(|next| (next_index, next))
+            });
+
+        if let Some((next_index, next)) = next {
+            if next.choseong == Choseong::Ieung {
+                continue;
+            }
+            if let Some(moved) = Jaeum::from(next.choseong).to_jongseong() {
+                characters[index] = current.with_jongseong(Some(moved)).into();
+                characters[next_index] = next.with_choseong(Choseong::Ieung).into();
+            }
+        }
+    }
+
+    characters.into_iter().collect()
+}
+
+/// Decides how [`pullup_choseong_with`] finds the syllable to take an initial consonant from.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PullupOptions<'a> {
+    /// The characters to look past, e.g. `&[' ']` to pull across a space as if it were not there.
+    /// Empty by default, i.e. only the syllables right next to each other are considered.
+    pub skip_chars: &'a [char],
+}
+
+/// Swaps the initial consonant (초성, [`Choseong`]) and the final consonant (종성, [`Jongseong`])
+/// of every Precomposed Korean [`Syllable`] in a given string, as if the syllable were flipped
+/// horizontally, e.g. '간' into '낙'.
+///
+/// A silent 'ㅇ' and the lack of a final consonant take each other's place, e.g. '가' into '악',
+/// while a syllable ending in 'ㅇ', or whose consonants do not fit the other position, e.g. '따' or
+/// '닭', is left as it is. Thus flipping twice gives the original string back.
+///
+/// ```
+/// use unikorn::flip_chojongseong_horizontally;
+///
+/// assert_eq!(flip_chojongseong_horizontally("간다 ok"), "낙앋 ok");
+/// assert_eq!(flip_chojongseong_horizontally("낙앋 ok"), "간다 ok");
+/// assert_eq!(flip_chojongseong_horizontally("강"), "강");
+/// ```
+pub fn flip_chojongseong_horizontally(source: &str) -> String {
+    map_syllables(source, |syllable| {
+        let choseong = match syllable.jongseong {
+            None => Some(Choseong::Ieung),
+            Some(Jongseong::Ieung) => None,
+            Some(jongseong) => Jaeum::from(jongseong).to_choseong(),
+        };
+        let jongseong = match syllable.choseong {
+            Choseong::Ieung => Some(None),
+            choseong => Jaeum::from(choseong).to_jongseong().map(Some),
+        };
+
+        match (choseong, jongseong) {
+            (Some(choseong), Some(jongseong)) => {
+                syllable.with_choseong(choseong).with_jongseong(jongseong)
+            }
+            _ => syllable,
+        }
+    })
+}
+
+/// Measures how many terminal columns a given string takes up, i.e. a Korean syllable (precomposed
+/// or composed from a run of conjoining jamo, see [`reverse_syllables`]) or a Hangul
+/// Compatibility Jamo takes up two, a Halfwidth Hangul jamo one, and so on.
+///
+/// This is the same as [`display_width_with`] with `fullwidth` set to `true`.
+///
+/// ```
+/// use unikorn::display_width;
+///
+/// assert_eq!(display_width("한글 ok"), 7);
+/// assert_eq!(display_width("\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}"), 4);
+/// assert_eq!(display_width("\u{FFA1}\u{FFC2}"), 2);
+/// ```
+pub fn display_width(s: &str) -> usize {
+    display_width_with(s, true)
+}
+
+/// Does the same as [`display_width`] if `fullwidth` is `true`; otherwise, counts the characters
+/// in a given string as perceived by a reader, i.e. a Korean syllable, precomposed or not, counts
+/// as one, as does any other [`char`].
+///
+/// A fullwidth character is a Korean syllable, a Hangul Compatibility Jamo, a standalone initial
+/// consonant in Hangul Jamo range, or a character in the fullwidth portion of Halfwidth and
+/// Fullwidth Forms (e.g. 'Ａ').
+///
+/// ```
+/// use unikorn::display_width_with;
+///
+/// assert_eq!(display_width_with("한글 ok", false), 5);
+/// assert_eq!(display_width_with("한글 ok", true), 7);
+/// ```
+pub fn display_width_with(s: &str, fullwidth: bool) -> usize {
+    compose_conjoining(s)
+        .map(|(_, character)| width_of(character, fullwidth))
+        .sum()
+}
+
+/// Shortens a given string so that it fits in `max` terminal columns as measured by
+/// [`display_width`], appending `ellipsis` if anything was cut off.
+///
+/// The string is only ever cut in between the syllables, never inside a run of conjoining jamo
+/// composing into one, and whatever is kept is returned as is, decomposed or not. If `ellipsis`
+/// alone does not fit in `max`, it is left out.
+///
+/// ```
+/// use unikorn::truncate_to_width;
+///
+/// assert_eq!(truncate_to_width("안녕하세요", 7, "…"), "안녕하…");
+/// assert_eq!(truncate_to_width("안녕", 4, "…"), "안녕");
+/// ```
+pub fn truncate_to_width(s: &str, max: usize, ellipsis: &str) -> String {
+    if display_width(s) <= max {
+        return String::from(s);
+    }
+
+    let ellipsis = if display_width(ellipsis) <= max {
+        ellipsis
+    } else {
+        ""
+    };
+    let budget = max - display_width(ellipsis);
+
+    let mut width = 0;
+    let mut cut = 0;
+    for (end, character) in compose_conjoining(s) {
+        width += width_of(character, true);
+        if width > budget {
+            break;
+        }
+        cut = end;
+    }
+
+    let mut truncated = String::with_capacity(cut + ellipsis.len());
+    truncated.push_str(&s[..cut]);
+    truncated.push_str(ellipsis);
+    truncated
+}
+
+/// Decides how [`modernize_text`] treats the archaic jamo.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ModernizePolicy {
+    /// The medial vowel (중성, [`Jungseong`]) Arae-A ('ㆍ') turns into, usually either
+    /// [`Jungseong::A`] ('ㅏ') or [`Jungseong::O`] ('ㅗ').
+    pub arae_a: Jungseong,
+    /// Whether an archaic cluster of jamo, e.g. U+1121 'ᄡ', is approximated by its first component
+    /// in the same position, e.g. U+1107 'ᄇ', rather than left to
+    /// [`ModernizePolicy::on_unsupported`]. A first component archaic in itself is modernized in
+    /// turn, if it can be. Off by default.
+    pub approximate_clusters: bool,
+    /// What to do with an archaic jamo without a modern equivalent, e.g. a consonant cluster like
+    /// U+1121 'ᄡ'.
+    pub on_unsupported: ConversionFallback,
+}
+impl Default for ModernizePolicy {
+    fn default() -> Self {
+        Self {
+            arae_a: Jungseong::A,
+            approximate_clusters: false,
+            on_unsupported: ConversionFallback::Keep,
+        }
+    }
+}
+
+/// Replaces the archaic jamo in a given string with their nearest modern equivalents, in both the
+/// compatibility and the conjoining forms:
+/// * Pan-Sios ('ㅿ') with Sios ('ㅅ'),
+/// * Yes-Ieung ('ㆁ') with Ieung ('ㅇ'),
+/// * Yeorin-Hieuh ('ㆆ') with Hieuh ('ㅎ'), and
+/// * Arae-A ('ㆍ') with [`ModernizePolicy::arae_a`], and
+/// * a cluster with its first component, if [`ModernizePolicy::approximate_clusters`] is set.
+///
+/// Any other archaic jamo is handled according to [`ModernizePolicy::on_unsupported`], and
+/// everything else passes through untouched. A run of conjoining jamo stays decomposed.
+///
+/// ```
+/// use unikorn::{modernize_text, ConversionFallback, Jungseong, ModernizePolicy};
+///
+/// assert_eq!(modernize_text("ㅿㆍㄹ", &ModernizePolicy::default()), "ㅅㅏㄹ");
+///
+/// let policy = ModernizePolicy {
+///     arae_a: Jungseong::O,
+///     on_unsupported: ConversionFallback::Skip,
+///     ..ModernizePolicy::default()
+/// };
+/// assert_eq!(
+///     modernize_text("\u{1112}\u{119E}\u{11AB} \u{1121}", &policy),
+///     "\u{1112}\u{1169}\u{11AB} "
+/// );
+///
+/// let policy = ModernizePolicy {
+///     approximate_clusters: true,
+///     ..ModernizePolicy::default()
+/// };
+/// assert_eq!(modernize_text("\u{1121}\u{1161}", &policy), "\u{1107}\u{1161}");
+/// ```
+#[doc(alias = "normalize_to_modern")]
+pub fn modernize_text(source: &str, policy: &ModernizePolicy) -> String {
+    let mut output = String::with_capacity(source.len());
+
+    for character in source.chars() {
+        let modern = modernize_archaic(character, policy.arae_a).or_else(|| {
+            if policy.approximate_clusters {
+                cluster_head(character).and_then(|head| {
+                    if is_archaic(head) {
+                        modernize_archaic(head, policy.arae_a)
+                    } else {
+                        Some(head)
+                    }
+                })
+            } else {
+                None
+            }
+        });
+        match modern {
+            Some(modern) => output.push(modern),
+            None if is_archaic(character) => policy.on_unsupported.apply(&mut output, character),
+            None => output.push(character),
+        }
+    }
+
+    output
+}
+
+/// Returns the nearest modern equivalent of an archaic jamo, in the same form, i.e. compatibility
+/// or conjoining, as [`modernize_text`] does with the default [`ModernizePolicy`], or [`None`] if
+/// there is none or `character` is not an archaic jamo in the first place.
+///
+/// ```
+/// use unikorn::modern_equivalent;
+///
+/// assert_eq!(modern_equivalent('ㆆ'), Some('ㅎ'));
+/// assert_eq!(modern_equivalent('\u{1140}'), Some('\u{1109}'));
+/// assert_eq!(modern_equivalent('\u{1121}'), None);
+/// assert_eq!(modern_equivalent('ㅎ'), None);
+/// ```
+pub fn modern_equivalent(character: char) -> Option<char> {
+    modernize_archaic(character, ModernizePolicy::default().arae_a)
+}
+
+/// Decomposes every Precomposed Korean [`Syllable`] in a given string into the jamo of `form`,
+/// leaving everything else untouched.
+///
+/// Only [`JamoForm::Conjoining`] keeps the syllables recomposable by Unicode normalization; the
+/// output of [`JamoForm::Compatibility`] reads as separate letters, which [`compose_orphans`] puts
+/// back together.
+///
+/// With [`JamoForm::Conjoining`], this is the Hangul decomposition algorithm of the Unicode
+/// Standard (§3.12), i.e. Unicode Normalization Form D (NFD) as far as Korean is concerned.
+///
+/// ```
+/// use unikorn::{decompose_syllables, JamoForm};
+///
+/// assert_eq!(
+///     decompose_syllables("강 ok", JamoForm::Conjoining),
+///     "\u{1100}\u{1161}\u{11BC} ok"
+/// );
+/// assert_eq!(decompose_syllables("강 ok", JamoForm::Compatibility), "ㄱㅏㅇ ok");
+/// ```
+#[doc(alias = "nfd")]
+pub fn decompose_syllables(s: &str, form: JamoForm) -> String {
+    let mut decomposed = String::with_capacity(s.len() * 3);
+
+    let mut copied = 0;
+    for (offset, syllable) in decompose_with_offsets(s) {
+        decomposed.push_str(&s[copied..offset]);
+        form.push_decomposed(&mut decomposed, syllable);
+        copied = offset + 3;
+    }
+    decomposed.push_str(&s[copied..]);
+
+    decomposed
+}
+
+/// Composes every run of modern conjoining jamo in a given string into a Precomposed Korean
+/// [`Syllable`] as [`reverse_syllables`] describes, leaving everything else untouched; the reverse
+/// of [`decompose_syllables`] with [`JamoForm::Conjoining`].
+///
+/// This is the Hangul composition algorithm of the Unicode Standard (§3.12), i.e. Unicode
+/// Normalization Form C (NFC) as far as Korean is concerned; everything else, including Hangul
+/// Compatibility Jamo and the archaic conjoining jamo which never compose, is left as is.
+///
+/// ```
+/// use unikorn::compose_syllables;
+///
+/// assert_eq!(compose_syllables("\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF} ok"), "한글 ok");
+/// assert_eq!(compose_syllables("ㄱㅏ"), "ㄱㅏ");
+/// ```
+#[doc(alias = "nfc")]
+pub fn compose_syllables(s: &str) -> String {
+    compose_conjoining(s)
+        .map(|(_, character)| character)
+        .collect()
+}
+
+/// Does the same as [`compose_syllables`], then turns every initial consonant left standing alone,
+/// i.e. not followed by a medial vowel, into its counterpart in Hangul Compatibility Jamo range,
+/// e.g. U+110F 'ᄏ' into 'ㅋ'.
+///
+/// Meant for the text some messaging apps deliver in conjoining jamo, which the older systems
+/// render as broken blocks: a syllable composes as usual, while a consonant typed by itself,
+/// e.g. in 'ㅋㅋ', has no syllable to go into. Any other jamo left over, e.g. a medial vowel with
+/// no initial consonant before it, is left as is.
+///
+/// ```
+/// use unikorn::recompose_nfd;
+///
+/// assert_eq!(
+///     recompose_nfd("\u{110B}\u{1161}\u{11AB}\u{1102}\u{1167}\u{11BC} \u{110F}\u{110F}"),
+///     "안녕 ㅋㅋ"
+/// );
+/// assert_eq!(recompose_nfd("\u{1172}\u{1172}"), "\u{1172}\u{1172}");
+/// ```
+pub fn recompose_nfd(s: &str) -> String {
+    compose_conjoining(s)
+        .map(
+            |(_, character)| match Choseong::from_conjoining(character) {
+                Ok(choseong) => choseong.into(),
+                Err(_) => character,
+            },
+        )
+        .collect()
+}
+
+/// Finds every standalone consonant or vowel in Hangul Compatibility Jamo range (U+3131 'ㄱ' --
+/// U+3163 'ㅣ') in a given string, i.e. those left uncomposed, alongside its byte offset.
+///
+/// ```
+/// use unikorn::find_orphan_jamo;
+///
+/// assert_eq!(find_orphan_jamo("한ㄱ"), [(3, 'ㄱ')]);
+/// assert_eq!(find_orphan_jamo("ㅎㅏ ok"), [(0, 'ㅎ'), (3, 'ㅏ')]);
+/// ```
+pub fn find_orphan_jamo(s: &str) -> Vec<(usize, char)> {
+    s.char_indices()
+        .filter(|&(_, character)| orphan_jamo(character).is_some())
+        .collect()
+}
+
+/// Composes every run of standalone consonants and vowels in Hangul Compatibility Jamo range
+/// (U+3131 'ㄱ' -- U+3163 'ㅣ') in a given string into Precomposed Korean [`Syllable`]s where
+/// possible, leaving everything else untouched.
+///
+/// The run is read greedily from the front: an initial consonant followed by a vowel composes into
+/// a syllable, taking the consonant after them as its final consonant, unless that consonant is
+/// better off beginning the next syllable, i.e. is followed by a vowel in turn. Whatever does not
+/// fit into a syllable is left as is.
+///
+/// This undoes [`decompose_syllables`] with [`JamoForm::Compatibility`], as long as the string had
+/// no standalone consonants or vowels to begin with.
+///
+/// ```
+/// use unikorn::compose_orphans;
+///
+/// assert_eq!(compose_orphans("ㅎㅏㄴㄱㅡㄹ"), "한글");
+/// assert_eq!(compose_orphans("ㅇㅏㄴㅕㅇ ㅋㅋ"), "아녕 ㅋㅋ");
+/// ```
+pub fn compose_orphans(s: &str) -> String {
+    let characters: Vec<char> = s.chars().collect();
+    let jamo_at = |index: usize| {
+        characters
+            .get(index)
+            .and_then(|&character| orphan_jamo(character))
+    };
+
+    let mut composed = String::with_capacity(s.len());
+    let mut index = 0;
+    while index < characters.len() {
+        let choseong = jamo_at(index).and_then(|jamo| jamo.as_choseong());
+        let jungseong = jamo_at(index + 1).and_then(|jamo| jamo.as_jungseong());
+
+        if let (Some(choseong), Some(jungseong)) = (choseong, jungseong) {
+            let begins_next = jamo_at(index + 2)
+                .and_then(|jamo| jamo.as_choseong())
+                .is_some()
+                && jamo_at(index + 3)
+                    .and_then(|jamo| jamo.as_jungseong())
+                    .is_some();
+            let jongseong = jamo_at(index + 2)
+                .and_then(|jamo| jamo.as_jongseong())
+                .filter(|_| !begins_next);
+
+            composed.push(Syllable::from((choseong, jungseong, jongseong)).into());
+            index += if jongseong.is_some() { 3 } else { 2 };
+        } else {
+            composed.push(characters[index]);
+            index += 1;
+        }
+    }
+
+    composed
+}
+
+/// Splits a given string into the Korean syllables and the rest, whichever form the syllables come
+/// in, i.e. precomposed, or a run of modern conjoining jamo as in NFD.
+///
+/// A run of conjoining jamo makes a syllable as [`reverse_syllables`] describes; whatever is left
+/// out of one, e.g. the first of two initial consonants in a row, a vowel without an initial
+/// consonant, or a second final consonant, comes out as a [`SyllableOrChar::Char`] by itself.
+///
+/// ```
+/// use unikorn::{segment, Syllable, SyllableOrChar};
+/// use std::convert::TryFrom;
+///
+/// let han = Syllable::try_from('한').unwrap();
+/// assert_eq!(
+///     segment("한\u{1112}\u{1161}\u{11AB}!"),
+///     [
+///         SyllableOrChar::Syllable(han),
+///         SyllableOrChar::Syllable(han),
+///         SyllableOrChar::Char('!'),
+///     ]
+/// );
+/// ```
+pub fn segment(source: &str) -> Vec<SyllableOrChar> {
+    compose_conjoining(source)
+        .map(|(_, character)| match Syllable::try_from(character) {
+            Ok(syllable) => SyllableOrChar::Syllable(syllable),
+            Err(_) => SyllableOrChar::Char(character),
+        })
+        .collect()
+}
+
+/// Represents a segment of a string as split by [`segment`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SyllableOrChar {
+    /// A Korean syllable, precomposed or not in the source.
+    Syllable(Syllable),
+    /// Anything else, including a conjoining jamo not making up a syllable.
+    Char(char),
+}
+impl From<SyllableOrChar> for char {
+    fn from(segment: SyllableOrChar) -> Self {
+        match segment {
+            SyllableOrChar::Syllable(syllable) => syllable.into(),
+            SyllableOrChar::Char(character) => character,
+        }
+    }
+}
+
+/// Converts a given string into [`Syllable`]s, one for each [`char`].
+///
+/// Fails with [`Error::NonKorean`] carrying the byte offset of the first [`char`] which is not a
+/// Precomposed Korean [`Syllable`].
+///
+/// ```
+/// use unikorn::{parse_korean_string, Error};
+///
+/// assert_eq!(parse_korean_string("한글").unwrap(), ['한', '글']);
+/// assert_eq!(
+///     parse_korean_string("한글 A").unwrap_err(),
+///     Error::NonKorean(' ', Some(6))
+/// );
+/// assert_eq!(
+///     parse_korean_string("한글A").unwrap_err().to_string(),
+///     "'A' at byte 6 is not a Precomposed Korean Sylable"
+/// );
+/// ```
+pub fn parse_korean_string(source: &str) -> Result<Vec<Syllable>, Error> {
+    source
+        .char_indices()
+        .map(|(offset, character)| {
+            Syllable::try_from(character).map_err(|_| Error::NonKorean(character, Some(offset)))
+        })
+        .collect()
+}
+
+/// Does the same check as [`parse_korean_string`], but goes on to the end of a given string rather
+/// than stopping at the first [`char`] which is not a Precomposed Korean [`Syllable`], reporting
+/// every such [`char`] alongside its byte offset.
+///
+/// ```
+/// use unikorn::{validate_korean_str, Error};
+///
+/// assert_eq!(validate_korean_str("안녕하세요"), Ok(()));
+/// assert_eq!(
+///     validate_korean_str("안녕ㅎ세yo"),
+///     Err(vec![
+///         (6, Error::NonKorean('ㅎ', Some(6))),
+///         (12, Error::NonKorean('y', Some(12))),
+///         (13, Error::NonKorean('o', Some(13))),
+///     ])
+/// );
+/// ```
+pub fn validate_korean_str(s: &str) -> Result<(), Vec<(usize, Error)>> {
+    let errors: Vec<_> = s
+        .char_indices()
+        .filter(|&(_, character)| !Syllable::is_one_of_us(character))
+        .map(|(offset, character)| (offset, Error::NonKorean(character, Some(offset))))
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Counts the fewest insertions, deletions, and substitutions of a whole Korean syllable it takes
+/// to turn a given string into another, i.e. the Levenshtein distance between the two, each
+/// syllable (precomposed or composed from a run of conjoining jamo) and any other [`char`] counted
+/// as one.
+///
+/// See [`levenshtein_jamo`] for a finer distance, telling a near miss from a different syllable
+/// altogether.
+///
+/// ```
+/// use unikorn::levenshtein_syllable;
+///
+/// assert_eq!(levenshtein_syllable("한국어", "한글어"), 1);
+/// assert_eq!(levenshtein_syllable("한국어", "한국 ok"), 3);
+/// assert_eq!(levenshtein_syllable("\u{1112}\u{1161}\u{11AB}", "한"), 0);
+/// ```
+pub fn levenshtein_syllable(a: &str, b: &str) -> usize {
+    let atoms = |s| {
+        compose_conjoining(s)
+            .map(|(_, character)| character)
+            .collect::<Vec<_>>()
+    };
+
+    crate::edit_distance(&atoms(a), &atoms(b))
+}
+
+/// Does the same as [`levenshtein_syllable`], but counts the edits of a jamo rather than of a
+/// whole syllable, i.e. every syllable is decomposed into the Hangul Compatibility Jamo first, as
+/// [`decompose_syllables`] does with [`JamoForm::Compatibility`], while any other [`char`] still
+/// counts as one.
+///
+/// A clustered consonant or a diphthong counts as one jamo, e.g. 'ㄺ' or 'ㅘ', the same as when
+/// it is typed in a single syllable.
+///
+/// ```
+/// use unikorn::levenshtein_jamo;
+///
+/// // '국' and '글' differ in two out of three jamo.
+/// assert_eq!(levenshtein_jamo("한국어", "한글어"), 2);
+/// // a final consonant typed before the vowel of the next syllable.
+/// assert_eq!(levenshtein_jamo("갑", "가비"), 1);
+/// assert_eq!(levenshtein_jamo("가", "ㄱ"), 1);
+/// ```
+pub fn levenshtein_jamo(a: &str, b: &str) -> usize {
+    let atoms = |s| {
+        let mut atoms = Vec::new();
+        for (_, character) in compose_conjoining(s) {
+            match Syllable::try_from(character) {
+                Ok(syllable) => atoms.extend(JamoForm::Compatibility.decompose(syllable)),
+                Err(_) => atoms.push(character),
+            }
+        }
+        atoms
+    };
+
+    crate::edit_distance(&atoms(a), &atoms(b))
+}
+
+fn width_of(character: char, fullwidth: bool) -> usize {
+    if fullwidth && is_fullwidth(character) {
+        2
+    } else {
+        1
+    }
+}
+
+fn is_fullwidth(character: char) -> bool {
+    Syllable::is_one_of_us(character)
+        || matches!(
+            character,
+            '\u{1100}'..='\u{115F}'
+                | '\u{3131}'..='\u{318E}'
+                | '\u{A960}'..='\u{A97C}'
+                | '\u{FF01}'..='\u{FF60}'
+                | '\u{FFE0}'..='\u{FFE6}'
+        )
+}
+
+fn modernize_archaic(character: char, arae_a: Jungseong) -> Option<char> {
+    match character {
+        // Pan-Sios
+        '\u{317F}' => Some('\u{3145}'),
+        '\u{1140}' => Some('\u{1109}'),
+        '\u{11EB}' => Some('\u{11BA}'),
+        // Yes-Ieung
+        '\u{3181}' => Some('\u{3147}'),
+        '\u{114C}' => Some('\u{110B}'),
+        '\u{11F0}' => Some('\u{11BC}'),
+        // Yeorin-Hieuh
+        '\u{3186}' => Some('\u{314E}'),
+        '\u{1159}' => Some('\u{1112}'),
+        '\u{11F9}' => Some('\u{11C2}'),
+        // Arae-A
+        '\u{318D}' => Some(char::from(arae_a)),
+        '\u{119E}' => Some(arae_a.to_conjoining()),
+        _ => None,
+    }
+}
+
+// the first component of an archaic cluster of jamo, in the same position and form, e.g. U+1121
+// 'ᄡ' into U+1107 'ᄇ', or U+3172 'ㅲ' into U+3142 'ㅂ'.
+fn cluster_head(character: char) -> Option<char> {
+    match character {
+        '\u{1113}' | '\u{1115}'..='\u{1116}' | '\u{115B}'..='\u{115D}' => Some('\u{1102}'),
+        '\u{1117}' | '\u{115E}' | '\u{A960}'..='\u{A963}' => Some('\u{1103}'),
+        '\u{1118}' | '\u{111A}' | '\u{A964}'..='\u{A96E}' => Some('\u{1105}'),
+        '\u{111C}' | '\u{A96F}'..='\u{A971}' => Some('\u{1106}'),
+        '\u{111E}'..='\u{112A}' | '\u{A972}'..='\u{A974}' => Some('\u{1107}'),
+        '\u{112D}'..='\u{113B}' => Some('\u{1109}'),
+        '\u{1141}'..='\u{1146}' | '\u{1148}'..='\u{114B}' | '\u{A976}'..='\u{A977}' => {
+            Some('\u{110B}')
+        }
+        '\u{114D}' => Some('\u{110C}'),
+        '\u{1152}'..='\u{1153}' => Some('\u{110E}'),
+        '\u{1156}' | '\u{A97A}' => Some('\u{1111}'),
+        '\u{115A}' => Some('\u{1100}'),
+        '\u{1176}'..='\u{1177}' | '\u{11A3}' => Some('\u{1161}'),
+        '\u{1178}'..='\u{1179}' | '\u{11A4}' => Some('\u{1163}'),
+        '\u{117A}'..='\u{117C}' => Some('\u{1165}'),
+        '\u{117D}'..='\u{117E}' | '\u{11A5}' => Some('\u{1167}'),
+        '\u{117F}'..='\u{1183}' | '\u{11A6}'..='\u{11A7}' | '\u{D7B0}'..='\u{D7B1}' => {
+            Some('\u{1169}')
+        }
+        '\u{1184}'..='\u{1188}' | '\u{D7B2}'..='\u{D7B4}' => Some('\u{116D}'),
+        '\u{1189}'..='\u{118D}' | '\u{D7B5}'..='\u{D7B6}' => Some('\u{116E}'),
+        '\u{118E}'..='\u{1194}' | '\u{D7B7}'..='\u{D7B8}' => Some('\u{1172}'),
+        '\u{1195}'..='\u{1196}' | '\u{D7B9}'..='\u{D7BC}' => Some('\u{1173}'),
+        '\u{1197}' => Some('\u{1174}'),
+        '\u{1198}'..='\u{119D}' | '\u{D7BD}'..='\u{D7C4}' => Some('\u{1175}'),
+        '\u{119F}'..='\u{11A1}' | '\u{D7C5}'..='\u{D7C6}' => Some('\u{119E}'),
+        '\u{11C3}'..='\u{11C4}' | '\u{11FA}'..='\u{11FE}' => Some('\u{11A8}'),
+        '\u{11C5}'..='\u{11C9}' | '\u{D7CB}'..='\u{D7CC}' => Some('\u{11AB}'),
+        '\u{11CA}'..='\u{11CB}' | '\u{D7CF}'..='\u{D7D4}' => Some('\u{11AE}'),
+        '\u{11CC}'..='\u{11CF}'
+        | '\u{11D1}'..='\u{11D9}'
+        | '\u{D7D5}'..='\u{D7D6}'
+        | '\u{D7D8}'..='\u{D7DC}' => Some('\u{11AF}'),
+        '\u{11DA}'..='\u{11E1}' | '\u{D7DE}'..='\u{D7DF}' | '\u{D7E1}'..='\u{D7E2}' => {
+            Some('\u{11B7}')
+        }
+        '\u{11E3}'..='\u{11E5}' | '\u{D7E3}'..='\u{D7E5}' | '\u{D7E7}'..='\u{D7E9}' => {
+            Some('\u{11B8}')
+        }
+        '\u{11E7}'..='\u{11EA}' | '\u{D7EA}'..='\u{D7EB}' | '\u{D7EE}'..='\u{D7F2}' => {
+            Some('\u{11BA}')
+        }
+        '\u{11EC}'..='\u{11ED}' | '\u{11EF}' => Some('\u{11BC}'),
+        '\u{11F1}'..='\u{11F2}' | '\u{D7F5}'..='\u{D7F6}' => Some('\u{11F0}'),
+        '\u{11F3}' | '\u{D7FA}'..='\u{D7FB}' => Some('\u{11C1}'),
+        '\u{11F5}'..='\u{11F8}' => Some('\u{11C2}'),
+        '\u{3166}'..='\u{3168}' => Some('\u{3134}'),
+        '\u{3169}'..='\u{316D}' => Some('\u{3139}'),
+        '\u{316E}'..='\u{3170}' => Some('\u{3141}'),
+        '\u{3172}'..='\u{3177}' => Some('\u{3142}'),
+        '\u{317A}'..='\u{317E}' => Some('\u{3145}'),
+        '\u{3182}'..='\u{3183}' => Some('\u{3181}'),
+        '\u{3187}'..='\u{3189}' => Some('\u{315B}'),
+        '\u{318A}'..='\u{318C}' => Some('\u{3160}'),
+        '\u{A975}' => Some('\u{110A}'),
+        '\u{A978}' => Some('\u{110D}'),
+        '\u{A97B}' => Some('\u{1112}'),
+        '\u{D7CE}' => Some('\u{D7CD}'),
+        '\u{D7D7}' => Some('\u{11D0}'),
+        '\u{D7EC}'..='\u{D7ED}' => Some('\u{11BB}'),
+        '\u{D7F3}'..='\u{D7F4}' => Some('\u{11EB}'),
+        '\u{D7F7}'..='\u{D7F8}' => Some('\u{11BD}'),
+        _ => None,
+    }
+}
+
+// the jamo, if a given character is a standalone one in Hangul Compatibility Jamo range.
+fn orphan_jamo(character: char) -> Option<Jamo> {
+    Jamo::try_from(character)
+        .ok()
+        .filter(|jamo| matches!(jamo.kind(), JamoKind::CompatJaeum | JamoKind::CompatMoeum))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        compose_orphans, compose_syllables, cuteify, decompose_syllables, display_width,
+        display_width_with, find_orphan_jamo, flip_chojongseong_horizontally, is_jamo_palindrome,
+        is_syllable_palindrome, levenshtein_jamo, levenshtein_syllable, map_syllable_pairs,
+        map_syllables, modern_equivalent, modernize_text, normalize_halfwidth, parse_korean_string,
+        pullup_choseong, pullup_choseong_with, pushdown_jongseong, pushdown_jongseong_with,
+        recompose_nfd, reverse_jamo, reverse_syllables, reverse_syllables_in_place, segment,
+        set_jongseong, strip_jongseong, truncate_to_width, validate_korean_str, CuteStyle,
+        ModernizePolicy, PullupOptions,
+    };
+    use crate::{
+        ConversionFallback, Error, JamoForm, Jongseong, Jungseong, PushdownOptions, Syllable,
+        SyllableOrChar,
+    };
+    use core::convert::TryFrom;
+
+    #[test]
+    fn test_decompose_syllables() {
+        assert_eq!(
+            decompose_syllables("강", JamoForm::Conjoining),
+            "\u{1100}\u{1161}\u{11BC}"
+        );
+        assert_eq!(decompose_syllables("강", JamoForm::Compatibility), "ㄱㅏㅇ");
+
+        assert_eq!(
+            decompose_syllables("닭 가", JamoForm::Conjoining),
+            "\u{1103}\u{1161}\u{11B0} \u{1100}\u{1161}"
+        );
+        assert_eq!(
+            decompose_syllables("닭 가", JamoForm::Compatibility),
+            "ㄷㅏㄺ ㄱㅏ"
+        );
+
+        // only the conjoining form composes back.
+        assert_eq!(
+            reverse_syllables(&decompose_syllables("강아지", JamoForm::Conjoining)),
+            "지아강"
+        );
+        assert_eq!(
+            reverse_syllables(&decompose_syllables("강", JamoForm::Compatibility)),
+            "ㅇㅏㄱ"
+        );
+
+        assert_eq!(
+            decompose_syllables("ㄱ abc", JamoForm::Conjoining),
+            "ㄱ abc"
+        );
+        assert_eq!(decompose_syllables("", JamoForm::Compatibility), "");
+    }
+
+    #[test]
+    fn test_compose_syllables() {
+        assert_eq!(compose_syllables(""), "");
+        assert_eq!(compose_syllables("한글"), "한글");
+        assert_eq!(
+            compose_syllables("\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}"),
+            "한글"
+        );
+        assert_eq!(compose_syllables("가\u{11A8}\u{11A8}"), "각\u{11A8}");
+        assert_eq!(compose_syllables(" \u{1161}"), " \u{1161}");
+
+        for form in [JamoForm::Conjoining, JamoForm::Compatibility] {
+            let decomposed = decompose_syllables("안녕, 세상!", form);
+            assert_eq!(
+                compose_syllables(&decomposed) == "안녕, 세상!",
+                form == JamoForm::Conjoining
+            );
+        }
+    }
+
+    #[test]
+    fn test_nfc_nfd() {
+        let nfc = compose_syllables;
+        let nfd = |s: &str| decompose_syllables(s, JamoForm::Conjoining);
+        assert_eq!(nfc(""), "");
+        assert_eq!(nfd(""), "");
+
+        let composed = "가각 힣, ok ㄱㅏ \u{1140}\u{1161}";
+        let decomposed = concat!(
+            "\u{1100}\u{1161}\u{1100}\u{1161}\u{11A8} \u{1112}\u{1175}\u{11C2}",
+            ", ok ㄱㅏ \u{1140}\u{1161}"
+        );
+        assert_eq!(nfd(composed), decomposed);
+        assert_eq!(nfc(decomposed), composed);
+        // NFC and NFD are idempotent.
+        assert_eq!(nfc(composed), composed);
+        assert_eq!(nfd(decomposed), decomposed);
+
+        // an LV syllable takes a conjoining T, an LVT one does not; nor does a lone T.
+        assert_eq!(
+            nfc("가\u{11A8}각\u{11A8} \u{11A8}"),
+            "각각\u{11A8} \u{11A8}"
+        );
+        // every syllable makes a round trip.
+        for code_point in 0xAC00..=0xD7A3 {
+            let syllable = char::from_u32(code_point).unwrap().to_string();
+            assert_eq!(nfc(&nfd(&syllable)), syllable);
+        }
+    }
+
+    #[test]
+    fn test_recompose_nfd() {
+        // "ㅋㅋ 안녕하세요~ 오늘 저녁 뭐 먹어? ㅠㅠ", as delivered in conjoining jamo, the consonants
+        // and the vowels typed by themselves included.
+        let delivered = "\u{110F}\u{110F} \u{110B}\u{1161}\u{11AB}\u{1102}\u{1167}\u{11BC}\
+            \u{1112}\u{1161}\u{1109}\u{1166}\u{110B}\u{116D}~ \
+            \u{110B}\u{1169}\u{1102}\u{1173}\u{11AF} \
+            \u{110C}\u{1165}\u{1102}\u{1167}\u{11A8} \u{1106}\u{116F} \u{1106}\u{1165}\u{11A8}\
+            \u{110B}\u{1165}? \u{1172}\u{1172}";
+        assert_eq!(
+            recompose_nfd(delivered),
+            "ㅋㅋ 안녕하세요~ 오늘 저녁 뭐 먹어? \u{1172}\u{1172}"
+        );
+
+        // an initial consonant followed by another.
+        assert_eq!(recompose_nfd("\u{1100}\u{1100}\u{1161}"), "ㄱ가");
+        // stray jamo at either end.
+        assert_eq!(recompose_nfd("\u{1161}\u{1100}\u{1161}"), "\u{1161}가");
+        assert_eq!(recompose_nfd("\u{1100}\u{1161}\u{1100}"), "가ㄱ");
+        assert_eq!(
+            recompose_nfd("\u{1100}\u{1161}\u{11A8}\u{11A8}"),
+            "각\u{11A8}"
+        );
+        // everything else passes through.
+        assert_eq!(recompose_nfd("한글 ㄱ ok"), "한글 ㄱ ok");
+        assert_eq!(recompose_nfd(""), "");
+        assert_eq!(
+            recompose_nfd(&decompose_syllables(
+                "다시 합창합시다",
+                JamoForm::Conjoining
+            )),
+            "다시 합창합시다"
+        );
+    }
+
+    #[test]
+    fn test_find_orphan_jamo() {
+        assert_eq!(find_orphan_jamo("한ㄱ"), [(3, 'ㄱ')]);
+        assert_eq!(find_orphan_jamo("ㄳ a ㅘ"), [(0, 'ㄳ'), (6, 'ㅘ')]);
+
+        assert!(find_orphan_jamo("한글 ok").is_empty());
+        // the conjoining jamo are meant to be standalone.
+        assert!(find_orphan_jamo("\u{1112}\u{1161}\u{11AB}").is_empty());
+        // so are the archaic ones, which are never composed into a syllable.
+        assert!(find_orphan_jamo("ㆍㅿ").is_empty());
+    }
+
+    #[test]
+    fn test_compose_orphans() {
+        assert_eq!(compose_orphans("한ㄱ"), "한ㄱ");
+        assert_eq!(compose_orphans("ㄱㅏ"), "가");
+        assert_eq!(compose_orphans("ㄱㅏㄱ"), "각");
+        assert_eq!(compose_orphans("ㄱㅏㄱㅏ"), "가가");
+        assert_eq!(compose_orphans("ㄷㅏㄺ"), "닭");
+        // 'ㄳ' cannot begin a syllable, so it stays as the final consonant.
+        assert_eq!(compose_orphans("ㄱㅏㄳㅏ"), "갃ㅏ");
+        // 'ㄸ' cannot end a syllable.
+        assert_eq!(compose_orphans("ㄱㅏㄸ"), "가ㄸ");
+        assert_eq!(compose_orphans("ㄱㄱㅏ"), "ㄱ가");
+        assert_eq!(compose_orphans("ㅏㅏ"), "ㅏㅏ");
+        assert_eq!(compose_orphans("ㄱ ㅏ"), "ㄱ ㅏ");
+        assert_eq!(compose_orphans("ㅎㅏ한ㄴ"), "하한ㄴ");
+    }
+
+    #[test]
+    fn test_segment() {
+        let syllable = |character| SyllableOrChar::Syllable(Syllable::try_from(character).unwrap());
+        let lone = SyllableOrChar::Char;
+
+        assert!(segment("").is_empty());
+        assert_eq!(segment("a가"), [lone('a'), syllable('가')]);
+        // L + V + T
+        assert_eq!(segment("\u{1100}\u{1161}\u{11A8}"), [syllable('각')]);
+        // L + V + T + T: the second final consonant is left alone.
+        assert_eq!(
+            segment("\u{1100}\u{1161}\u{11A8}\u{11A8}"),
+            [syllable('각'), lone('\u{11A8}')]
+        );
+        // L + L + V: so is the first initial consonant.
+        assert_eq!(
+            segment("\u{1100}\u{1100}\u{1161}"),
+            [lone('\u{1100}'), syllable('가')]
+        );
+        // V without L.
+        assert_eq!(
+            segment(" \u{1161}\u{11A8}"),
+            [lone(' '), lone('\u{1161}'), lone('\u{11A8}')]
+        );
+        // a precomposed syllable still takes a final consonant, unless it already has one.
+        assert_eq!(segment("가\u{11A8}"), [syllable('각')]);
+        assert_eq!(segment("각\u{11A8}"), [syllable('각'), lone('\u{11A8}')]);
+        // the archaic and compatibility jamo are not composed.
+        assert_eq!(
+            segment("\u{1140}\u{1161}ㄱㅏ"),
+            [lone('\u{1140}'), lone('\u{1161}'), lone('ㄱ'), lone('ㅏ')]
+        );
+
+        let source = "한\u{1100}\u{1173}\u{11AF} ok";
+        assert_eq!(
+            segment(source)
+                .into_iter()
+                .map(char::from)
+                .collect::<String>(),
+            "한글 ok"
+        );
+    }
+
+    #[test]
+    fn test_parse_korean_string() {
+        assert!(parse_korean_string("").unwrap().is_empty());
+        assert_eq!(parse_korean_string("가나다").unwrap(), ['가', '나', '다']);
+        assert_eq!(
+            parse_korean_string("A가").unwrap_err(),
+            Error::NonKorean('A', Some(0))
+        );
+        assert_eq!(
+            parse_korean_string("가나\u{1100}\u{1161}").unwrap_err(),
+            Error::NonKorean('\u{1100}', Some(6))
+        );
+        assert_eq!(
+            parse_korean_string("가a").unwrap_err().to_string(),
+            "'a' at byte 3 is not a Precomposed Korean Sylable"
+        );
+    }
+
+    #[test]
+    fn test_validate_korean_str() {
+        assert_eq!(validate_korean_str(""), Ok(()));
+        assert_eq!(validate_korean_str("가나다"), Ok(()));
+        assert_eq!(
+            validate_korean_str("a가"),
+            Err(vec![(0, Error::NonKorean('a', Some(0)))])
+        );
+        // the decomposed syllables do not count, one error for each jamo.
+        assert_eq!(
+            validate_korean_str("가\u{1100}\u{1161} "),
+            Err(vec![
+                (3, Error::NonKorean('\u{1100}', Some(3))),
+                (6, Error::NonKorean('\u{1161}', Some(6))),
+                (9, Error::NonKorean(' ', Some(9))),
+            ])
+        );
+        // agrees with `parse_korean_string()` on the first one.
+        let source = "한글 ok";
+        assert_eq!(
+            validate_korean_str(source).unwrap_err()[0].1,
+            parse_korean_string(source).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_levenshtein_syllable() {
+        assert_eq!(levenshtein_syllable("", ""), 0);
+        assert_eq!(levenshtein_syllable("", "한글"), 2);
+        assert_eq!(levenshtein_syllable("한글", "한글"), 0);
+        assert_eq!(levenshtein_syllable("한글", "한국"), 1);
+        assert_eq!(levenshtein_syllable("한글", "글한"), 2);
+        assert_eq!(levenshtein_syllable("안녕하세요", "안녕세요"), 1);
+        // every other char counts as one as well.
+        assert_eq!(levenshtein_syllable("abc 가", "abd 가"), 1);
+        assert_eq!(levenshtein_syllable("가", "ㄱ"), 1);
+        assert_eq!(
+            levenshtein_syllable("\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}", "한국"),
+            1
+        );
+    }
+
+    #[test]
+    fn test_levenshtein_jamo() {
+        assert_eq!(levenshtein_jamo("", ""), 0);
+        assert_eq!(levenshtein_jamo("", "한글"), 6);
+        assert_eq!(levenshtein_jamo("한글", "한글"), 0);
+        assert_eq!(levenshtein_jamo("한글", "한귤"), 1);
+        assert_eq!(levenshtein_jamo("한글", "한그"), 1);
+        assert_eq!(levenshtein_jamo("닭", "달"), 1);
+        assert_eq!(levenshtein_jamo("과", "고"), 1);
+        assert_eq!(levenshtein_jamo("abc 가", "abd 가"), 1);
+        assert_eq!(levenshtein_jamo("ㅎㅏㄴ", "한"), 0);
+        assert_eq!(
+            levenshtein_jamo("\u{1112}\u{1161}\u{11AB}", "한"),
+            levenshtein_jamo("한", "한")
+        );
+        // short of standalone jamo, finer than, yet never more than three times, the distance
+        // between the syllables.
+        for (a, b) in [("한국어", "한글어"), ("가나다", "다나가"), ("밥", "bab")] {
+            let syllable = levenshtein_syllable(a, b);
+            let jamo = levenshtein_jamo(a, b);
+            assert!(syllable <= jamo && jamo <= 3 * syllable, "{} {}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_pushdown_jongseong() {
+        assert_eq!(pushdown_jongseong("먹어요"), "머거요");
+        assert_eq!(pushdown_jongseong("닭이"), "달기");
+        assert_eq!(pushdown_jongseong("없어"), "업서");
+        // 'ㅇ' stays put.
+        assert_eq!(pushdown_jongseong("강아지"), "강아지");
+        // only into a silent 'ㅇ'.
+        assert_eq!(pushdown_jongseong("국밥"), "국밥");
+        assert_eq!(pushdown_jongseong("밥 을"), "밥 을");
+        assert_eq!(pushdown_jongseong("abc 밥"), "abc 밥");
+    }
+
+    #[test]
+    fn test_pushdown_jongseong_with() {
+        let space = PushdownOptions {
+            skip_chars: &[' '],
+            ..PushdownOptions::default()
+        };
+        assert_eq!(pushdown_jongseong_with("밥 을", space), "바 블");
+        assert_eq!(pushdown_jongseong_with("밥  을", space), "바  블");
+        assert_eq!(pushdown_jongseong_with("밥, 을", space), "밥, 을");
+        assert_eq!(pushdown_jongseong_with("밥 ", space), "밥 ");
+
+        let punctuated = PushdownOptions {
+            skip_chars: &[' ', ','],
+            ..PushdownOptions::default()
+        };
+        assert_eq!(pushdown_jongseong_with("밥, 을", punctuated), "바, 블");
+        // a run of separators of either kind, and separators with nothing after them.
+        assert_eq!(pushdown_jongseong_with("밥 ,, 을", punctuated), "바 ,, 블");
+        assert_eq!(pushdown_jongseong_with("밥 , ", punctuated), "밥 , ");
+        assert_eq!(pushdown_jongseong_with("먹어 요 ", punctuated), "머거 요 ");
+        assert_eq!(pushdown_jongseong_with(" ,", punctuated), " ,");
+        assert_eq!(
+            pushdown_jongseong_with("밥 을", PushdownOptions::default()),
+            pushdown_jongseong("밥 을")
+        );
+    }
+
+    #[test]
+    fn test_pushdown_jongseong_with_palatalization() {
+        let palatalized = PushdownOptions {
+            palatalization: true,
+            ..PushdownOptions::default()
+        };
+        for (source, linked, palatalized_too) in [
+            ("굳이", "구디", "구지"),
+            ("같이", "가티", "가치"),
+            ("해돋이", "해도디", "해도지"),
+            ("밭이", "바티", "바치"),
+            // only before 'ㅣ'.
+            ("밭을", "바틀", "바틀"),
+            ("같아", "가타", "가타"),
+            ("돋여", "도뎌", "도뎌"),
+            // the second component of a cluster, too.
+            ("핥이", "할티", "할치"),
+            // and none other than 'ㄷ' and 'ㅌ'.
+            ("먹이", "머기", "머기"),
+        ] {
+            assert_eq!(pushdown_jongseong(source), linked, "{}", source);
+            assert_eq!(
+                pushdown_jongseong_with(source, palatalized),
+                palatalized_too,
+                "{}",
+                source
+            );
+        }
+
+        let both = PushdownOptions {
+            skip_chars: &[' '],
+            palatalization: true,
+        };
+        // no palatalization across a skipped character.
+        assert_eq!(pushdown_jongseong_with("밭 이", both), "바 티");
+        assert_eq!(pushdown_jongseong_with("밭이 밭 이", both), "바치 바 티");
+    }
+
+    #[test]
+    fn test_pushdown_jongseong_idempotent() {
+        let space = PushdownOptions {
+            skip_chars: &[' '],
+            palatalization: true,
+        };
+        for (source, linked) in [
+            ("닭알이", "달가리"),
+            ("읽어 없애", "일거 업새"),
+            ("앉아 있어", "안자 이써"),
+            ("같이 옷 입어", "가치 오 시버"),
+            ("강아지 엉엉", "강아지 엉엉"),
+        ] {
+            let once = pushdown_jongseong_with(source, space);
+            assert_eq!(once, linked, "{}", source);
+            assert_eq!(pushdown_jongseong_with(&once, space), once, "{}", source);
+            assert_eq!(
+                pushdown_jongseong(&pushdown_jongseong(source)),
+                pushdown_jongseong(source),
+                "{}",
+                source
+            );
+        }
+    }
+
+    #[test]
+    fn test_flip_chojongseong_horizontally() {
+        assert_eq!(flip_chojongseong_horizontally(""), "");
+        assert_eq!(flip_chojongseong_horizontally("각 갑 가 아"), "각 박 악 아");
+        // the consonants not fitting the other position, and a final 'ㅇ'.
+        assert_eq!(flip_chojongseong_horizontally("따 닭 쌍"), "따 닭 쌍");
+        assert_eq!(flip_chojongseong_horizontally("쌀"), "랐");
+
+        for code_point in 0xAC00..=0xD7A3 {
+            let syllable = char::from_u32(code_point).unwrap().to_string();
+            let flipped = flip_chojongseong_horizontally(&syllable);
+            assert_eq!(flip_chojongseong_horizontally(&flipped), syllable);
+        }
+    }
+
+    #[test]
+    fn test_pullup_choseong() {
+        assert_eq!(pullup_choseong(""), "");
+        assert_eq!(pullup_choseong("머거요"), "먹어요");
+        assert_eq!(pullup_choseong("가나다"), "간앋아");
+        // 'ㅇ' and the consonants which cannot be final stay put.
+        assert_eq!(pullup_choseong("아이"), "아이");
+        assert_eq!(pullup_choseong("아빠 오빠"), "아빠 오빠");
+        // nor does a syllable with a final consonant already take another one.
+        assert_eq!(pullup_choseong("달가리"), "달갈이");
+        assert_eq!(pullup_choseong("머 거, ok"), "머 거, ok");
+        assert_eq!(
+            pullup_choseong("\u{1106}\u{1165}\u{1100}\u{1165}"),
+            "\u{1106}\u{1165}\u{1100}\u{1165}"
+        );
+    }
+
+    #[test]
+    fn test_pullup_choseong_with() {
+        let space = PullupOptions { skip_chars: &[' '] };
+        assert_eq!(pullup_choseong_with("머 거", space), "먹 어");
+        assert_eq!(pullup_choseong_with("머  거", space), "먹  어");
+        assert_eq!(pullup_choseong_with("머, 거", space), "머, 거");
+
+        let punctuated = PullupOptions {
+            skip_chars: &[' ', ','],
+        };
+        // a run of separators of either kind, and separators with nothing after them.
+        assert_eq!(pullup_choseong_with("머 ,, 거", punctuated), "먹 ,, 어");
+        assert_eq!(pullup_choseong_with("머거 요 ", punctuated), "먹어 요 ");
+        assert_eq!(pullup_choseong_with("머 , ", punctuated), "머 , ");
+        assert_eq!(pullup_choseong_with(" ,", punctuated), " ,");
+        assert_eq!(
+            pullup_choseong_with("머거", PullupOptions::default()),
+            pullup_choseong("머거")
+        );
+
+        // the reverse of pushdown_jongseong_with, as long as no cluster is involved.
+        let pushdown = PushdownOptions {
+            skip_chars: &[' ', ','],
+            ..PushdownOptions::default()
+        };
+        for source in ["먹어 요", "밥, 을 먹었어", "같이"] {
+            let pushed = pushdown_jongseong_with(source, pushdown);
+            assert_eq!(
+                pullup_choseong_with(&pushed, punctuated),
+                source,
+                "{}",
+                source
+            );
+        }
+        let once = pullup_choseong_with("가나다 라마", space);
+        assert_eq!(pullup_choseong_with(&once, space), once);
+    }
+
+    #[test]
+    fn test_display_width() {
+        assert_eq!(display_width(""), 0);
+        assert_eq!(display_width("한글"), 4);
+        assert_eq!(
+            display_width("\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}"),
+            4
+        );
+        assert_eq!(display_width("가\u{11A8}"), 2);
+        assert_eq!(display_width("abc 가나"), 8);
+        assert_eq!(display_width("ㄱㅏ"), 4);
+        assert_eq!(display_width("\u{FFA1}\u{FFC2}"), 2);
+        assert_eq!(display_width("ＡＢ"), 4);
+
+        // incomplete runs count per char
+        assert_eq!(display_width("\u{1100}\u{1100}\u{1161}"), 4);
+        assert_eq!(display_width("\u{1161}\u{11A8}"), 2);
+    }
+
+    #[test]
+    fn test_display_width_with() {
+        assert_eq!(display_width_with("한글", false), 2);
+        assert_eq!(
+            display_width_with("\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}", false),
+            2
+        );
+        assert_eq!(display_width_with("가\u{11A8}", false), 1);
+        assert_eq!(display_width_with("abc 가나", false), 6);
+        assert_eq!(display_width_with("한글 ＡＢ", false), 5);
+        assert_eq!(display_width_with("\u{1100}\u{1100}\u{1161}", false), 2);
+
+        assert_eq!(display_width_with("ㄱ a", true), 4);
+        assert_eq!(display_width_with("\u{FFA1}", true), 1);
+    }
+
+    #[test]
+    fn test_truncate_to_width() {
+        // precomposed
+        assert_eq!(truncate_to_width("안녕하세요", 10, "…"), "안녕하세요");
+        assert_eq!(truncate_to_width("안녕하세요", 9, "…"), "안녕하세…");
+        assert_eq!(truncate_to_width("안녕하세요", 8, "…"), "안녕하…");
+        assert_eq!(truncate_to_width("안녕하세요", 6, ""), "안녕하");
+        assert_eq!(truncate_to_width("안녕하세요", 5, ""), "안녕");
+
+        // decomposed, never cut inside a syllable and kept decomposed
+        let decomposed = "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}";
+        assert_eq!(truncate_to_width(decomposed, 4, "…"), decomposed);
+        assert_eq!(
+            truncate_to_width(decomposed, 3, "…"),
+            "\u{1112}\u{1161}\u{11AB}…"
+        );
+        assert_eq!(truncate_to_width(decomposed, 1, ""), "");
+        assert_eq!(truncate_to_width("가\u{11A8}나", 3, ""), "가\u{11A8}");
+
+        // halfwidth
+        assert_eq!(
+            truncate_to_width("\u{FFA1}\u{FFC2}\u{FFA4}", 2, "."),
+            "\u{FFA1}."
+        );
+
+        // mixed ASCII
+        assert_eq!(truncate_to_width("abc 한글", 6, "..."), "abc...");
+        assert_eq!(truncate_to_width("abc 한글", 7, "..."), "abc ...");
+        assert_eq!(truncate_to_width("a한b", 2, ""), "a");
+
+        // only the ellipsis fits, or not even that
+        assert_eq!(truncate_to_width("한글", 1, "…"), "…");
+        assert_eq!(truncate_to_width("abc", 2, "..."), "ab");
+        assert_eq!(truncate_to_width("", 0, "…"), "");
+    }
+
+    #[test]
+    fn test_modernize_text() {
+        let policy = ModernizePolicy::default();
+        assert_eq!(modernize_text("ㅿㆁㆆㆍ", &policy), "ㅅㅇㅎㅏ");
+        assert_eq!(
+            modernize_text("\u{1140}\u{119E}\u{11EB} \u{114C}\u{1173}\u{11F0}", &policy),
+            "\u{1109}\u{1161}\u{11BA} \u{110B}\u{1173}\u{11BC}"
+        );
+        assert_eq!(
+            modernize_text("\u{1159}\u{1161}\u{11F9}", &policy),
+            "\u{1112}\u{1161}\u{11C2}"
+        );
+        assert_eq!(modernize_text("한글 abc", &policy), "한글 abc");
+        assert_eq!(modernize_text("", &policy), "");
+
+        // Arae-A
+        let policy = ModernizePolicy {
+            arae_a: Jungseong::O,
+            ..ModernizePolicy::default()
+        };
+        assert_eq!(modernize_text("ㆍ\u{119E}", &policy), "ㅗ\u{1169}");
+
+        // unsupported ones, i.e. the clusters and the rest of the archaic jamo
+        let archaic = "\u{1121}ㅥ\u{A960}\u{D7B0}\u{11FF}";
+        assert_eq!(
+            modernize_text(archaic, &ModernizePolicy::default()),
+            archaic
+        );
+        let policy = ModernizePolicy {
+            on_unsupported: ConversionFallback::Skip,
+            ..ModernizePolicy::default()
+        };
+        assert_eq!(modernize_text(archaic, &policy), "");
+        let policy = ModernizePolicy {
+            on_unsupported: ConversionFallback::ReplacementChar,
+            ..ModernizePolicy::default()
+        };
+        assert_eq!(
+            modernize_text("\u{1121}\u{1161}", &policy),
+            "\u{FFFD}\u{1161}"
+        );
+
+        // the clusters, by their first components, modernized in turn if archaic themselves
+        let policy = ModernizePolicy {
+            arae_a: Jungseong::O,
+            approximate_clusters: true,
+            on_unsupported: ConversionFallback::Skip,
+        };
+        assert_eq!(modernize_text(archaic, &policy), "\u{1107}\u{1103}\u{1169}");
+        assert_eq!(
+            modernize_text("\u{1112}\u{119F}\u{11EC} ㆂ", &policy),
+            "\u{1112}\u{1169}\u{11BC} ㅇ"
+        );
+
+        // the fillers are not archaic
+        assert_eq!(
+            modernize_text("\u{115F}\u{1160}\u{3164}", &policy),
+            "\u{115F}\u{1160}\u{3164}"
+        );
+    }
+
+    #[test]
+    fn test_map_syllables() {
+        assert_eq!(
+            map_syllables("가나 abc 다", |syllable| syllable
+                .with_jungseong(Jungseong::O)),
+            "고노 abc 도"
+        );
+        assert_eq!(
+            map_syllables("ㄱ\u{1100}\u{1161}", |_| unreachable!()),
+            "ㄱ\u{1100}\u{1161}"
+        );
+
+        let mut visited = Vec::new();
+        map_syllables("한 글", |syllable| {
+            visited.push(char::from(syllable));
+            syllable
+        });
+        assert_eq!(visited, ['한', '글']);
+
+        for source in ["강물이 흐른다", "닭볶음탕!", "ㄱ ㅏ abc", ""] {
+            assert_eq!(
+                map_syllables(source, |syllable| syllable.with_jongseong(None)),
+                strip_jongseong(source)
+            );
+        }
+    }
+
+    #[test]
+    fn test_map_syllable_pairs() {
+        let mut visited = Vec::new();
+        let mapped = map_syllable_pairs("가나 다", |current, next| {
+            visited.push((char::from(current), next.map(char::from)));
+            (
+                current,
+                next.map(|next| next.with_jongseong(Some(Jongseong::Ieung))),
+            )
+        });
+        assert_eq!(mapped, "가낭 다");
+        // the replacement is what gets visited next; '다' is not adjacent to anything.
+        assert_eq!(visited, [('가', Some('나')), ('낭', None), ('다', None)]);
+
+        assert_eq!(map_syllable_pairs("abc", |_, _| unreachable!()), "abc");
+    }
+
+    #[test]
+    fn test_modern_equivalent() {
+        for (archaic, modern) in [
+            ('ㅿ', 'ㅅ'),
+            ('ㆁ', 'ㅇ'),
+            ('ㆆ', 'ㅎ'),
+            ('ㆍ', 'ㅏ'),
+            ('\u{1140}', '\u{1109}'),
+            ('\u{114C}', '\u{110B}'),
+            ('\u{1159}', '\u{1112}'),
+            ('\u{119E}', '\u{1161}'),
+            ('\u{11EB}', '\u{11BA}'),
+            ('\u{11F0}', '\u{11BC}'),
+            ('\u{11F9}', '\u{11C2}'),
+        ] {
+            assert_eq!(modern_equivalent(archaic), Some(modern), "{:?}", archaic);
+        }
+
+        assert_eq!(modern_equivalent('ㅥ'), None);
+        assert_eq!(modern_equivalent('\u{A960}'), None);
+        assert_eq!(modern_equivalent('ㄱ'), None);
+        assert_eq!(modern_equivalent('가'), None);
+    }
+
+    #[test]
+    fn test_normalize_halfwidth() {
+        assert_eq!(normalize_halfwidth("\u{FFA1}"), "ㄱ");
+        assert_eq!(normalize_halfwidth("\u{FFA1}\u{FFC2}\u{FFA4}"), "ㄱㅏㄴ");
+        assert_eq!(normalize_halfwidth("abc\u{FFA1}xyz"), "abcㄱxyz");
+        assert_eq!(
+            normalize_halfwidth("\u{FFC7}\u{FFCA}\u{FFCF}\u{FFD2}\u{FFD7}\u{FFDA}\u{FFDC}"),
+            "ㅔㅕㅚㅛㅠㅡㅣ"
+        );
+
+        // unassigned code points and the halfwidth filler are left as is.
+        assert_eq!(
+            normalize_halfwidth("\u{FFA0}\u{FFC8}\u{FFDD}"),
+            "\u{FFA0}\u{FFC8}\u{FFDD}"
+        );
+        assert_eq!(normalize_halfwidth("가ㄱ"), "가ㄱ");
+
+        for (halfwidth, compat) in (0xFFA1..=0xFFBE).zip(0x3131..=0x314E) {
+            assert_eq!(
+                normalize_halfwidth(&char::from_u32(halfwidth).unwrap().to_string()),
+                char::from_u32(compat).unwrap().to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_reverse_syllables() {
+        assert_eq!(reverse_syllables("가나다"), "다나가");
+        assert_eq!(reverse_syllables("abc 가"), "가 cba");
+        assert_eq!(reverse_syllables(""), "");
+
+        // decomposed, and partially decomposed
+        assert_eq!(
+            reverse_syllables("\u{1100}\u{1161}\u{1102}\u{1161}\u{11AB}!"),
+            "!난가"
+        );
+        assert_eq!(reverse_syllables("가\u{11A8}나"), "나각");
+        assert_eq!(reverse_syllables("\u{1100}\u{1161}각"), "각가");
+
+        // incomplete runs are left as is
+        assert_eq!(
+            reverse_syllables("\u{1100}a\u{1161}\u{11A8}"),
+            "\u{11A8}\u{1161}a\u{1100}"
+        );
+        assert_eq!(reverse_syllables("각\u{11A8}"), "\u{11A8}각");
+    }
+
+    #[test]
+    fn test_reverse_syllables_in_place() {
+        assert_eq!(reverse_syllables_in_place("가나다"), "다나가");
+        assert_eq!(reverse_syllables_in_place("ABC 한글"), "ABC 글한");
+        assert_eq!(reverse_syllables_in_place("한a글b"), "글a한b");
+        assert_eq!(reverse_syllables_in_place("abc"), "abc");
+        assert_eq!(reverse_syllables_in_place(""), "");
+        assert_eq!(
+            reverse_syllables_in_place("\u{1100}\u{1161}, \u{1102}\u{1161}\u{11AB}"),
+            "난, 가"
+        );
+        // standalone jamo are not syllables.
+        assert_eq!(reverse_syllables_in_place("가ㄱ나"), "나ㄱ가");
+    }
+
+    #[test]
+    fn test_reverse_jamo() {
+        assert_eq!(reverse_jamo(""), "");
+        assert_eq!(reverse_jamo("가"), "ㅏㄱ");
+        assert_eq!(reverse_jamo("각"), "각");
+        assert_eq!(reverse_jamo("한글"), "륵낳");
+        assert_eq!(reverse_jamo("abc 가"), "ㅏㄱ cba");
+        assert_eq!(
+            reverse_jamo("\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}"),
+            reverse_jamo("한글")
+        );
+        assert_eq!(reverse_jamo("기러기"), "ㅣ거릭");
+        // twice gives back the syllables, as long as there were no standalone jamo.
+        for source in ["기러기", "한글", "닭볶음탕", "가 나"] {
+            assert_eq!(reverse_jamo(&reverse_jamo(source)), source);
+        }
+    }
+
+    #[test]
+    fn test_is_syllable_palindrome() {
+        assert!(is_syllable_palindrome(""));
+        assert!(is_syllable_palindrome("abc"));
+        assert!(is_syllable_palindrome("가"));
+        assert!(is_syllable_palindrome("기러기"));
+        assert!(is_syllable_palindrome("토마토"));
+        assert!(is_syllable_palindrome("여보, 안경 안 보여"));
+        // everything but the syllables is ignored, standalone jamo included.
+        assert!(is_syllable_palindrome("a기b러c기ㄱ"));
+        assert!(is_syllable_palindrome(
+            "\u{1100}\u{1175}\u{1105}\u{1165}\u{1100}\u{1175}"
+        ));
+        assert!(is_syllable_palindrome("\u{1100}\u{1175}러기"));
+
+        assert!(!is_syllable_palindrome("가나"));
+        assert!(!is_syllable_palindrome("한글"));
+        // the same jamo, yet different syllables.
+        assert!(!is_syllable_palindrome("각가"));
+    }
+
+    #[test]
+    fn test_is_jamo_palindrome() {
+        assert!(is_jamo_palindrome(""));
+        assert!(is_jamo_palindrome("abc"));
+        assert!(is_jamo_palindrome("각"));
+        assert!(is_jamo_palindrome("각각"));
+        assert!(is_jamo_palindrome("몸 ㄱ 몸"));
+        assert!(is_jamo_palindrome("ab 난 cd"));
+        assert!(is_jamo_palindrome("\u{1100}\u{1161}\u{11A8}"));
+        // the same syllables read backwards, yet not the same jamo.
+        assert!(!is_jamo_palindrome("기러기"));
+        assert!(!is_jamo_palindrome("가"));
+        // a cluster is one jamo, not its components reversed.
+        assert!(!is_jamo_palindrome("갃"));
+
+        // a palindrome in jamo stays one when reversed jamo by jamo.
+        for source in ["각", "몸", "각각", "임밍"] {
+            assert!(is_jamo_palindrome(source), "{}", source);
+            assert_eq!(reverse_jamo(source), source);
+        }
+    }
+
+    #[test]
+    fn test_strip_jongseong() {
+        assert_eq!(strip_jongseong("강물"), "가무");
+        assert_eq!(strip_jongseong("닭볶음탕!"), "다보으타!");
+        assert_eq!(strip_jongseong("ㄱ ㅏ abc"), "ㄱ ㅏ abc");
+        assert_eq!(strip_jongseong(""), "");
+    }
+
+    #[test]
+    fn test_set_jongseong() {
+        assert_eq!(set_jongseong("바다", Some(Jongseong::Rieul)), "발달");
+        assert_eq!(set_jongseong("강물", Some(Jongseong::Mieum)), "감뭄");
+        assert_eq!(set_jongseong("강물", None), "가무");
+        assert_eq!(set_jongseong("ㄱ a", Some(Jongseong::Kiyeok)), "ㄱ a");
+    }
+
+    #[test]
+    fn test_cuteify() {
+        let source = "좋아, 했어 밥 ok 가a";
+        assert_eq!(
+            cuteify(source, CuteStyle::AppendIeungJongseong),
+            "좋앙, 했엉 밥 ok 가a"
+        );
+        assert_eq!(
+            cuteify(source, CuteStyle::EchoVowel),
+            "좋아아, 했어어 밥아 ok 가a"
+        );
+        assert_eq!(
+            cuteify(source, CuteStyle::Both),
+            "좋아앙, 했어엉 밥앙 ok 가a"
+        );
+        // only the last syllable of a word changes, and the output is deterministic.
+        assert_eq!(cuteify("사랑해요", CuteStyle::Both), "사랑해요용");
+        assert_eq!(cuteify("ㅋㅋ", CuteStyle::Both), "ㅋㅋ");
+        assert_eq!(cuteify("", CuteStyle::EchoVowel), "");
+    }
+}
@@ -0,0 +1,14 @@
+//! The transformations rewriting the syllables of a string in place, gathered in one module for
+//! those who want all of them; each is available from the crate root as well.
+//!
+//! ```
+//! use unikorn::transform::{pushdown_jongseong, strip_jongseong};
+//!
+//! assert_eq!(pushdown_jongseong("먹어요"), "머거요");
+//! assert_eq!(strip_jongseong("먹어요"), "머어요");
+//! ```
+pub use crate::text::{
+    flip_chojongseong_horizontally, map_syllable_pairs, map_syllables, pullup_choseong,
+    pullup_choseong_with, pushdown_jongseong, pushdown_jongseong_with, reverse_syllables,
+    set_jongseong, strip_jongseong, PullupOptions, PushdownOptions,
+};
@@ -0,0 +1,81 @@
+//! Counting the keystrokes Korean text takes on the standard 2-set (두벌식, Dubeolsik) keyboard.
+use crate::{Jaeum, Moeum, Syllable};
+use core::convert::TryFrom;
+
+/// Counts the keystrokes a given string takes on the standard 2-set (두벌식, Dubeolsik) keyboard,
+/// as a typing tutor would.
+///
+/// Every consonant or vowel is a key of its own, even a tense consonant taking Shift, e.g. 'ㄲ',
+/// whereas a compound vowel or a clustered final consonant takes one key for each of its
+/// components, e.g. 'ㅘ' for 'ㅗ' and 'ㅏ', or 'ㄺ' for 'ㄹ' and 'ㄱ'. This holds for a Precomposed
+/// Korean [`Syllable`] and a standalone consonant or vowel in Hangul Compatibility Jamo range
+/// alike; any other [`char`] counts as a single keystroke.
+///
+/// ```
+/// use unikorn::keystroke_count;
+///
+/// assert_eq!(keystroke_count("한글"), 6);
+/// assert_eq!(keystroke_count("닭"), 4); // ㄷ, ㅏ, ㄹ, ㄱ
+/// assert_eq!(keystroke_count("꽃 ok"), 6); // ㄲ, ㅗ, ㅊ, ' ', o, k
+/// ```
+pub fn keystroke_count(s: &str) -> usize {
+    s.chars()
+        .map(|character| {
+            if let Ok(syllable) = Syllable::try_from(character) {
+                jaeum_keystrokes(syllable.choseong.into())
+                    + moeum_keystrokes(syllable.jungseong)
+                    + syllable
+                        .jongseong
+                        .map_or(0, |jongseong| jaeum_keystrokes(jongseong.into()))
+            } else if let Ok(jaeum) = Jaeum::try_from(character) {
+                jaeum_keystrokes(jaeum)
+            } else if let Ok(moeum) = Moeum::try_from(character) {
+                moeum_keystrokes(moeum)
+            } else {
+                1
+            }
+        })
+        .sum()
+}
+
+// a consonant without a key of its own is always a cluster of two.
+fn jaeum_keystrokes(jaeum: Jaeum) -> usize {
+    if jaeum.dubeolsik_key().is_some() {
+        1
+    } else {
+        2
+    }
+}
+
+// and so is a vowel.
+fn moeum_keystrokes(moeum: Moeum) -> usize {
+    if moeum.dubeolsik_key().is_some() {
+        1
+    } else {
+        2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::keystroke_count;
+
+    #[test]
+    fn test_keystroke_count() {
+        assert_eq!(keystroke_count(""), 0);
+        assert_eq!(keystroke_count("한글"), 6);
+        // the tense consonants and ㅒ, ㅖ take Shift, yet only one key.
+        assert_eq!(keystroke_count("쌌다"), 5);
+        assert_eq!(keystroke_count("얘"), 2);
+        // the compound vowels and the clustered final consonants take two.
+        assert_eq!(keystroke_count("왔"), 4);
+        assert_eq!(keystroke_count("뷁"), 5);
+        assert_eq!(keystroke_count("값"), 4);
+        // the standalone ones count the same.
+        assert_eq!(keystroke_count("ㅋㅋㅋ"), 3);
+        assert_eq!(keystroke_count("ㄳㅢ"), 4);
+        // anything else is a key on its own.
+        assert_eq!(keystroke_count("a b!"), 4);
+        assert_eq!(keystroke_count("\u{1100}\u{1161}ㆍ"), 3);
+    }
+}
@@ -0,0 +1,204 @@
+//! Bindings exposing the core API to JavaScript and TypeScript via `wasm-bindgen`.
+//!
+//! [`Choseong`], [`Jungseong`], and [`Jongseong`] are exported as they are, i.e. as numeric enums
+//! with the same discriminants, while a [`Syllable`] crosses the boundary as a [`SyllableJs`].
+//! Every [`char`] is passed as its Unicode code point, and every function is exported under its
+//! camelCase name, e.g. [`strip_jongseong`](crate::strip_jongseong) as `stripJongseong`.
+use crate::{Choseong, JamoForm, Jongseong, Jungseong, PullupOptions, PushdownOptions, Syllable};
+use alloc::{string::String, vec::Vec};
+use core::convert::TryFrom;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// A Precomposed Korean [`Syllable`] as seen from JavaScript.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SyllableJs {
+    /// The initial consonant (초성, [`Choseong`]).
+    pub choseong: Choseong,
+    /// The medial vowel (중성, [`Jungseong`]).
+    pub jungseong: Jungseong,
+    /// The final consonant (종성, [`Jongseong`]), if any.
+    pub jongseong: Option<Jongseong>,
+}
+impl From<Syllable> for SyllableJs {
+    fn from(syllable: Syllable) -> Self {
+        Self {
+            choseong: syllable.choseong,
+            jungseong: syllable.jungseong,
+            jongseong: syllable.jongseong,
+        }
+    }
+}
+impl From<SyllableJs> for Syllable {
+    fn from(syllable: SyllableJs) -> Self {
+        Self {
+            choseong: syllable.choseong,
+            jungseong: syllable.jungseong,
+            jongseong: syllable.jongseong,
+        }
+    }
+}
+#[wasm_bindgen]
+impl SyllableJs {
+    /// Composes a syllable out of its components.
+    #[wasm_bindgen(constructor)]
+    pub fn new(choseong: Choseong, jungseong: Jungseong, jongseong: Option<Jongseong>) -> Self {
+        Syllable::from((choseong, jungseong, jongseong)).into()
+    }
+
+    /// Converts the code point of a Precomposed Korean [`Syllable`] into one, or returns
+    /// `undefined` if it is not.
+    #[wasm_bindgen(js_name = tryFromChar)]
+    pub fn try_from_char_js(character: u32) -> Option<SyllableJs> {
+        char::from_u32(character)
+            .and_then(|character| Syllable::try_from(character).ok())
+            .map(Self::from)
+    }
+
+    /// Returns the code point of this syllable.
+    #[wasm_bindgen(js_name = toChar)]
+    pub fn to_char_js(self) -> u32 {
+        char::from(Syllable::from(self)) as u32
+    }
+
+    /// Returns this syllable as a string.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string_js(self) -> String {
+        char::from(Syllable::from(self)).into()
+    }
+
+    /// Decomposes this syllable into the conjoining jamo (Hangul Jamo range, U+1100 -- U+11FF).
+    #[wasm_bindgen(js_name = toConjoining)]
+    pub fn to_conjoining_js(self) -> String {
+        let mut decomposed = String::with_capacity(9);
+        JamoForm::Conjoining.push_decomposed(&mut decomposed, self.into());
+        decomposed
+    }
+}
+
+/// See [`compose_from_indices`](crate::compose_from_indices).
+#[wasm_bindgen(js_name = composeFromIndices)]
+pub fn compose_from_indices_js(indices: &[u32]) -> String {
+    crate::compose_from_indices(indices)
+}
+
+/// See [`decompose_to_indices`](crate::decompose_to_indices).
+#[wasm_bindgen(js_name = decomposeToIndices)]
+pub fn decompose_to_indices_js(source: &str) -> Vec<u32> {
+    crate::decompose_to_indices(source)
+}
+
+/// See [`display_width`](crate::display_width).
+#[wasm_bindgen(js_name = displayWidth)]
+pub fn display_width_js(source: &str) -> usize {
+    crate::display_width(source)
+}
+
+/// See [`normalize_halfwidth`](crate::normalize_halfwidth).
+#[wasm_bindgen(js_name = normalizeHalfwidth)]
+pub fn normalize_halfwidth_js(source: &str) -> String {
+    crate::normalize_halfwidth(source)
+}
+
+/// See [`pullup_choseong`](crate::pullup_choseong).
+#[wasm_bindgen(js_name = pullupChoseong)]
+pub fn pullup_choseong_js(source: &str) -> String {
+    crate::pullup_choseong(source)
+}
+
+/// See [`pullup_choseong_with`](crate::pullup_choseong_with); every [`char`] in `skip` is one of
+/// [`PullupOptions::skip_chars`].
+#[wasm_bindgen(js_name = pullupChoseongWith)]
+pub fn pullup_choseong_with_js(source: &str, skip: &str) -> String {
+    let skip_chars: Vec<char> = skip.chars().collect();
+    crate::pullup_choseong_with(
+        source,
+        PullupOptions {
+            skip_chars: &skip_chars,
+        },
+    )
+}
+
+/// See [`pushdown_jongseong`](crate::pushdown_jongseong).
+#[wasm_bindgen(js_name = pushdownJongseong)]
+pub fn pushdown_jongseong_js(source: &str) -> String {
+    crate::pushdown_jongseong(source)
+}
+
+/// See [`pushdown_jongseong_with`](crate::pushdown_jongseong_with); every [`char`] in `skip` is
+/// one of [`PushdownOptions::skip_chars`].
+#[wasm_bindgen(js_name = pushdownJongseongWith)]
+pub fn pushdown_jongseong_with_js(source: &str, skip: &str, palatalization: bool) -> String {
+    let skip_chars: Vec<char> = skip.chars().collect();
+    crate::pushdown_jongseong_with(
+        source,
+        PushdownOptions {
+            skip_chars: &skip_chars,
+            palatalization,
+        },
+    )
+}
+
+/// See [`reverse_syllables`](crate::reverse_syllables).
+#[wasm_bindgen(js_name = reverseSyllables)]
+pub fn reverse_syllables_js(source: &str) -> String {
+    crate::reverse_syllables(source)
+}
+
+/// See [`set_jongseong`](crate::set_jongseong).
+#[wasm_bindgen(js_name = setJongseong)]
+pub fn set_jongseong_js(source: &str, jongseong: Option<Jongseong>) -> String {
+    crate::set_jongseong(source, jongseong)
+}
+
+/// See [`strip_jongseong`](crate::strip_jongseong).
+#[wasm_bindgen(js_name = stripJongseong)]
+pub fn strip_jongseong_js(source: &str) -> String {
+    crate::strip_jongseong(source)
+}
+
+/// See [`truncate_to_width`](crate::truncate_to_width).
+#[wasm_bindgen(js_name = truncateToWidth)]
+pub fn truncate_to_width_js(source: &str, max: usize, ellipsis: &str) -> String {
+    crate::truncate_to_width(source, max, ellipsis)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        pullup_choseong_js, pullup_choseong_with_js, pushdown_jongseong_js,
+        pushdown_jongseong_with_js, SyllableJs,
+    };
+    use crate::{Choseong, Jongseong, Jungseong};
+
+    #[test]
+    fn test_syllable_js() {
+        let syllable = SyllableJs::try_from_char_js('한' as u32).unwrap();
+        assert_eq!(
+            syllable,
+            SyllableJs::new(Choseong::Hieuh, Jungseong::A, Some(Jongseong::Nieun))
+        );
+        assert_eq!(syllable.to_char_js(), '한' as u32);
+        assert_eq!(syllable.to_string_js(), "한");
+        assert_eq!(syllable.to_conjoining_js(), "\u{1112}\u{1161}\u{11AB}");
+
+        assert_eq!(SyllableJs::try_from_char_js('ㄱ' as u32), None);
+        assert_eq!(SyllableJs::try_from_char_js(0xD800), None);
+        assert_eq!(SyllableJs::try_from_char_js(u32::MAX), None);
+    }
+
+    #[test]
+    fn test_pullup_choseong_js() {
+        assert_eq!(pullup_choseong_js("머거 요"), "먹어 요");
+        assert_eq!(pullup_choseong_with_js("머 거", " "), "먹 어");
+        assert_eq!(pullup_choseong_with_js("머 거", ""), "머 거");
+    }
+
+    #[test]
+    fn test_pushdown_jongseong_js() {
+        assert_eq!(pushdown_jongseong_js("먹어 요"), "머거 요");
+        assert_eq!(pushdown_jongseong_with_js("밥 을", " ", false), "바 블");
+        assert_eq!(pushdown_jongseong_with_js("같이", "", true), "가치");
+        assert_eq!(pushdown_jongseong_with_js("같이", "", false), "가티");
+    }
+}
@@ -0,0 +1,157 @@
+//! Streaming the whole-string transformations into a [`fmt::Write`], without allocating.
+use crate::{scan::decompose_with_offsets, Choseong, JamoForm, Jongseong, Jungseong, Syllable};
+use core::{
+    convert::TryFrom,
+    fmt::{self, Write},
+    iter,
+};
+
+/// Does the same as [`decompose_syllables`](crate::decompose_syllables), writing into a given
+/// [`fmt::Write`] instead, e.g. a [`Formatter`](fmt::Formatter) inside a `Display` impl.
+///
+/// ```
+/// use unikorn::{write_decomposed, JamoForm};
+///
+/// let mut decomposed = String::new();
+/// write_decomposed(&mut decomposed, "한글 ok", JamoForm::Compatibility).unwrap();
+/// assert_eq!(decomposed, "ㅎㅏㄴㄱㅡㄹ ok");
+/// ```
+pub fn write_decomposed<W: Write>(w: &mut W, s: &str, form: JamoForm) -> fmt::Result {
+    let mut copied = 0;
+    for (offset, syllable) in decompose_with_offsets(s) {
+        w.write_str(&s[copied..offset])?;
+        form.decompose(syllable)
+            .try_for_each(|jamo| w.write_char(jamo))?;
+        copied = offset + 3;
+    }
+
+    w.write_str(&s[copied..])
+}
+
+/// Does the same as [`compose_syllables`](crate::compose_syllables), writing into a given
+/// [`fmt::Write`] instead, e.g. a [`Formatter`](fmt::Formatter) inside a `Display` impl.
+///
+/// ```
+/// use unikorn::write_composed;
+///
+/// let mut composed = String::new();
+/// write_composed(&mut composed, "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF} ok").unwrap();
+/// assert_eq!(composed, "한글 ok");
+/// ```
+pub fn write_composed<W: Write>(w: &mut W, s: &str) -> fmt::Result {
+    compose_conjoining(s).try_for_each(|(_, character)| w.write_char(character))
+}
+
+// composes the runs of conjoining jamo into the precomposed syllables, passing everything else
+// through, each paired with the byte offset in `s` right past where it came from.
+pub(crate) fn compose_conjoining(s: &str) -> impl Iterator<Item = (usize, char)> + '_ {
+    let mut characters = s.chars().peekable();
+    let mut end = 0;
+
+    iter::from_fn(move || {
+        let character = characters.next()?;
+        end += character.len_utf8();
+        let mut syllable = match (
+            Choseong::from_conjoining(character),
+            characters
+                .peek()
+                .map(|&next| Jungseong::from_conjoining(next)),
+        ) {
+            (Ok(choseong), Some(Ok(jungseong))) => {
+                end += characters.next().map_or(0, char::len_utf8);
+                Syllable::from((choseong, jungseong))
+            }
+            _ => match Syllable::try_from(character) {
+                Ok(syllable) if syllable.jongseong.is_none() => syllable,
+                _ => return Some((end, character)),
+            },
+        };
+
+        if let Some(Ok(jongseong)) = characters
+            .peek()
+            .map(|&next| Jongseong::from_conjoining(next))
+        {
+            end += characters.next().map_or(0, char::len_utf8);
+            syllable.jongseong = Some(jongseong);
+        }
+        Some((end, syllable.into()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{write_composed, write_decomposed};
+    use crate::JamoForm;
+    use core::fmt::{self, Write};
+
+    // fails once it has taken `capacity` chars.
+    struct Limited {
+        written: String,
+        capacity: usize,
+    }
+    impl Write for Limited {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            if self.written.chars().count() + s.chars().count() > self.capacity {
+                return Err(fmt::Error);
+            }
+            self.written.push_str(s);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_write_decomposed() {
+        let mut decomposed = String::from("> ");
+        write_decomposed(&mut decomposed, "각 a", JamoForm::Conjoining).unwrap();
+        assert_eq!(decomposed, "> \u{1100}\u{1161}\u{11A8} a");
+
+        let mut limited = Limited {
+            written: String::new(),
+            capacity: 4,
+        };
+        assert!(write_decomposed(&mut limited, "a한글", JamoForm::Compatibility).is_err());
+        assert_eq!(limited.written, "aㅎㅏㄴ");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_write_decomposed_as_decompose_syllables() {
+        for source in ["", "abc", "한글", "안녕, 세상!", "ㄱ\u{1100}\u{1161}각"] {
+            for form in [JamoForm::Conjoining, JamoForm::Compatibility] {
+                let mut decomposed = String::new();
+                write_decomposed(&mut decomposed, source, form).unwrap();
+                assert_eq!(decomposed, crate::decompose_syllables(source, form));
+            }
+        }
+    }
+
+    #[test]
+    fn test_write_composed() {
+        let mut composed = String::from("> ");
+        write_composed(&mut composed, "\u{1100}\u{1161}\u{11A8}\u{11A8} a").unwrap();
+        assert_eq!(composed, "> 각\u{11A8} a");
+
+        let mut limited = Limited {
+            written: String::new(),
+            capacity: 1,
+        };
+        assert!(write_composed(&mut limited, "\u{1100}\u{1161}\u{1100}\u{1161}").is_err());
+        assert_eq!(limited.written, "가");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_write_composed_as_compose_syllables() {
+        for source in [
+            "",
+            "abc",
+            "한글",
+            "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}",
+            "\u{1100}\u{1100}\u{1161} \u{1161}\u{11A8}가\u{11A8}",
+        ] {
+            let mut composed = String::new();
+            write_composed(&mut composed, source).unwrap();
+            assert_eq!(composed, crate::compose_syllables(source));
+        }
+    }
+}